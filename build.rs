@@ -0,0 +1,729 @@
+/*!
+Generates the controlled vocabularies of `src/controlled_vocabularies.rs`
+from the SKOS RDF/XML exports of the Named Authority Lists vendored in
+`vocabularies/`. Updating a vocabulary means replacing its export there.
+
+Each concept scheme becomes an enum with one variant per concept, a table of
+the URIs, notations and preferred labels of its concepts and an implementation
+of `ControlledVocabulary`. The variants are named after the notations of the
+concepts, either through the name maps at the end of this file or, for code
+lists such as currencies, directly. The names therefore stay put when the labels
+of a newer export change. A concept missing from its name map is named after
+its English preferred label with a warning, so that its name can be fixed
+in the map before it is relied on.
+*/
+
+#[path = "src/skos.rs"]
+mod skos;
+
+use skos::{Concept, ConceptScheme};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// The directory the vocabularies are read from.
+const VOCABULARY_DIR: &str = "vocabularies";
+
+/// How the variants of a generated enum are named.
+enum Naming {
+    /// By the notation of the concept, from a map of notations to names.
+    Names(&'static [(&'static str, &'static str)]),
+    /// After the notation of the concept.
+    Notation,
+}
+
+/// A vocabulary to generate and the Rust items it is generated into.
+struct Vocabulary {
+    file: &'static str,
+    name: &'static str,
+    table: &'static str,
+    naming: Naming,
+    /// What a value of the vocabulary is called in the error of `TryFrom<&str>`,
+    /// or `None` if the conversion is written by hand.
+    description: Option<&'static str>,
+    /// Notations of other code lists, by datatype, collected into tables
+    /// of their own.
+    notation_tables: &'static [(&'static str, &'static str)],
+}
+
+const VOCABULARIES: &[Vocabulary] = &[
+    europass("accreditation.rdf", "AccreditationType", "ACCREDITATION_TYPES", ACCREDITATION_TYPE_NAMES, "a Europass accreditation type"),
+    europass("assessment.rdf", "AssessmentType", "ASSESSMENT_TYPES", ASSESSMENT_TYPE_NAMES, "a Europass assessment type"),
+    europass("com-channel.rdf", "CommunicationChannelType", "COMMUNICATION_CHANNEL_TYPES", COMMUNICATION_CHANNEL_TYPE_NAMES, "a Europass communication channel type"),
+    europass("com-channel-usg.rdf", "CommunicationChannelUsageType", "COMMUNICATION_CHANNEL_USAGE_TYPES", COMMUNICATION_CHANNEL_USAGE_TYPE_NAMES, "a Europass communication channel usage type"),
+    europass("encoding.rdf", "ContentEncodingTypes", "CONTENT_ENCODING_TYPES", CONTENT_ENCODING_TYPE_NAMES, "a Europass content encoding type"),
+    europass("education-credit.rdf", "EducationalCreditSystems", "EDUCATIONAL_CREDIT_SYSTEMS", EDUCATIONAL_CREDIT_SYSTEMS_NAMES, "a Europass educational credit system"),
+    europass("credential.rdf", "CredentialType", "CREDENTIAL_TYPES", CREDENTIAL_TYPE_NAMES, "a Europass credential type"),
+    europass("entitlement-status.rdf", "EntitlementStatus", "ENTITLEMENT_STATUSES", ENTITLEMENT_STATUS_NAMES, "a Europass entitlement status"),
+    europass("entitlement.rdf", "EntitlementType", "ENTITLEMENT_TYPES", ENTITLEMENT_TYPE_NAMES, "a Europass entitlement type"),
+    europass("learning-activity.rdf", "LearningActivityType", "LEARNING_ACTIVITY_TYPES", LEARNING_ACTIVITY_TYPE_NAMES, "a Europass learning activity type"),
+    europass("learning-opportunity.rdf", "LearningOpportunityType", "LEARNING_OPPORTUNITY_TYPES", LEARNING_OPPORTUNITY_TYPE_NAMES, "a Europass learning opportunity type"),
+    europass("learning-schedule.rdf", "LearningScheduleType", "LEARNING_SCHEDULE_TYPES", LEARNING_SCHEDULE_TYPE_NAMES, "a Europass learning schedule type"),
+    europass("learning-setting.rdf", "LearningSettingType", "LEARNING_SETTING_TYPES", LEARNING_SETTING_TYPE_NAMES, "a Europass learning setting type"),
+    europass("learning-assessment.rdf", "ModeOfLearningType", "MODE_OF_LEARNING_TYPES", MODE_OF_LEARNING_TYPE_NAMES, "a Europass mode of learning type"),
+    europass("target-group.rdf", "LearningTargetGroup", "LEARNING_TARGET_GROUPS", LEARNING_TARGET_GROUP_NAMES, "a Europass learning target group"),
+    europass("verification-status.rdf", "VerificationStatus", "VERIFICATION_STATUSES", VERIFICATION_STATUS_NAMES, "a Europass verification status"),
+    europass("verification.rdf", "VerificationType", "VERIFICATION_TYPES", VERIFICATION_TYPE_NAMES, "a Europass verification type"),
+    Vocabulary {
+        file: "measurement-unit.rdf",
+        name: "MDRunit",
+        table: "MDR_UNITS",
+        naming: Naming::Names(MDR_UNIT_NAMES),
+        description: Some("an MDR measurement unit"),
+        notation_tables: &[],
+    },
+    Vocabulary {
+        file: "currency.rdf",
+        name: "MDRcurrency",
+        table: "MDR_CURRENCIES",
+        naming: Naming::Notation,
+        description: Some("an MDR standard currency code"),
+        notation_tables: &[],
+    },
+    Vocabulary {
+        file: "language.rdf",
+        name: "Language",
+        table: "LANGUAGES",
+        naming: Naming::Names(LANGUAGE_NAMES),
+        description: None,
+        notation_tables: &[("http://publications.europa.eu/ontology/euvoc#ISO_639_1", "ISO_639_1_CODES")],
+    },
+];
+
+const fn europass(
+    file: &'static str,
+    name: &'static str,
+    table: &'static str,
+    names: &'static [(&'static str, &'static str)],
+    description: &'static str,
+) -> Vocabulary {
+    Vocabulary {
+        file,
+        name,
+        table,
+        naming: Naming::Names(names),
+        description: Some(description),
+        notation_tables: &[],
+    }
+}
+
+fn notation(concept: &Concept) -> Result<&str, String> {
+    concept
+        .notation()
+        .ok_or_else(|| format!("Concept {} has no notation without a datatype", concept.uri))
+}
+
+fn english_label(concept: &Concept) -> Result<&str, String> {
+    concept
+        .pref_label("en")
+        .ok_or_else(|| format!("Concept {} has no English preferred label", concept.uri))
+}
+
+/// Turns a label into an identifier in upper camel case,
+/// dropping diacritics and punctuation.
+fn upper_camel_case(label: &str) -> String {
+    let ascii: String = label
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ä' | 'å' => 'a',
+            'Å' | 'Ä' => 'A',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ï' => 'i',
+            'ó' | 'ô' | 'ö' | 'ø' => 'o',
+            'Ö' | 'Ø' => 'O',
+            'ú' | 'ü' => 'u',
+            c => c,
+        })
+        .collect();
+    ascii
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().expect("Words are not empty").to_ascii_uppercase();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+/// Names a concept missing from a name map after its English label.
+/// A label ending in an acronym in parentheses is named by the acronym,
+/// and other parenthesised remarks are left out.
+fn label_name(concept: &Concept) -> Result<String, String> {
+    let label = english_label(concept)?;
+    Ok(match label.strip_suffix(')').and_then(|label| label.rsplit_once('(')) {
+        Some((_, acronym)) if !acronym.is_empty() && acronym.chars().all(|c| c.is_ascii_uppercase()) => {
+            acronym.to_string()
+        }
+        _ => upper_camel_case(&without_remarks(label)),
+    })
+}
+
+/// Names the variants of a generated enum.
+fn variant_names(vocabulary: &Vocabulary, scheme: &ConceptScheme) -> Result<Vec<String>, String> {
+    let mut names = Vec::new();
+    for concept in &scheme.concepts {
+        let notation = notation(concept)?;
+        let name = match vocabulary.naming {
+            Naming::Notation => notation.to_string(),
+            Naming::Names(map) => match map.iter().find(|(mapped, _)| *mapped == notation) {
+                Some((_, name)) => name.to_string(),
+                None => {
+                    let name = label_name(concept)?;
+                    println!(
+                        "cargo:warning=Concept {} of {} has no name in build.rs and was named {}",
+                        notation, vocabulary.file, name
+                    );
+                    name
+                }
+            },
+        };
+        if !name.starts_with(|c: char| c.is_ascii_uppercase()) || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(format!("{} in {} is not a valid variant name", name, vocabulary.file));
+        }
+        if names.contains(&name) {
+            return Err(format!("{} is named twice in {}", name, vocabulary.file));
+        }
+        names.push(name);
+    }
+    Ok(names)
+}
+
+fn without_remarks(label: &str) -> String {
+    let mut depth = 0;
+    label
+        .chars()
+        .filter(|c| match c {
+            '(' => {
+                depth += 1;
+                false
+            }
+            ')' => {
+                depth -= 1;
+                false
+            }
+            _ => depth == 0,
+        })
+        .collect()
+}
+
+fn write_doc(out: &mut String, indent: &str, text: &str) {
+    for line in text.lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            writeln!(out, "{}///", indent).unwrap();
+        } else {
+            writeln!(out, "{}/// {}", indent, line).unwrap();
+        }
+    }
+}
+
+fn generate(vocabulary: &Vocabulary, scheme: &ConceptScheme, out: &mut String) -> Result<(), String> {
+    let names = variant_names(vocabulary, scheme)?;
+    let name = vocabulary.name;
+    if let Some(ref definition) = scheme.definition {
+        write_doc(out, "", definition);
+        writeln!(out, "///").unwrap();
+    }
+    writeln!(out, "/// See {}.", scheme.uri).unwrap();
+    writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]").unwrap();
+    writeln!(out, "pub enum {} {{", name).unwrap();
+    for (variant, concept) in names.iter().zip(&scheme.concepts) {
+        if let Some(ref definition) = concept.definition {
+            write_doc(out, "    ", definition);
+        }
+        if concept.deprecated {
+            if concept.definition.is_some() {
+                writeln!(out, "    ///").unwrap();
+            }
+            let replacements: Vec<String> = concept
+                .replaced_by
+                .iter()
+                .filter_map(|uri| scheme.concepts.iter().position(|concept| &concept.uri == uri))
+                .map(|index| format!("`{}`", names[index]))
+                .collect();
+            if replacements.is_empty() {
+                writeln!(out, "    /// Deprecated.").unwrap();
+            } else {
+                writeln!(out, "    /// Deprecated, replaced by {}.", replacements.join(" and ")).unwrap();
+            }
+        }
+        writeln!(out, "    {},", variant).unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "const {}: &[Concept<{}>] = &[", vocabulary.table, name).unwrap();
+    for (variant, concept) in names.iter().zip(&scheme.concepts) {
+        let labels: Vec<String> = concept
+            .pref_labels
+            .iter()
+            .map(|(language, label)| format!("({:?}, {:?})", language, label))
+            .collect();
+        let deprecation = if concept.deprecated {
+            format!(".deprecated(&{:?})", concept.replaced_by)
+        } else {
+            String::new()
+        };
+        writeln!(
+            out,
+            "    Concept::new({}::{}, {:?}, {:?}, &[{}]){},",
+            name,
+            variant,
+            concept.uri,
+            notation(concept)?,
+            labels.join(", "),
+            deprecation
+        )
+        .unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+
+    for (datatype, table) in vocabulary.notation_tables {
+        writeln!(out, "const {}: &[({}, &str)] = &[", table, name).unwrap();
+        for (variant, concept) in names.iter().zip(&scheme.concepts) {
+            for (_, notation) in concept.notations.iter().filter(|(d, _)| d.as_deref() == Some(*datatype)) {
+                writeln!(out, "    ({}::{}, {:?}),", name, variant, notation).unwrap();
+            }
+        }
+        writeln!(out, "];\n").unwrap();
+    }
+
+    let scheme_name = scheme
+        .pref_label("en")
+        .ok_or_else(|| format!("The concept scheme of {} has no English label", vocabulary.file))?;
+    writeln!(out, "impl ControlledVocabulary for {} {{", name).unwrap();
+    writeln!(out, "    const SCHEME_URI: &'static str = {:?};", scheme.uri).unwrap();
+    writeln!(out, "    const SCHEME_NAME: &'static str = {:?};", scheme_name).unwrap();
+    writeln!(
+        out,
+        "    const SCHEME_VERSION: &'static str = {:?};",
+        scheme.version.as_deref().unwrap_or("")
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "    fn concepts() -> &'static [Concept<Self>] {{").unwrap();
+    writeln!(out, "        {}", vocabulary.table).unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}\n").unwrap();
+
+    if let Some(description) = vocabulary.description {
+        writeln!(out, "impl_try_from_concept!({}, {:?});\n", name, description).unwrap();
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), String> {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", VOCABULARY_DIR);
+    let mut out = String::new();
+    for vocabulary in VOCABULARIES {
+        let path = Path::new(VOCABULARY_DIR).join(vocabulary.file);
+        println!("cargo:rerun-if-changed={}", path.display());
+        let file = std::fs::File::open(&path).map_err(|e| format!("Could not open {}: {}", path.display(), e))?;
+        let scheme = skos::read_concept_scheme(std::io::BufReader::new(file))
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        generate(vocabulary, &scheme, &mut out).map_err(|e| format!("Could not generate {}: {}", path.display(), e))?;
+    }
+    let out_dir = std::env::var("OUT_DIR").map_err(|e| e.to_string())?;
+    std::fs::write(Path::new(&out_dir).join("controlled_vocabularies.rs"), out)
+        .map_err(|e| format!("Could not write the generated vocabularies: {}", e))
+}
+
+// ------- Variant names by notation -------
+
+const ACCREDITATION_TYPE_NAMES: &[(&str, &str)] = &[
+    ("institutional-licensing", "InstitutionalLicense"),
+    ("programme-quality-assurance", "ProgramQualityAssurance"),
+    ("institutional-quality-assurance", "InstitutionalQualityAssurance"),
+    ("programme-licensing", "ProgramLicense"),
+];
+
+const ASSESSMENT_TYPE_NAMES: &[(&str, &str)] = &[
+    ("peer-assessment", "PeerAssessment"),
+    ("marked-assignment", "MarkedAssignment"),
+    ("continuous-evaluation", "ContinuousEvaluation"),
+    ("portfolio", "Portfolio"),
+    ("group-performance", "GroupPerformance"),
+    ("practical-assessment", "PracticalAssessment"),
+    ("written-examination", "WrittenExamination"),
+    ("level-of-attendance", "LevelOfAttendance"),
+    ("project-work", "ProjectWork"),
+    ("peer-review", "PeerReview"),
+    ("quiz", "Quiz"),
+    ("problem-based-learning", "ProblemBasedLearning"),
+    ("oral-examination", "OralExamination"),
+    ("artefact-assessment", "ArtefactAssessment"),
+];
+
+const COMMUNICATION_CHANNEL_TYPE_NAMES: &[(&str, &str)] = &[
+    ("post", "Post"),
+    ("email", "Email"),
+    ("mobile-phone", "MobilePhone"),
+    ("fax", "Fax"),
+    ("web", "Web"),
+];
+
+const COMMUNICATION_CHANNEL_USAGE_TYPE_NAMES: &[(&str, &str)] = &[
+    ("personal", "Personal"),
+    ("legal", "Legal"),
+    ("business", "Business"),
+    ("mobile", "Mobile"),
+];
+
+const CONTENT_ENCODING_TYPE_NAMES: &[(&str, &str)] = &[
+    ("base64", "Base64"),
+];
+
+const EDUCATIONAL_CREDIT_SYSTEMS_NAMES: &[(&str, &str)] = &[
+    ("ecvet", "VocationalSystem"),
+    ("ects", "CreditTransferSystem"),
+];
+
+const CREDENTIAL_TYPE_NAMES: &[(&str, &str)] = &[
+    ("learning-activity", "LearningActivity"),
+    ("qualification-award", "QualificationAward"),
+    ("diploma-supplement", "DiplomaSupplement"),
+    ("learning-entitlement", "LearningEntitlement"),
+    ("generic", "Generic"),
+];
+
+const ENTITLEMENT_STATUS_NAMES: &[(&str, &str)] = &[
+    ("prospective", "Prospective"),
+    ("actual", "Actual"),
+];
+
+const ENTITLEMENT_TYPE_NAMES: &[(&str, &str)] = &[
+    ("occupation", "Occupation"),
+    ("learning-opportunity", "LearningOpportunity"),
+    ("membership", "Membership"),
+];
+
+const LEARNING_ACTIVITY_TYPE_NAMES: &[(&str, &str)] = &[
+    ("lab-simulation-practice-coursework", "PracticalCoursework"),
+    ("job-experience", "JobExperience"),
+    ("volunteering", "Volunteering"),
+    ("research", "Research"),
+    ("self-motivated-study", "SelfMotivatedStudy"),
+    ("e-learning-coursework", "ELearningCoursework"),
+    ("internship", "Internship"),
+    ("apprenticeship", "Apprenticeship"),
+    ("workshop-seminar-conference", "Workshop"),
+    ("educational-programme", "EducationalProgramme"),
+    ("classroom-coursework", "ClassroomCoursework"),
+];
+
+const LEARNING_OPPORTUNITY_TYPE_NAMES: &[(&str, &str)] = &[
+    ("course", "Course"),
+    ("programme-module", "ProgrammeModule"),
+    ("mentoring", "Mentoring"),
+    ("mooc", "MOOC"),
+    ("apprenticeship", "Apprenticeship"),
+    ("study-visit", "StudyVisit"),
+    ("short-learning-programme", "ShortLearningProgramme"),
+    ("internship", "Internship"),
+    ("educational-programme", "EducationalProgramme"),
+    ("class", "Class"),
+    ("service-learning", "ServiceLearning"),
+    ("thesis", "Thesis"),
+];
+
+const LEARNING_SCHEDULE_TYPE_NAMES: &[(&str, &str)] = &[
+    ("part-time-light", "PartTimeLight"),
+    ("full-time", "FullTime"),
+    ("part-time-intensive", "PartTimeIntensive"),
+];
+
+const LEARNING_SETTING_TYPE_NAMES: &[(&str, &str)] = &[
+    ("formal-learning", "FormalLearning"),
+    ("non-formal-learning", "NonFormalLearning"),
+];
+
+const MODE_OF_LEARNING_TYPE_NAMES: &[(&str, &str)] = &[
+    ("work-based", "WorkBased"),
+    ("project-based", "ProjectBased"),
+    ("presential", "Presential"),
+    ("online", "Online"),
+    ("blended", "Blended"),
+    ("research-lab-based", "ResearchLabBased"),
+];
+
+const LEARNING_TARGET_GROUP_NAMES: &[(&str, &str)] = &[
+    ("high-achievers", "HighAchievers"),
+    ("non-native-speakers", "NonNativeSpeakers"),
+    ("requiring-employment-retraining", "RequiringEmploymentRetraining"),
+    ("in-tertiary-education-eqf-6", "InTertiaryEducationEQF6"),
+    ("completed-primary-education", "CompletedPrimaryEducation"),
+    ("in-compulsory-education", "InCompulsoryEducation"),
+    ("completed-tertiary-education-eqf-7", "CompletedTertiaryEducationEQF7"),
+    ("in-primary-education", "InPrimaryEducation"),
+    ("worked-less-than-3-years", "WorkedLessThan3Years"),
+    ("completed-tertiary-education-eqf-8", "CompletedTertiaryEducationEQF8"),
+    ("completed-compulsory-education", "CompletedCompulsoryEducation"),
+    ("in-tertiary-education-eqf-7", "InTertiaryEducationEQF7"),
+    ("in-tertiary-education-eqf-8", "InTertiaryEducationEQF8"),
+    ("migrants", "Migrants"),
+    ("worked-3-to-10-years", "Worked3to10Years"),
+    ("worked-over-10-years", "WorkedOver10Years"),
+    ("with-learning-disability", "WithLearningDisability"),
+    ("native-speakers", "NativeSpeakers"),
+    ("completed-tertiary-education-eqf-6", "CompletedTertiaryEducationEQF6"),
+    ("low-achievers", "LowAchievers"),
+];
+
+const VERIFICATION_STATUS_NAMES: &[(&str, &str)] = &[
+    ("gray", "Gray"),
+    ("green", "Green"),
+    ("red", "Red"),
+];
+
+const VERIFICATION_TYPE_NAMES: &[(&str, &str)] = &[
+    ("owner", "Owner"),
+    ("revocation", "Revocation"),
+    ("format", "Format"),
+    ("validity", "Validity"),
+    ("custom", "Custom"),
+    ("accreditation", "Accreditation"),
+    ("seal", "Seal"),
+];
+
+const MDR_UNIT_NAMES: &[(&str, &str)] = &[
+    ("2N", "Decibel"),
+    ("3C", "Manmonth"),
+    ("AD", "Byte"),
+    ("AMP", "Ampere"),
+    ("BAR", "Bar"),
+    ("BIT", "Bit"),
+    ("BQL", "Becquerel"),
+    ("C34", "Mole"),
+    ("C45", "Nanometre"),
+    ("CDL", "Candela"),
+    ("CEL", "DegreeCelsius"),
+    ("CMK", "SquareCentimetre"),
+    ("CMQ", "CubicCentimetre"),
+    ("CMT", "Centimetre"),
+    ("D30", "TeraJoule"),
+    ("E34", "GigaByte"),
+    ("GRM", "Gram"),
+    ("GTE", "GrossTonnage"),
+    ("GWH", "GigaWattHour"),
+    ("HAR", "Hectare"),
+    ("HLT", "HectoLitre"),
+    ("HTZ", "Hertz"),
+    ("HUR", "Hour"),
+    ("JOU", "Joule"),
+    ("KEL", "Kelvin"),
+    ("KGM", "Kilogram"),
+    ("KMH", "KilometrePerHour"),
+    ("KMK", "SquareKilometre"),
+    ("KTM", "Kilometre"),
+    ("KWH", "KiloWattHour"),
+    ("KWT", "KiloWatt"),
+    ("LH", "LabourHour"),
+    ("LTR", "Litre"),
+    ("MAW", "MegaWatt"),
+    ("MGM", "MilliGram"),
+    ("MIN", "Minute"),
+    ("MLT", "MilliLitre"),
+    ("MMT", "MilliMetre"),
+    ("MTK", "SquareMetre"),
+    ("MTR", "Metre"),
+    ("MTS", "MetrePerSecond"),
+    ("NEW", "Newton"),
+    ("PAL", "Pascal"),
+    ("SEC", "Second"),
+    ("TKM", "TonneKilometre"),
+    ("TNE", "Tonne"),
+    ("TOE", "TonneOfOilEquvalent"),
+    ("VLT", "Volt"),
+    ("WTT", "Watt"),
+];
+
+const LANGUAGE_NAMES: &[(&str, &str)] = &[
+    ("ABK", "Abkhazian"),
+    ("AAR", "Afar"),
+    ("AFR", "Afrikaans"),
+    ("AKA", "Akan"),
+    ("SQI", "Albanian"),
+    ("AMH", "Amharic"),
+    ("ARA", "Arabic"),
+    ("ARG", "Aragonese"),
+    ("HYE", "Armenian"),
+    ("ASM", "Assamese"),
+    ("AVA", "Avaric"),
+    ("AVE", "Avestan"),
+    ("AYM", "Aymara"),
+    ("AZE", "Azerbaijani"),
+    ("BAM", "Bambara"),
+    ("BAK", "Bashkir"),
+    ("EUS", "Basque"),
+    ("BEL", "Belarusian"),
+    ("BEN", "Bengali"),
+    ("BIS", "Bislama"),
+    ("BOS", "Bosnian"),
+    ("BRE", "Breton"),
+    ("BUL", "Bulgarian"),
+    ("MYA", "Burmese"),
+    ("CAT", "Catalan"),
+    ("CHA", "Chamorro"),
+    ("CHE", "Chechen"),
+    ("NYA", "Chichewa"),
+    ("ZHO", "Chinese"),
+    ("CHU", "ChurchSlavic"),
+    ("CHV", "Chuvash"),
+    ("COR", "Cornish"),
+    ("COS", "Corsican"),
+    ("CRE", "Cree"),
+    ("HRV", "Croatian"),
+    ("CES", "Czech"),
+    ("DAN", "Danish"),
+    ("DIV", "Dhivehi"),
+    ("NLD", "Dutch"),
+    ("DZO", "Dzongkha"),
+    ("ENG", "English"),
+    ("EPO", "Esperanto"),
+    ("EST", "Estonian"),
+    ("EWE", "Ewe"),
+    ("FAO", "Faroese"),
+    ("FIJ", "Fijian"),
+    ("FIN", "Finnish"),
+    ("FRA", "French"),
+    ("FUL", "Fulah"),
+    ("GLG", "Galician"),
+    ("LUG", "Ganda"),
+    ("KAT", "Georgian"),
+    ("DEU", "German"),
+    ("ELL", "Greek"),
+    ("GRN", "Guarani"),
+    ("GUJ", "Gujarati"),
+    ("HAT", "HaitianCreole"),
+    ("HAU", "Hausa"),
+    ("HEB", "Hebrew"),
+    ("HER", "Herero"),
+    ("HIN", "Hindi"),
+    ("HMO", "HiriMotu"),
+    ("HUN", "Hungarian"),
+    ("ISL", "Icelandic"),
+    ("IDO", "Ido"),
+    ("IBO", "Igbo"),
+    ("SMN", "InariSami"),
+    ("IND", "Indonesian"),
+    ("INA", "Interlingua"),
+    ("ILE", "Interlingue"),
+    ("IKU", "Inuktitut"),
+    ("IPK", "Inupiaq"),
+    ("GLE", "Irish"),
+    ("ITA", "Italian"),
+    ("JPN", "Japanese"),
+    ("JAV", "Javanese"),
+    ("KAL", "Kalaallisut"),
+    ("KAN", "Kannada"),
+    ("KAU", "Kanuri"),
+    ("KRL", "Karelian"),
+    ("KAS", "Kashmiri"),
+    ("KAZ", "Kazakh"),
+    ("KHM", "Khmer"),
+    ("KIK", "Kikuyu"),
+    ("KIN", "Kinyarwanda"),
+    ("KOM", "Komi"),
+    ("KON", "Kongo"),
+    ("KOR", "Korean"),
+    ("KUA", "Kuanyama"),
+    ("KUR", "Kurdish"),
+    ("KIR", "Kyrgyz"),
+    ("LAO", "Lao"),
+    ("LAT", "Latin"),
+    ("LAV", "Latvian"),
+    ("LIM", "Limburgish"),
+    ("LIN", "Lingala"),
+    ("LIT", "Lithuanian"),
+    ("LUB", "LubaKatanga"),
+    ("LTZ", "Luxembourgish"),
+    ("MKD", "Macedonian"),
+    ("MLG", "Malagasy"),
+    ("MSA", "Malay"),
+    ("MAL", "Malayalam"),
+    ("MLT", "Maltese"),
+    ("GLV", "Manx"),
+    ("MRI", "Maori"),
+    ("MAR", "Marathi"),
+    ("MAH", "Marshallese"),
+    ("MON", "Mongolian"),
+    ("CNR", "Montenegrin"),
+    ("NAU", "Nauru"),
+    ("NAV", "Navajo"),
+    ("NDO", "Ndonga"),
+    ("NEP", "Nepali"),
+    ("NDE", "NorthNdebele"),
+    ("SME", "NorthernSami"),
+    ("NOR", "Norwegian"),
+    ("NOB", "NorwegianBokmal"),
+    ("NNO", "NorwegianNynorsk"),
+    ("OCI", "Occitan"),
+    ("OJI", "Ojibwa"),
+    ("ORI", "Oriya"),
+    ("ORM", "Oromo"),
+    ("OSS", "Ossetian"),
+    ("PLI", "Pali"),
+    ("PUS", "Pashto"),
+    ("FAS", "Persian"),
+    ("POL", "Polish"),
+    ("POR", "Portuguese"),
+    ("PAN", "Punjabi"),
+    ("QUE", "Quechua"),
+    ("ROM", "Romani"),
+    ("RON", "Romanian"),
+    ("ROH", "Romansh"),
+    ("RUN", "Rundi"),
+    ("RUS", "Russian"),
+    ("SMO", "Samoan"),
+    ("SAG", "Sango"),
+    ("SAN", "Sanskrit"),
+    ("SRD", "Sardinian"),
+    ("GLA", "ScottishGaelic"),
+    ("SRP", "Serbian"),
+    ("SNA", "Shona"),
+    ("III", "SichuanYi"),
+    ("SND", "Sindhi"),
+    ("SIN", "Sinhala"),
+    ("SMS", "SkoltSami"),
+    ("SLK", "Slovak"),
+    ("SLV", "Slovenian"),
+    ("SOM", "Somali"),
+    ("NBL", "SouthNdebele"),
+    ("SOT", "SouthernSotho"),
+    ("SPA", "Spanish"),
+    ("SUN", "Sundanese"),
+    ("SWA", "Swahili"),
+    ("SSW", "Swati"),
+    ("SWE", "Swedish"),
+    ("TGL", "Tagalog"),
+    ("TAH", "Tahitian"),
+    ("TGK", "Tajik"),
+    ("TAM", "Tamil"),
+    ("TAT", "Tatar"),
+    ("TEL", "Telugu"),
+    ("THA", "Thai"),
+    ("BOD", "Tibetan"),
+    ("TIR", "Tigrinya"),
+    ("TON", "Tongan"),
+    ("TSO", "Tsonga"),
+    ("TSN", "Tswana"),
+    ("TUR", "Turkish"),
+    ("TUK", "Turkmen"),
+    ("TWI", "Twi"),
+    ("UKR", "Ukrainian"),
+    ("URD", "Urdu"),
+    ("UIG", "Uyghur"),
+    ("UZB", "Uzbek"),
+    ("VEN", "Venda"),
+    ("VIE", "Vietnamese"),
+    ("VOL", "Volapuk"),
+    ("WLN", "Walloon"),
+    ("CYM", "Welsh"),
+    ("FRY", "WesternFrisian"),
+    ("WOL", "Wolof"),
+    ("XHO", "Xhosa"),
+    ("YID", "Yiddish"),
+    ("YOR", "Yoruba"),
+    ("ZHA", "Zhuang"),
+    ("ZUL", "Zulu"),
+];
@@ -0,0 +1,574 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+/*!
+This submodule checks that the issuers of qualification credentials
+were accredited to award the qualifications.
+
+The accreditations are read from a local snapshot of the
+[Europass accreditation database](https://europa.eu/europass/en/accreditation-database),
+an [`AccreditationDatabase`], which is either a JSON file
+
+```json
+{
+  "accreditations": [
+    {
+      "id": "https://example.org/accreditations/1",
+      "type": "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/accreditation/programme-licensing",
+      "title": { "en": "Licence to award master's degrees" },
+      "organisation": "https://example.org",
+      "accreditingAgent": "https://example.org/ministry",
+      "limitQualification": "https://example.org/qualifications/msc",
+      "limitEQFLevel": "http://data.europa.eu/snb/eqf/7",
+      "limitJurisdiction": "http://publications.europa.eu/resource/authority/country/FIN",
+      "issueDate": "2020-01-01",
+      "expiryDate": "2030-01-01"
+    }
+  ]
+}
+```
+
+or an XML file with the same content in the Europass namespace.
+Both formats are defined by this crate, as the database has no export
+format of its own; the XML one follows the elements of the Europass Learning Model:
+
+```xml
+<accreditations xmlns="http://data.europa.eu/snb">
+  <accreditation id="https://example.org/accreditations/1">
+    <type uri="https://github.com/SeSodesa/map-to-europass-credential/vocabularies/accreditation/programme-licensing"/>
+    <title><text lang="en">Licence to award master's degrees</text></title>
+    <organisation idref="https://example.org"/>
+    <accreditingAgent idref="https://example.org/ministry"/>
+    <limitQualification idref="https://example.org/qualifications/msc"/>
+    <limitEQFLevel uri="http://data.europa.eu/snb/eqf/7"/>
+    <limitJurisdiction uri="http://publications.europa.eu/resource/authority/country/FIN"/>
+    <issueDate>2020-01-01</issueDate>
+    <expiryDate>2030-01-01</expiryDate>
+  </accreditation>
+</accreditations>
+```
+
+Only the limits and the expiry date are optional. The accreditations of an organisation are
+attached to its `has_accreditation` with [`AccreditationDatabase::attach`].
+
+The [`AccreditationVerifier`] reads the issuer of a credential from `issuer/@idref`
+and the awarded qualifications from `credentialSubject/achievements/learningAchievement`
+as they are written by [`VerifiableCredential::to_xml`](crate::europass_learning_model::VerifiableCredential::to_xml).
+Each achievement refers to its learning specification with `specifiedBy/@idref`
+and to its awarding process with `wasAwardedBy/@idref`. The specifications under
+`learningSpecificationReferences` that have an `eqfLevel/@uri` are qualifications, and
+the processes under `awardingProcessReferences` give the date and location of awarding
+in `awardingDate` and `awardingLocation/@uri`.
+The issuing date `issued` of the credential stands in for a missing awarding date.
+Only licences, accreditations of the types [`AccreditationType::InstitutionalLicense`]
+and [`AccreditationType::ProgramLicense`], entitle an issuer to award qualifications;
+quality assurance audits do not. A licence limited to a jurisdiction does not cover
+qualifications awarded in an unknown location.
+*/
+
+use crate::controlled_vocabularies::{AccreditationType, ControlledVocabulary, VerificationStatus, VerificationType};
+use crate::europass_learning_model::{Accreditation, Code, EuropeanLanguage, LanguageFallback, Organisation, Text};
+use crate::european_qualifications_framework::EQFLevel;
+use crate::verification::{parse_date_time, CredentialDocument, CredentialElement, Verifier};
+use chrono::{DateTime, Utc};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// The path of the awarded learning achievements in a credential.
+const ACHIEVEMENT: &str = "credentialSubject/achievements/learningAchievement";
+
+/// The path of the learning specifications the achievements refer to.
+const SPECIFICATION: &str = "learningSpecificationReferences/learningSpecification";
+
+/// The path of the awarding processes the achievements refer to.
+const AWARDING_PROCESS: &str = "awardingProcessReferences/awardingProcess";
+
+/// An accreditation as it is given in a snapshot.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccreditationRecord {
+    id: String,
+    #[serde(rename = "type")]
+    accreditation_type: String,
+    /// The title by language tag.
+    #[serde(default)]
+    title: BTreeMap<String, String>,
+    organisation: String,
+    accrediting_agent: Option<String>,
+    limit_qualification: Option<String>,
+    #[serde(rename = "limitEQFLevel")]
+    limit_eqf_level: Option<String>,
+    limit_jurisdiction: Option<String>,
+    issue_date: String,
+    expiry_date: Option<String>,
+}
+
+/// The content of a JSON snapshot.
+#[derive(Deserialize)]
+struct Snapshot {
+    accreditations: Vec<AccreditationRecord>,
+}
+
+impl AccreditationRecord {
+    fn into_accreditation(self) -> Result<Accreditation, String> {
+        let error = |e: String| format!("Could not read accreditation {}: {}", self.id, e);
+        let accreditation_type = AccreditationType::from_uri(&self.accreditation_type)
+            .ok_or_else(|| error(format!("unknown accreditation type {}…", self.accreditation_type)))?;
+        let title = Text::from_translations(
+            self.title
+                .iter()
+                .filter_map(|(language, title)| Some((language.parse::<EuropeanLanguage>().ok()?, title.clone()))),
+            &LanguageFallback::default(),
+        );
+        let eqf_level = self
+            .limit_eqf_level
+            .as_deref()
+            .map(|uri| EQFLevel::from_uri(uri).ok_or_else(|| error(format!("unknown EQF level {}…", uri))))
+            .transpose()?;
+        let mut accreditation = Accreditation::new(
+            &self.id,
+            accreditation_type,
+            title,
+            &self.organisation,
+            parse_date_time(&self.issue_date).map_err(error)?,
+            self.expiry_date.as_deref().map(parse_date_time).transpose().map_err(error)?,
+        )?;
+        accreditation.limit(
+            self.limit_qualification.as_deref(),
+            eqf_level,
+            self.limit_jurisdiction.as_deref().map(jurisdiction),
+        );
+        if let Some(agent) = &self.accrediting_agent {
+            accreditation.set_accrediting_agent(agent);
+        }
+        Ok(accreditation)
+    }
+}
+
+/// A jurisdiction as a code of the authority table it belongs to,
+/// such as the countries table of the Publications Office.
+fn jurisdiction(uri: &str) -> Code {
+    let (table, notation) = uri.rsplit_once('/').unwrap_or(("", uri));
+    let table_name = table.rsplit('/').next().unwrap_or_default();
+    Code::new(notation, table, table_name, notation, "", uri)
+}
+
+/// The value of an attribute of an element in a snapshot.
+fn attribute(element: &BytesStart, name: &[u8]) -> Result<String, String> {
+    for attribute in element.attributes() {
+        let attribute = attribute.map_err(|e| e.to_string())?;
+        if attribute.key == name {
+            let value = attribute.unescaped_value().map_err(|e| e.to_string())?;
+            return String::from_utf8(value.into_owned()).map_err(|e| e.to_string());
+        }
+    }
+    Err(format!(
+        "Element {} of the accreditation snapshot has no attribute {}…",
+        String::from_utf8_lossy(element.name()),
+        String::from_utf8_lossy(name)
+    ))
+}
+
+/// A local snapshot of the accreditation database.
+#[derive(Debug, Clone, Default)]
+pub struct AccreditationDatabase {
+    accreditations: Vec<Accreditation>,
+}
+
+impl AccreditationDatabase {
+    /// Reads a snapshot from a JSON or an XML file.
+    pub fn load(path: &Path) -> Result<AccreditationDatabase, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read accreditation snapshot {}: {}…", path.display(), e))?;
+        if content.trim_start().starts_with('<') {
+            AccreditationDatabase::from_xml(&content)
+        } else {
+            AccreditationDatabase::from_json(&content)
+        }
+    }
+
+    /// Reads a snapshot in the JSON format.
+    pub fn from_json(json: &str) -> Result<AccreditationDatabase, String> {
+        let snapshot: Snapshot =
+            serde_json::from_str(json).map_err(|e| format!("Could not parse the accreditation snapshot: {}…", e))?;
+        AccreditationDatabase::from_records(snapshot.accreditations)
+    }
+
+    /// Reads a snapshot in the XML format.
+    pub fn from_xml(xml: &str) -> Result<AccreditationDatabase, String> {
+        let mut reader = Reader::from_str(xml);
+        reader.check_end_names(true);
+        let mut buf = Vec::new();
+        let mut records = Vec::new();
+        let mut record: Option<AccreditationRecord> = None;
+        // The element whose text is being read, with the language of a title.
+        let mut text: Option<(Vec<u8>, String)> = None;
+        let mut content = String::new();
+        loop {
+            let event = reader.read_event(&mut buf).map_err(|e| {
+                format!("Could not read the accreditation snapshot at {}: {}…", reader.buffer_position(), e)
+            })?;
+            match event {
+                Event::Start(ref element) | Event::Empty(ref element) => {
+                    let name = element.local_name().to_vec();
+                    if name == b"accreditation" {
+                        record = Some(AccreditationRecord { id: attribute(element, b"id")?, ..Default::default() });
+                        continue;
+                    }
+                    let record = match record.as_mut() {
+                        Some(record) => record,
+                        None => continue,
+                    };
+                    match name.as_slice() {
+                        b"type" => record.accreditation_type = attribute(element, b"uri")?,
+                        b"organisation" => record.organisation = attribute(element, b"idref")?,
+                        b"accreditingAgent" => record.accrediting_agent = Some(attribute(element, b"idref")?),
+                        b"limitQualification" => record.limit_qualification = Some(attribute(element, b"idref")?),
+                        b"limitEQFLevel" => record.limit_eqf_level = Some(attribute(element, b"uri")?),
+                        b"limitJurisdiction" => record.limit_jurisdiction = Some(attribute(element, b"uri")?),
+                        b"text" => text = Some((name, attribute(element, b"lang")?)),
+                        b"issueDate" | b"expiryDate" => text = Some((name, String::new())),
+                        _ => {}
+                    }
+                    content.clear();
+                }
+                Event::Text(ref value) | Event::CData(ref value) if text.is_some() => {
+                    content.push_str(&value.unescape_and_decode(&reader).map_err(|e| e.to_string())?);
+                }
+                Event::End(ref element) => {
+                    if element.local_name() == b"accreditation" {
+                        records.extend(record.take());
+                    } else if let (Some((name, language)), Some(record)) = (text.take(), record.as_mut()) {
+                        let value = content.trim().to_string();
+                        match name.as_slice() {
+                            b"text" => {
+                                record.title.entry(language).or_insert(value);
+                            }
+                            b"issueDate" => record.issue_date = value,
+                            _ => record.expiry_date = Some(value),
+                        }
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        AccreditationDatabase::from_records(records)
+    }
+
+    fn from_records(records: Vec<AccreditationRecord>) -> Result<AccreditationDatabase, String> {
+        let accreditations = records
+            .into_iter()
+            .map(AccreditationRecord::into_accreditation)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(AccreditationDatabase { accreditations })
+    }
+
+    /// The number of accreditations in the snapshot.
+    pub fn len(&self) -> usize {
+        self.accreditations.len()
+    }
+
+    /// Whether the snapshot has no accreditations.
+    pub fn is_empty(&self) -> bool {
+        self.accreditations.is_empty()
+    }
+
+    /// The accreditations of the organisation with the given identifier.
+    pub fn accreditations_of<'a>(&'a self, organisation: &'a str) -> impl Iterator<Item = &'a Accreditation> + 'a {
+        self.accreditations
+            .iter()
+            .filter(move |accreditation| accreditation.organisation() == organisation)
+    }
+
+    /// Attaches the accreditations of an organisation to it
+    /// and returns the number of its accreditations.
+    pub fn attach(&self, organisation: &mut Organisation) -> Result<usize, String> {
+        let accreditations: Vec<Accreditation> = self.accreditations_of(organisation.id()).cloned().collect();
+        for accreditation in accreditations {
+            organisation.add_accreditation(accreditation)?;
+        }
+        Ok(organisation.accreditations().len())
+    }
+}
+
+/// The types of accreditations that license their organisations to award qualifications.
+const LICENSING_TYPES: [AccreditationType; 2] = [AccreditationType::InstitutionalLicense, AccreditationType::ProgramLicense];
+
+/// Checks that the issuer of a credential was licensed
+/// for the qualifications it awards on the dates they were awarded.
+pub struct AccreditationVerifier {
+    database: AccreditationDatabase,
+}
+
+impl AccreditationVerifier {
+    /// Constructs a verifier that consults the given snapshot.
+    pub fn new(database: AccreditationDatabase) -> AccreditationVerifier {
+        AccreditationVerifier { database }
+    }
+
+    /// The status of the awarding of a single qualification with a description.
+    fn qualification_status(
+        &self,
+        issuer: &str,
+        qualification: &str,
+        eqf_level: &str,
+        awarding_date: Option<&str>,
+        location: Option<&str>,
+    ) -> (VerificationStatus, String) {
+        let eqf_level = match EQFLevel::from_uri(eqf_level) {
+            Some(eqf_level) => eqf_level,
+            None => {
+                return (VerificationStatus::Red, format!("The EQF level {} of {} is not valid…", eqf_level, qualification))
+            }
+        };
+        let awarded = match awarding_date.map(parse_date_time) {
+            Some(Ok(awarded)) => awarded,
+            Some(Err(error)) => return (VerificationStatus::Red, error),
+            None => {
+                return (VerificationStatus::Red, format!("The awarding date of {} is not known…", qualification))
+            }
+        };
+        let level = format!(" at EQF level {}", eqf_level.number());
+        let place = location.map(|uri| format!(" in {}", uri)).unwrap_or_else(|| String::from(" in an unknown location"));
+        let covering: Vec<&str> = self
+            .database
+            .accreditations_of(issuer)
+            .filter(|accreditation| LICENSING_TYPES.contains(&accreditation.accreditation_type()))
+            .filter(|accreditation| accreditation.covers(qualification, Some(eqf_level), location, awarded))
+            .map(Accreditation::id)
+            .collect();
+        if covering.is_empty() {
+            (
+                VerificationStatus::Red,
+                format!("{} was not licensed to award {}{}{} on {}…", issuer, qualification, level, place, awarded.date()),
+            )
+        } else {
+            (
+                VerificationStatus::Green,
+                format!(
+                    "{} was licensed to award {}{}{} on {} by {}.",
+                    issuer,
+                    qualification,
+                    level,
+                    place,
+                    awarded.date(),
+                    covering.join(", ")
+                ),
+            )
+        }
+    }
+}
+
+impl Verifier for AccreditationVerifier {
+    fn check_type(&self) -> VerificationType {
+        VerificationType::Accreditation
+    }
+
+    fn verify(&self, credential: &CredentialDocument, _as_of: DateTime<Utc>) -> (VerificationStatus, String) {
+        // The referenced learning specifications and awarding processes by their identifiers.
+        let specifications: BTreeMap<&str, CredentialElement> = credential
+            .elements(SPECIFICATION)
+            .filter_map(|specification| specification.value("@id").map(|id| (id, specification)))
+            .collect();
+        let awarding_processes: BTreeMap<&str, CredentialElement> = credential
+            .elements(AWARDING_PROCESS)
+            .filter_map(|process| process.value("@id").map(|id| (id, process)))
+            .collect();
+        let mut awarded = Vec::new();
+        for achievement in credential.elements(ACHIEVEMENT) {
+            let qualification = match achievement.value("specifiedBy/@idref") {
+                Some(qualification) => qualification,
+                None => continue,
+            };
+            let specification = match specifications.get(qualification) {
+                Some(specification) => specification,
+                None => {
+                    return (
+                        VerificationStatus::Red,
+                        format!("The credential does not describe the learning specification {} it awards…", qualification),
+                    )
+                }
+            };
+            // Only the specifications of qualifications have an EQF level.
+            let eqf_level = match specification.value("eqfLevel/@uri") {
+                Some(eqf_level) => eqf_level,
+                None => continue,
+            };
+            let process = match achievement.value("wasAwardedBy/@idref") {
+                Some(id) => match awarding_processes.get(id) {
+                    Some(process) => Some(process),
+                    None => {
+                        return (
+                            VerificationStatus::Red,
+                            format!("The credential does not describe the awarding process {} of {}…", id, qualification),
+                        )
+                    }
+                },
+                None => None,
+            };
+            awarded.push((
+                qualification,
+                eqf_level,
+                process.and_then(|process| process.value("awardingDate")),
+                process.and_then(|process| process.value("awardingLocation/@uri")),
+            ));
+        }
+        if awarded.is_empty() {
+            return (VerificationStatus::Gray, String::from("The credential does not award a qualification…"));
+        }
+        let issuer = match credential.value("issuer/@idref") {
+            Some(issuer) => issuer,
+            None => return (VerificationStatus::Red, String::from("The credential does not name its issuer…")),
+        };
+        let issued = credential.value("issued");
+        let statuses: Vec<(VerificationStatus, String)> = awarded
+            .into_iter()
+            .map(|(qualification, eqf_level, awarding_date, location)| {
+                self.qualification_status(issuer, qualification, eqf_level, awarding_date.or(issued), location)
+            })
+            .collect();
+        let status = if statuses.iter().any(|(status, _)| *status == VerificationStatus::Red) {
+            VerificationStatus::Red
+        } else {
+            VerificationStatus::Green
+        };
+        let description = statuses.into_iter().map(|(_, d)| d).collect::<Vec<_>>().join(" ");
+        (status, description)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use chrono::TimeZone;
+
+    const ISSUER: &str = "https://example.org";
+    const MASTERS_DEGREE: &str = "https://example.org/qualifications/msc";
+
+    fn database () -> AccreditationDatabase {
+        match AccreditationDatabase::from_json(include_str!("../test_data/accreditation/snapshot.json")) {
+            Ok(database) => database,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn accreditation_snapshots () {
+        let from_json = database();
+        let from_xml = match AccreditationDatabase::from_xml(include_str!("../test_data/accreditation/snapshot.xml")) {
+            Ok(database) => database,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(from_json.len(), 4);
+        assert_eq!(from_json.accreditations, from_xml.accreditations);
+        let licence = &from_json.accreditations[0];
+        assert_eq!(licence.accreditation_type(), AccreditationType::ProgramLicense);
+        assert_eq!(licence.limit_qualification(), Some(MASTERS_DEGREE));
+        assert_eq!(licence.limit_eqf_level(), Some(EQFLevel::Level7));
+        assert_eq!(licence.limit_jurisdiction().map(Code::target_notation), Some("FIN"));
+        assert_eq!(licence.title().get(EuropeanLanguage::Finnish), Some("Lupa myöntää maisterin tutkintoja"));
+
+        let mut organisation = Organisation::new(ISSUER, Text::new(EuropeanLanguage::English, "Example University"));
+        assert_eq!(from_json.attach(&mut organisation), Ok(3));
+        assert_eq!(from_json.attach(&mut organisation), Ok(3));
+        let mut other = Organisation::new("https://example.com", Text::default());
+        assert!(other.add_accreditation(licence.clone()).is_err());
+        assert!(AccreditationDatabase::from_json("{\"accreditations\": [{\"id\": \"x\"}]}").is_err());
+    }
+
+    #[test]
+    fn accreditation_verification () {
+        use crate::controlled_vocabularies::CredentialType;
+        use crate::europass_learning_model::{
+            EuropassCredential, LearningSpecification, Person, Qualification, ValidityPeriod, VerifiableCredential,
+        };
+        use crate::national_qualification_frameworks::{FinlandNQF, NQF};
+
+        let verifier = AccreditationVerifier::new(database());
+        let as_of = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let date = |date: &str| date.parse::<chrono::NaiveDate>().unwrap();
+        // A credential written by the crate awarding the given qualifications
+        // at the given NQF levels on `awarded` in `location`.
+        let credential = |issuer: &str, qualifications: &[(&str, FinlandNQF)], awarded: Option<&str>, location: Option<&str>| {
+            let mut credential = EuropassCredential::new(
+                CredentialType::Generic,
+                Text::new(EuropeanLanguage::English, "Degree"),
+                Organisation::new(issuer, Text::new(EuropeanLanguage::English, "Example University")),
+                Person::new("urn:person:1", Text::default(), Text::default(), None),
+            );
+            let mut course = LearningSpecification::new("urn:course-unit:1", Text::new(EuropeanLanguage::English, "Course"));
+            course.set_credit_points(5.0, crate::europass_learning_model::CreditWorkloadRule::default().workload(5.0));
+            credential.add_achievement(course);
+            for (qualification, level) in qualifications {
+                let mut specification = LearningSpecification::new(qualification, Text::new(EuropeanLanguage::English, "Degree"));
+                match Qualification::from_nqf_level(false, NQF::Finland(*level)) {
+                    Ok(q) => specification.set_qualification(q),
+                    Err(e) => panic!("{}", e),
+                }
+                credential.add_achievement(specification);
+            }
+            if let Some(location) = location {
+                credential.set_awarding_location(jurisdiction(location));
+            }
+            let validity = match ValidityPeriod::from_dates(awarded.map(date), None) {
+                Ok(v) => v,
+                Err(e) => panic!("{}", e),
+            };
+            let issued = date("2024-06-01").and_hms_opt(12, 0, 0).unwrap();
+            match VerifiableCredential::new(credential, "degree-1", issued, validity).to_xml() {
+                Ok(xml) => xml,
+                Err(e) => panic!("{}", e),
+            }
+        };
+        let status = |xml: &str| match CredentialDocument::parse(xml) {
+            Ok(credential) => verifier.verify(&credential, as_of),
+            Err(e) => panic!("{}", e),
+        };
+        let finland = Some("http://publications.europa.eu/resource/authority/country/FIN");
+        let masters = [(MASTERS_DEGREE, FinlandNQF::Level7)];
+        let (green, description) = status(&credential(ISSUER, &masters, Some("2024-05-31"), finland));
+        assert_eq!(green, VerificationStatus::Green);
+        assert_eq!(
+            description,
+            "https://example.org was licensed to award https://example.org/qualifications/msc at EQF level 7 \
+            in http://publications.europa.eu/resource/authority/country/FIN on 2024-05-31 by https://example.org/accreditations/1."
+        );
+        // The issuing date stands in for an unknown awarding date.
+        assert_eq!(status(&credential(ISSUER, &masters, None, finland)).0, VerificationStatus::Green);
+        assert_eq!(
+            status(&credential(ISSUER, &[(MASTERS_DEGREE, FinlandNQF::Level6)], Some("2024-05-31"), finland)).0,
+            VerificationStatus::Red
+        );
+        // Neither accreditation had been issued in 2017.
+        assert_eq!(status(&credential(ISSUER, &masters, Some("2017-05-31"), finland)).0, VerificationStatus::Red);
+        // The licence limited to Finland does not cover awarding in an unknown location.
+        assert_eq!(status(&credential(ISSUER, &masters, Some("2024-05-31"), None)).0, VerificationStatus::Red);
+        // The operating licence without limits expired at the start of 2024,
+        // and the institutional audit in force since then does not license awarding.
+        let bachelors = [("https://example.org/qualifications/bsc", FinlandNQF::Level6)];
+        assert_eq!(status(&credential(ISSUER, &bachelors, Some("2023-05-31"), None)).0, VerificationStatus::Green);
+        assert_eq!(status(&credential(ISSUER, &bachelors, Some("2024-05-31"), None)).0, VerificationStatus::Red);
+        assert_eq!(
+            status(&credential("https://example.com", &masters, Some("2024-05-31"), finland)).0,
+            VerificationStatus::Red
+        );
+        // The details of each qualification are read from its own achievement.
+        let both = [bachelors[0], masters[0]];
+        let (red, description) = status(&credential(ISSUER, &both, Some("2024-05-31"), finland));
+        assert_eq!(red, VerificationStatus::Red);
+        assert!(description.contains("https://example.org was not licensed to award https://example.org/qualifications/bsc at EQF level 6"));
+        assert!(description.contains("https://example.org was licensed to award https://example.org/qualifications/msc at EQF level 7"));
+        assert_eq!(status(&credential(ISSUER, &both, Some("2023-05-31"), finland)).0, VerificationStatus::Green);
+        // Courses are not qualifications.
+        assert_eq!(status(&credential(ISSUER, &[], Some("2024-05-31"), finland)).0, VerificationStatus::Gray);
+        // Achievements referring to learning specifications the credential does not describe are not trusted.
+        let dangling = credential(ISSUER, &masters, Some("2024-05-31"), finland)
+            .replace(&format!("<learningSpecification id=\"{}\">", MASTERS_DEGREE), "<learningSpecification id=\"urn:other\">");
+        assert_eq!(status(&dangling).0, VerificationStatus::Red);
+    }
+}
@@ -4,694 +4,802 @@ This submodule defines the Rust representation of the controlled vocabularies
 published in the Named Authority Lists of Europass. These refer to different
 types of classifications of the objects defined in the Europass Learning Model.
 
+Every vocabulary implements [`ControlledVocabulary`], which gives the URI,
+notation and preferred labels of each concept and the URI of the concept scheme
+it belongs to. Concepts can be formed back from their URI or notation with
+`TryFrom<&str>`.
+
+The vocabularies are generated at build time from the SKOS RDF/XML files
+in the `vocabularies` directory of the crate, so updating a vocabulary is
+a matter of replacing its file there with the export of the Named Authority List.
+The files shipped with the crate are stand-ins written by hand. Languages,
+currencies and measurement units have their official URIs, but the concepts
+of the Europass Standard Lists are identified by their notation under
+[`STAND_IN_CONCEPT_NAMESPACE`], which belongs to this crate,
+until the official exports with their opaque identifiers replace them.
+
 See https://europa.eu/europass/en/europass-digital-credentials-interoperability.
 */
 
-/// This is an enumeration of the standard list of EU accreditation types.
-/// See https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://data.europa.eu/snb/accreditation/25831c2.
-pub enum AccreditationType {
-    /// A licencing procedure applied at the level of an organisation.
-    /// Institutional Licencing implies permission for the institution to operate,
-    /// and is awarded by Public Authorities or delegates thereof.
-    InstitutionalLicense,
-    /// A quality assurance procedure applied at the level of one or several programmes.
-    /// Programme Quality Assurance leads to a QA Decision,
-    /// but does not have any legal implications.
-    /// Programme Quality Assurance may be given within the context of private QA labels.
-    ProgramQualityAssurance,
-    /// A quality assurance procedure applied at the level of an organisation.
-    /// Institutional Quality Assurance leads to a QA Decision,
-    /// but does not have any legal implications.
-    /// Institutional Quality Assurance may be provided within the context of
-    /// private QA labels.
-    InstitutionalQualityAssurance,
-    /// A licencing procedure applied at the level of one or several programmes.
-    /// Institutional Licencing implies permission for an institution to provide
-    /// a specific programme, and is awarded by Public Authorities or delegates thereof.
-    ProgramLicense,
-}
+use crate::europass_learning_model::Code;
 
-/// Europass standard list of assessment types.
-/// See https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://data.europa.eu/snb/assessment/25831c2
-pub enum AssessmentType {
-    PeerAssessment,
-    MarkedAssignment,
-    ContinuousEvaluation,
-    Portfolio,
-    GroupPerformance,
-    PracticalAssessment,
-    WrittenExamination,
-    LevelOfAttendance,
-    ProjectWork,
-    PeerReview,
-    Quiz,
-    ProblemBasedLearning,
-    OralExamination,
-    ArtefactAssessment,
-}
+/// The namespace owned by this crate, in which the stand-in files of
+/// the Europass Standard Lists identify their concepts as `<list>/<notation>`
+/// and the national qualification frameworks their levels as `nqf/<country>/<notation>`.
+/// These URIs are not published by the EU and do not resolve.
+pub const STAND_IN_CONCEPT_NAMESPACE: &str = "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/";
 
-/// The Europass Standard List of Communication Channel Types provides a list of online information transmission media categories.
-/// See https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://data.europa.eu/snb/com-channel/25831c2.
-pub enum CommunicationChannelType {
-    Post,
-    Email,
-    MobilePhone,
-    Fax,
-    Web,
+/// Whether a URI is an official one rather than a stand-in under [`STAND_IN_CONCEPT_NAMESPACE`].
+pub fn is_official_uri(uri: &str) -> bool {
+    !uri.starts_with(STAND_IN_CONCEPT_NAMESPACE)
 }
 
-/// The Europass Standard List of Communication Channel Usage Types provides a list of descriptors of information transmission settings.
-/// See https://op.europa.eu/en/web/eu-vocabularies/dataset/-/resource?uri=http://publications.europa.eu/resource/dataset/com-channel-usg.
-pub enum CommunicationChannelUsageType {
-    Personal,
-    Legal,
-    Business,
-    Mobile,
+/// A concept of a controlled vocabulary: the value the concept is represented
+/// with in Rust, its notation in the concept scheme, its preferred labels,
+/// keyed by ISO 639-1 language code, and whether it has been deprecated
+/// and replaced by other concepts.
+#[derive(Debug)]
+pub struct Concept<T: 'static> {
+    value: T,
+    uri: &'static str,
+    notation: &'static str,
+    pref_labels: &'static [(&'static str, &'static str)],
+    deprecated: bool,
+    /// The URIs of the concepts replacing a deprecated concept.
+    replaced_by: &'static [&'static str],
 }
 
-/// Europass Vocabularies consist of a set of simplified, re-usable and extensible data models that capture the fundamental characteristics entities.
-/// They are primarily used in the Europass CV template, Europass Digital Credentials and Europass Learning Opportunities. Any entity wishing to be
-/// interoperable with these services can benefit by using the models.
-///
-/// See https://op.europa.eu/en/web/eu-vocabularies/dataset/-/resource?uri=http://publications.europa.eu/resource/dataset/encoding
-pub enum ContentEncodingTypes {
-    Base64,
-}
+impl<T: Copy> Concept<T> {
+    const fn new(
+        value: T,
+        uri: &'static str,
+        notation: &'static str,
+        pref_labels: &'static [(&'static str, &'static str)],
+    ) -> Concept<T> {
+        Concept {
+            value,
+            uri,
+            notation,
+            pref_labels,
+            deprecated: false,
+            replaced_by: &[],
+        }
+    }
 
-///  The Europass Standard List of Educational Credit Systems distinguishes between existing and widely used European credit systems, e.g. for higher education (ECTS) and for vocational education and training (ECVET).
-///  See https://op.europa.eu/en/web/eu-vocabularies/dataset/-/resource?uri=http://publications.europa.eu/resource/dataset/education-credit.
-pub enum EducationalCreditSystems {
-    /// European credit system for vocational education and training
-    VocationalSystem,
-    /// European Credit Transfer System
-    CreditTransferSystem,
-}
+    /// Marks the concept as deprecated and replaced by the concepts
+    /// with the given URIs.
+    const fn deprecated(self, replaced_by: &'static [&'static str]) -> Concept<T> {
+        Concept {
+            deprecated: true,
+            replaced_by,
+            ..self
+        }
+    }
 
-/// An enumeration of the standard Europass credential types.
-/// See https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://data.europa.eu/snb/credential/25831c2.
-pub enum CredentialType {
-    /// Represents a credential which describes that an activity has been or is being done
-    LearningActivity,
-    /// Represents the award of a qualification by an institution that is accredited to do so, confirmed by its inclusion in the Europass Accreditation Database.
-    QualificationAward,
-    /// Represents the award of a higher education qualification by an institution that is accredited to do so, confirmed by its inclusion in the Europass Accreditation Database, and including all diploma supplement information.
-    DiplomaSupplement,
-    /// Represents a credential which describes that the user has received a right.
-    LearningEntitlement,
-    /// This is the default Europass credential type.
-    Generic,
-}
+    /// The URI of the concept.
+    pub fn uri(&self) -> &'static str {
+        self.uri
+    }
 
-/// An enumeration of standard Europass entitlement statuses.
-/// See https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://data.europa.eu/snb/entitlement-status/25831c2
-pub enum EntitlementStatus {
-    /// A prospective entitlement awards the right to apply for a (specific or class of)
-    /// learning opportunity, employment or membership.
-    Prospective,
-    /// An actual entitlement grants the right to pursue a learning opportunity,
-    /// employment or membership automatically.
-    Actual,
-}
+    /// The notation of the concept in its concept scheme.
+    pub fn notation(&self) -> &'static str {
+        self.notation
+    }
 
-/// An enumeration of standard Europass entitlement types.
-/// See https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://data.europa.eu/snb/entitlement/25831c2
-pub enum EntitlementType {
-    Occupation,
-    LearningOpportunity,
-    Membership
+    /// The preferred labels of the concept with their ISO 639-1 language codes.
+    pub fn pref_labels(&self) -> &'static [(&'static str, &'static str)] {
+        self.pref_labels
+    }
 }
 
-/// The Europass Standard List of Learning Activity Types categorises and labels activities that learners can engage with in order to achieve pre-defined learning outcomes.
-/// See https://op.europa.eu/en/web/eu-vocabularies/dataset/-/resource?uri=http://publications.europa.eu/resource/dataset/learning-activity.
-pub enum LearningActivityType {
-    /// Lab / simulation / practice coursework
-    PracticalCoursework,
-    JobExperience,
-    Volunteering,
-    Research,
-    SelfMotivatedStudy,
-    ELearningCoursework,
-    Internship,
-    Apprenticeship,
-    /// Workshop, seminar or conference
-    Workshop,
-    EducationalProgramme,
-    ClassroomCoursework,
-}
+/// A controlled vocabulary published as a concept scheme
+/// in the Named Authority Lists. Implementors only list their concepts;
+/// the rest of the methods are derived from that list.
+pub trait ControlledVocabulary: Copy + PartialEq + 'static {
+    /// The URI of the concept scheme.
+    const SCHEME_URI: &'static str;
+
+    /// The name of the concept scheme.
+    const SCHEME_NAME: &'static str;
+
+    /// The version of the concept scheme the vocabulary was generated from.
+    const SCHEME_VERSION: &'static str;
+
+    /// Every concept of the vocabulary.
+    fn concepts() -> &'static [Concept<Self>];
+
+    /// The concept this value represents.
+    fn concept(&self) -> &'static Concept<Self> {
+        Self::concepts()
+            .iter()
+            .find(|concept| concept.value == *self)
+            .expect("Every value of a controlled vocabulary has a concept")
+    }
+
+    /// The notation of this concept in its concept scheme.
+    fn notation(&self) -> &'static str {
+        self.concept().notation
+    }
+
+    /// The URI of this concept.
+    fn uri(&self) -> &'static str {
+        self.concept().uri
+    }
+
+    /// The preferred label of this concept in the language
+    /// with the given ISO 639-1 code, if the concept has one.
+    fn pref_label(&self, language: &str) -> Option<&'static str> {
+        self.concept()
+            .pref_labels
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(language))
+            .map(|(_, label)| *label)
+    }
+
+    /// Whether this concept has been deprecated in its concept scheme.
+    fn is_deprecated(&self) -> bool {
+        self.concept().deprecated
+    }
+
+    /// The concepts this deprecated concept has been replaced by.
+    fn replaced_by(&self) -> Vec<Self> {
+        self.concept()
+            .replaced_by
+            .iter()
+            .filter_map(|uri| Self::from_uri(uri))
+            .collect()
+    }
+
+    /// The current concept to use in place of this one: the concept itself
+    /// if it is not deprecated, or else the first concept it is replaced by,
+    /// followed through any further replacements. `None` if there is
+    /// no current replacement for a deprecated concept.
+    fn replacement(&self) -> Option<Self> {
+        let mut current = *self;
+        for _ in 0..Self::concepts().len() {
+            if !current.is_deprecated() {
+                return Some(current);
+            }
+            current = *current.replaced_by().first()?;
+        }
+        None
+    }
+
+    /// Finds the concept with the given URI.
+    fn from_uri(uri: &str) -> Option<Self> {
+        Self::concepts()
+            .iter()
+            .find(|concept| concept.uri == uri)
+            .map(|concept| concept.value)
+    }
 
-/// The Europass Standard List of Learning Opportunity Types provides an array of potential delivery formats of organised learning.
-/// See https://op.europa.eu/en/web/eu-vocabularies/dataset/-/resource?uri=http://publications.europa.eu/resource/dataset/learning-opportunity
-pub enum LearningOpportunityType {
-    Course,
-    ProgrammeModule,
-    Mentoring,
-    MOOC,
-    Apprenticeship,
-    StudyVisit,
-    ShortLearningProgramme,
-    Internship,
-    EducationalProgramme,
-    Class,
-    ServiceLearning,
-    Thesis,
+    /// Finds the concept with the given notation.
+    fn from_notation(notation: &str) -> Option<Self> {
+        Self::concepts()
+            .iter()
+            .find(|concept| concept.notation == notation)
+            .map(|concept| concept.value)
+    }
+
+    /// Converts this concept into a Europass `Code`, named in English.
+    /// Warns if the concept has been deprecated.
+    fn to_code(&self) -> Code {
+        warn_if_deprecated(*self);
+        Code::new(
+            self.notation(),
+            Self::SCHEME_URI,
+            Self::SCHEME_NAME,
+            self.pref_label("en").unwrap_or_else(|| self.notation()),
+            "",
+            self.uri(),
+        )
+    }
 }
 
-///  The Europass Standard List of Learning Schedule Types allows, in a standardised way, the indication of the intensity of learning, from light part time to full time engagement.
-///  See https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://data.europa.eu/snb/learning-schedule/25831c2
-pub enum LearningScheduleType {
-    /// Part time light (less than 8 hours)
-    PartTimeLight,
-    /// Full time (more then 30 hours)
-    FullTime,
-    /// Part time intensive (8 to 30 hours)
-    PartTimeIntensive,
+/// Logs a warning if the given concept has been deprecated,
+/// naming its replacement if it has one.
+fn warn_if_deprecated<T: ControlledVocabulary>(value: T) {
+    if !value.is_deprecated() {
+        return;
+    }
+    match value.replacement() {
+        Some(replacement) => log::warn!(
+            "The concept {} of {} (version {}) is deprecated, use {} instead",
+            value.notation(),
+            T::SCHEME_NAME,
+            T::SCHEME_VERSION,
+            replacement.notation()
+        ),
+        None => log::warn!(
+            "The concept {} of {} (version {}) is deprecated and has no replacement",
+            value.notation(),
+            T::SCHEME_NAME,
+            T::SCHEME_VERSION
+        ),
+    }
 }
 
-/// The Europass Standard List of Learning Setting Types provides terms to distinguish between formal learning taking place in a more organised and structured environment,
-/// and non-formal learning that is more flexible and self-paced, however still involves some form of learning support.
-///
-/// See https://op.europa.eu/en/web/eu-vocabularies/dataset/-/resource?uri=http://publications.europa.eu/resource/dataset/learning-setting
-pub enum LearningSettingType {
-    FormalLearning,
-    NonFormalLearning,
+/// Implements `TryFrom<&str>` for a controlled vocabulary,
+/// accepting either the URI or the notation of a concept.
+/// Warns if the concept has been deprecated.
+macro_rules! impl_try_from_concept {
+    ($vocabulary:ty, $description:expr) => {
+        impl std::convert::TryFrom<&str> for $vocabulary {
+            type Error = String;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                let concept = <$vocabulary as ControlledVocabulary>::from_uri(value)
+                    .or_else(|| <$vocabulary as ControlledVocabulary>::from_notation(value))
+                    .ok_or_else(|| format!("Could not form {} from string {}…", $description, value))?;
+                warn_if_deprecated(concept);
+                Ok(concept)
+            }
+        }
+    };
 }
 
+include!(concat!(env!("OUT_DIR"), "/controlled_vocabularies.rs"));
+
 /// This refers to the `VerificationType` in the documentation.
 /// Therefore this type is provided simply as an indirect means of accessing
 /// an instance of `VerificationType`.
 pub struct SupervisionAndVerificationType (VerificationType);
 
-///  The Europass Standard List of Modes of Learning and Assessment provides a list of distinct means by which learning and assessment can be carried out.
-///  See https://op.europa.eu/en/web/eu-vocabularies/dataset/-/resource?uri=http://publications.europa.eu/resource/dataset/learning-assessment
-pub enum ModeOfLearningType {
-    WorkBased,
-    ProjectBased,
-    Presential,
-    Online,
-    Blended,
-    ResearchLabBased,
-}
+/// The URI of the language authority table in the Named Authority Lists.
+pub const LANGUAGE_SCHEME_URI: &str = "http://publications.europa.eu/resource/authority/language";
+
+impl Language {
+    /// The two letter ISO 639-1 code of this language, if it has one.
+    pub fn iso_639_1(&self) -> Option<&'static str> {
+        ISO_639_1_CODES
+            .iter()
+            .find(|(language, _)| language == self)
+            .map(|(_, code)| *code)
+    }
+
+    /// The three letter ISO 639-3 code of this language.
+    pub fn iso_639_3(&self) -> String {
+        self.authority_code().to_lowercase()
+    }
+
+    /// The authority code of this language in the language authority table.
+    pub fn authority_code(&self) -> &'static str {
+        self.notation()
+    }
 
-/// The Europass Standard List of Target Groups provides a custom vocabulary to describe groups of learners that a learning opportunity, and corresponding credential, is tailored and/or best suited for.
-/// See https://op.europa.eu/en/web/eu-vocabularies/dataset/-/resource?uri=http://publications.europa.eu/resource/dataset/target-group
-pub enum LearningTargetGroup {
-    HighAchievers,
-    NonNativeSpeakers,
-    RequiringEmploymentRetraining,
-    InTertiaryEducationEQF6,
-    CompletedPrimaryEducation,
-    InCompulsoryEducation,
-    CompletedTertiaryEducationEQF7,
-    InPrimaryEducation,
-    WorkedLessThan3Years,
-    CompletedTertiaryEducationEQF8,
-    CompletedCompulsoryEducation,
-    InTertiaryEducationEQF7,
-    InTertiaryEducationEQF8,
-    Migrants,
-    Worked3to10Years,
-    WorkedOver10Years,
-    WithLearningDisability,
-    NativeSpeakers,
-    CompletedTertiaryEducationEQF6,
-    LowAchievers,
+    /// The URI of this language in the language authority table.
+    pub fn nal_uri(&self) -> String {
+        self.uri().to_string()
+    }
 }
 
-/// An enumeration of the standard Europass verification statuses.
-/// See https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://data.europa.eu/snb/verification-status/25831c2.
-pub enum VerificationStatus {
-    Gray,
-    Green,
-    Red,
+impl std::convert::TryFrom<&str> for Language {
+    type Error = String;
+
+    /// Accepts ISO 639-1 and ISO 639-3 codes in either case,
+    /// URIs of the language authority table and SISU language URNs
+    /// of the form `urn:code:language:fi`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let code = value
+            .strip_prefix("urn:code:language:")
+            .or_else(|| value.strip_prefix(LANGUAGE_SCHEME_URI).and_then(|code| code.strip_prefix('/')))
+            .unwrap_or(value);
+        ISO_639_1_CODES
+            .iter()
+            .find(|(_, iso_639_1)| iso_639_1.eq_ignore_ascii_case(code))
+            .map(|(language, _)| *language)
+            .or_else(|| Language::from_notation(&code.to_uppercase()))
+            .ok_or_else(|| format!("Could not form an EU language from string {}…", value))
+    }
 }
 
-/// An enumeration of the standard Europass verification statuses.
-/// See https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://data.europa.eu/snb/verification/25831c2.
-pub enum VerificationType {
-    /// Check whether the the person presenting the credential is the owner.
-    /// Includes:
-    ///
-    /// (a) Comparison of the Name and Date of Birth written in
-    ///     the credential with the name and date of birth stored
-    ///     in a person's national eID.
-    Owner,
-    /// Check whether the the credential has been revoked.
-    /// Includes:
-    /// (a) check if revocation certificate has been published to address indicated in
-    ///     the credential.
-    /// (b) Check if revocation certificate has been published to national revocation list
-    /// (c) Check if revocation certificate has been published to EU revocation list
-    Revocation,
-    /// Check as to whether the credential has been issued according to
-    /// the standard specified for that credential-type. Includes:
-    ///
-    /// (a) technical check of the XML file for validity
-    /// (b) check if credential has basic required fields for all credentials
-    /// (c) check if credential has followed the rules indicated against
-    ///     the application profile for that specific credential-type stored at
-    ///     data.europa.eu
-    Format,
-    /// Check whether the credential is still valid. Includes:
-    ///
-    /// (a) check against expiry information contained with credential
-    Validity,
-    /// Custom check defined by a third-party credential verifier
-    Custom,
-    /// Check whether the awarding body is authorised to issue the credential.
-    /// Includes:
-    ///
-    /// (a) check if credential is an NQF qualification award.
-    /// (b) If it is, check that institution or qualification is in
-    ///     the accreditation database by comparing UID of qualification
-    ///     and/or institution with that in the accreditation database
-    Accreditation,
-    /// Check as to whether the credential has been tampered with. Includes:
-    ///
-    /// (a) check that the eSeal is valid
-    Seal,
+/// The ISO 4217 numeric codes and minor units of the currencies
+/// in current use. Minor units are not defined for precious metals,
+/// special drawing rights and other funds codes.
+const ISO_4217: &[(MDRcurrency, u16, Option<u8>)] = &[
+    (MDRcurrency::AED, 784, Some(2)),
+    (MDRcurrency::AFN, 971, Some(2)),
+    (MDRcurrency::ALL, 8, Some(2)),
+    (MDRcurrency::AMD, 51, Some(2)),
+    (MDRcurrency::ANG, 532, Some(2)),
+    (MDRcurrency::AOA, 973, Some(2)),
+    (MDRcurrency::ARS, 32, Some(2)),
+    (MDRcurrency::AUD, 36, Some(2)),
+    (MDRcurrency::AWG, 533, Some(2)),
+    (MDRcurrency::AZN, 944, Some(2)),
+    (MDRcurrency::BAM, 977, Some(2)),
+    (MDRcurrency::BBD, 52, Some(2)),
+    (MDRcurrency::BDT, 50, Some(2)),
+    (MDRcurrency::BGN, 975, Some(2)),
+    (MDRcurrency::BHD, 48, Some(3)),
+    (MDRcurrency::BIF, 108, Some(0)),
+    (MDRcurrency::BMD, 60, Some(2)),
+    (MDRcurrency::BND, 96, Some(2)),
+    (MDRcurrency::BOB, 68, Some(2)),
+    (MDRcurrency::BOV, 984, Some(2)),
+    (MDRcurrency::BRL, 986, Some(2)),
+    (MDRcurrency::BSD, 44, Some(2)),
+    (MDRcurrency::BTN, 64, Some(2)),
+    (MDRcurrency::BWP, 72, Some(2)),
+    (MDRcurrency::BYN, 933, Some(2)),
+    (MDRcurrency::BZD, 84, Some(2)),
+    (MDRcurrency::CAD, 124, Some(2)),
+    (MDRcurrency::CDF, 976, Some(2)),
+    (MDRcurrency::CHE, 947, Some(2)),
+    (MDRcurrency::CHF, 756, Some(2)),
+    (MDRcurrency::CHW, 948, Some(2)),
+    (MDRcurrency::CLF, 990, Some(4)),
+    (MDRcurrency::CLP, 152, Some(0)),
+    (MDRcurrency::CNY, 156, Some(2)),
+    (MDRcurrency::COP, 170, Some(2)),
+    (MDRcurrency::COU, 970, Some(2)),
+    (MDRcurrency::CRC, 188, Some(2)),
+    (MDRcurrency::CUP, 192, Some(2)),
+    (MDRcurrency::CVE, 132, Some(2)),
+    (MDRcurrency::CZK, 203, Some(2)),
+    (MDRcurrency::DJF, 262, Some(0)),
+    (MDRcurrency::DKK, 208, Some(2)),
+    (MDRcurrency::DOP, 214, Some(2)),
+    (MDRcurrency::DZD, 12, Some(2)),
+    (MDRcurrency::EGP, 818, Some(2)),
+    (MDRcurrency::ERN, 232, Some(2)),
+    (MDRcurrency::ETB, 230, Some(2)),
+    (MDRcurrency::EUR, 978, Some(2)),
+    (MDRcurrency::FJD, 242, Some(2)),
+    (MDRcurrency::FKP, 238, Some(2)),
+    (MDRcurrency::GBP, 826, Some(2)),
+    (MDRcurrency::GEL, 981, Some(2)),
+    (MDRcurrency::GHS, 936, Some(2)),
+    (MDRcurrency::GIP, 292, Some(2)),
+    (MDRcurrency::GMD, 270, Some(2)),
+    (MDRcurrency::GNF, 324, Some(0)),
+    (MDRcurrency::GTQ, 320, Some(2)),
+    (MDRcurrency::GYD, 328, Some(2)),
+    (MDRcurrency::HKD, 344, Some(2)),
+    (MDRcurrency::HNL, 340, Some(2)),
+    (MDRcurrency::HTG, 332, Some(2)),
+    (MDRcurrency::HUF, 348, Some(2)),
+    (MDRcurrency::IDR, 360, Some(2)),
+    (MDRcurrency::ILS, 376, Some(2)),
+    (MDRcurrency::INR, 356, Some(2)),
+    (MDRcurrency::IQD, 368, Some(3)),
+    (MDRcurrency::IRR, 364, Some(2)),
+    (MDRcurrency::ISK, 352, Some(0)),
+    (MDRcurrency::JMD, 388, Some(2)),
+    (MDRcurrency::JOD, 400, Some(3)),
+    (MDRcurrency::JPY, 392, Some(0)),
+    (MDRcurrency::KES, 404, Some(2)),
+    (MDRcurrency::KGS, 417, Some(2)),
+    (MDRcurrency::KHR, 116, Some(2)),
+    (MDRcurrency::KMF, 174, Some(0)),
+    (MDRcurrency::KPW, 408, Some(2)),
+    (MDRcurrency::KRW, 410, Some(0)),
+    (MDRcurrency::KWD, 414, Some(3)),
+    (MDRcurrency::KYD, 136, Some(2)),
+    (MDRcurrency::KZT, 398, Some(2)),
+    (MDRcurrency::LAK, 418, Some(2)),
+    (MDRcurrency::LBP, 422, Some(2)),
+    (MDRcurrency::LKR, 144, Some(2)),
+    (MDRcurrency::LRD, 430, Some(2)),
+    (MDRcurrency::LSL, 426, Some(2)),
+    (MDRcurrency::LYD, 434, Some(3)),
+    (MDRcurrency::MAD, 504, Some(2)),
+    (MDRcurrency::MDL, 498, Some(2)),
+    (MDRcurrency::MGA, 969, Some(2)),
+    (MDRcurrency::MKD, 807, Some(2)),
+    (MDRcurrency::MMK, 104, Some(2)),
+    (MDRcurrency::MNT, 496, Some(2)),
+    (MDRcurrency::MOP, 446, Some(2)),
+    (MDRcurrency::MRU, 929, Some(2)),
+    (MDRcurrency::MUR, 480, Some(2)),
+    (MDRcurrency::MVR, 462, Some(2)),
+    (MDRcurrency::MWK, 454, Some(2)),
+    (MDRcurrency::MXN, 484, Some(2)),
+    (MDRcurrency::MXV, 979, Some(2)),
+    (MDRcurrency::MYR, 458, Some(2)),
+    (MDRcurrency::MZN, 943, Some(2)),
+    (MDRcurrency::NAD, 516, Some(2)),
+    (MDRcurrency::NGN, 566, Some(2)),
+    (MDRcurrency::NIO, 558, Some(2)),
+    (MDRcurrency::NOK, 578, Some(2)),
+    (MDRcurrency::NPR, 524, Some(2)),
+    (MDRcurrency::NZD, 554, Some(2)),
+    (MDRcurrency::OMR, 512, Some(3)),
+    (MDRcurrency::PAB, 590, Some(2)),
+    (MDRcurrency::PEN, 604, Some(2)),
+    (MDRcurrency::PGK, 598, Some(2)),
+    (MDRcurrency::PHP, 608, Some(2)),
+    (MDRcurrency::PKR, 586, Some(2)),
+    (MDRcurrency::PLN, 985, Some(2)),
+    (MDRcurrency::PYG, 600, Some(0)),
+    (MDRcurrency::QAR, 634, Some(2)),
+    (MDRcurrency::RON, 946, Some(2)),
+    (MDRcurrency::RSD, 941, Some(2)),
+    (MDRcurrency::RUB, 643, Some(2)),
+    (MDRcurrency::RWF, 646, Some(0)),
+    (MDRcurrency::SAR, 682, Some(2)),
+    (MDRcurrency::SBD, 90, Some(2)),
+    (MDRcurrency::SCR, 690, Some(2)),
+    (MDRcurrency::SDG, 938, Some(2)),
+    (MDRcurrency::SEK, 752, Some(2)),
+    (MDRcurrency::SGD, 702, Some(2)),
+    (MDRcurrency::SHP, 654, Some(2)),
+    (MDRcurrency::SLE, 925, Some(2)),
+    (MDRcurrency::SOS, 706, Some(2)),
+    (MDRcurrency::SRD, 968, Some(2)),
+    (MDRcurrency::SSP, 728, Some(2)),
+    (MDRcurrency::STN, 930, Some(2)),
+    (MDRcurrency::SVC, 222, Some(2)),
+    (MDRcurrency::SYP, 760, Some(2)),
+    (MDRcurrency::SZL, 748, Some(2)),
+    (MDRcurrency::THB, 764, Some(2)),
+    (MDRcurrency::TJS, 972, Some(2)),
+    (MDRcurrency::TMT, 934, Some(2)),
+    (MDRcurrency::TND, 788, Some(3)),
+    (MDRcurrency::TOP, 776, Some(2)),
+    (MDRcurrency::TRY, 949, Some(2)),
+    (MDRcurrency::TTD, 780, Some(2)),
+    (MDRcurrency::TWD, 901, Some(2)),
+    (MDRcurrency::TZS, 834, Some(2)),
+    (MDRcurrency::UAH, 980, Some(2)),
+    (MDRcurrency::UGX, 800, Some(0)),
+    (MDRcurrency::USD, 840, Some(2)),
+    (MDRcurrency::USN, 997, Some(2)),
+    (MDRcurrency::UYI, 940, Some(0)),
+    (MDRcurrency::UYU, 858, Some(2)),
+    (MDRcurrency::UYW, 927, Some(4)),
+    (MDRcurrency::UZS, 860, Some(2)),
+    (MDRcurrency::VED, 926, Some(2)),
+    (MDRcurrency::VES, 928, Some(2)),
+    (MDRcurrency::VND, 704, Some(0)),
+    (MDRcurrency::VUV, 548, Some(0)),
+    (MDRcurrency::WST, 882, Some(2)),
+    (MDRcurrency::XAF, 950, Some(0)),
+    (MDRcurrency::XAG, 961, None),
+    (MDRcurrency::XAU, 959, None),
+    (MDRcurrency::XBA, 955, None),
+    (MDRcurrency::XBB, 956, None),
+    (MDRcurrency::XBC, 957, None),
+    (MDRcurrency::XBD, 958, None),
+    (MDRcurrency::XCD, 951, Some(2)),
+    (MDRcurrency::XDR, 960, None),
+    (MDRcurrency::XOF, 952, Some(0)),
+    (MDRcurrency::XPD, 964, None),
+    (MDRcurrency::XPF, 953, Some(0)),
+    (MDRcurrency::XPT, 962, None),
+    (MDRcurrency::XSU, 994, None),
+    (MDRcurrency::XTS, 963, None),
+    (MDRcurrency::XUA, 965, None),
+    (MDRcurrency::XXX, 999, None),
+    (MDRcurrency::YER, 886, Some(2)),
+    (MDRcurrency::ZAR, 710, Some(2)),
+    (MDRcurrency::ZMW, 967, Some(2)),
+    (MDRcurrency::ZWG, 924, Some(2)),
+];
+
+impl MDRcurrency {
+    fn iso_4217(&self) -> Option<&'static (MDRcurrency, u16, Option<u8>)> {
+        ISO_4217.iter().find(|(currency, _, _)| currency == self)
+    }
+
+    /// The alphabetic ISO 4217 code of this currency, such as `EUR`.
+    pub fn alphabetic_code(&self) -> &'static str {
+        self.notation()
+    }
+
+    /// The numeric ISO 4217 code of this currency, such as `978` for the euro.
+    /// Only known for the currencies in current use.
+    pub fn numeric_code(&self) -> Option<u16> {
+        self.iso_4217().map(|(_, numeric_code, _)| *numeric_code)
+    }
+
+    /// The number of digits after the decimal separator used for
+    /// amounts of this currency, such as 2 for the euro and 0 for the yen.
+    pub fn minor_units(&self) -> Option<u8> {
+        self.iso_4217().and_then(|(_, _, minor_units)| *minor_units)
+    }
+
+    /// The name of this currency in the given language,
+    /// falling back to the English name.
+    pub fn name(&self, language: &str) -> &'static str {
+        self.pref_label(language)
+            .or_else(|| self.pref_label("en"))
+            .unwrap_or_else(|| self.notation())
+    }
+
+    /// The currency with the given numeric ISO 4217 code.
+    pub fn from_numeric_code(numeric_code: u16) -> Option<MDRcurrency> {
+        ISO_4217
+            .iter()
+            .find(|(_, code, _)| *code == numeric_code)
+            .map(|(currency, _, _)| *currency)
+    }
 }
 
-/// The Measurement unit authority table is a controlled vocabulary
-/// listing units of measurement with their authority codes.
-/// The labels and symbols are given in all official EU languages.
-/// If available, the codes are based on the code list recommendation
-/// N°. 20 "Codes for Units of Measure Used in International Trade"
-/// (as revised periodically) maintained and published by UNECE through
-/// its Center for Trade Facilitation and Electronic Business (UN/CEFACT).
-/// The Measurement unit authority table is maintained by the Publications Office
-/// of the European Union on the EU Vocabularies website.
-pub enum MDRunit {
-    Decibel,
-    Manmonth,
-    Byte,
-    Ampere,
-    Bar,
-    Bit,
-    Becquerel,
-    Mole,
-    Nanometre,
-    Candela,
-    DegreeCelsius,
-    SquareCentimetre,
-    CubicCentimetre,
-    Centimetre,
-    TeraJoule,
-    GigaByte,
-    Gram,
-    GrossTonnage,
-    GigaWattHour,
-    Hectare,
-    HectoLitre,
-    Hertz,
-    Hour,
-    Joule,
-    Kelvin,
-    Kilogram,
-    KilometrePerHour,
-    SquareKilometre,
-    Kilometre,
-    KiloWattHour,
-    KiloWatt,
-    LabourHour,
-    Litre,
-    MegaWatt,
-    MilliGram,
-    Minute,
-    MilliLitre,
-    MilliMetre,
-    SquareMetre,
-    Metre,
-    MetrePerSecond,
-    Newton,
-    Pascal,
-    Second,
-    TonneKilometre,
-    Tonne,
-    TonneOfOilEquvalent,
-    Volt,
-    Watt
+/// A physical or economic quantity measured in units
+/// of the MDR Measurement unit Named Authority List.
+/// Measures of the same dimension can be converted to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Time,
+    /// Work effort, such as labour hours.
+    Effort,
+    Length,
+    Area,
+    Volume,
+    Mass,
+    Energy,
+    Power,
+    Speed,
+    Information,
+    Pressure,
+    Temperature,
 }
 
-impl std::convert::TryFrom<&str> for MDRunit {
-    type Error = &'static str;
+/// The dimension of each convertible unit with the factor and offset
+/// converting a value in the unit to the base unit of the dimension,
+/// so that `base = value * factor + offset`.
+/// A manmonth is taken to be 160 labour hours,
+/// that is 20 working days of 8 hours.
+const UNIT_CONVERSIONS: &[(MDRunit, Dimension, f64, f64)] = &[
+    (MDRunit::Second, Dimension::Time, 1.0, 0.0),
+    (MDRunit::Minute, Dimension::Time, 60.0, 0.0),
+    (MDRunit::Hour, Dimension::Time, 3600.0, 0.0),
+    (MDRunit::LabourHour, Dimension::Effort, 1.0, 0.0),
+    (MDRunit::Manmonth, Dimension::Effort, 160.0, 0.0),
+    (MDRunit::Metre, Dimension::Length, 1.0, 0.0),
+    (MDRunit::Nanometre, Dimension::Length, 1e-9, 0.0),
+    (MDRunit::MilliMetre, Dimension::Length, 1e-3, 0.0),
+    (MDRunit::Centimetre, Dimension::Length, 1e-2, 0.0),
+    (MDRunit::Kilometre, Dimension::Length, 1e3, 0.0),
+    (MDRunit::SquareMetre, Dimension::Area, 1.0, 0.0),
+    (MDRunit::SquareCentimetre, Dimension::Area, 1e-4, 0.0),
+    (MDRunit::Hectare, Dimension::Area, 1e4, 0.0),
+    (MDRunit::SquareKilometre, Dimension::Area, 1e6, 0.0),
+    (MDRunit::Litre, Dimension::Volume, 1.0, 0.0),
+    (MDRunit::MilliLitre, Dimension::Volume, 1e-3, 0.0),
+    (MDRunit::CubicCentimetre, Dimension::Volume, 1e-3, 0.0),
+    (MDRunit::HectoLitre, Dimension::Volume, 1e2, 0.0),
+    (MDRunit::Kilogram, Dimension::Mass, 1.0, 0.0),
+    (MDRunit::MilliGram, Dimension::Mass, 1e-6, 0.0),
+    (MDRunit::Gram, Dimension::Mass, 1e-3, 0.0),
+    (MDRunit::Tonne, Dimension::Mass, 1e3, 0.0),
+    (MDRunit::Joule, Dimension::Energy, 1.0, 0.0),
+    (MDRunit::KiloWattHour, Dimension::Energy, 3.6e6, 0.0),
+    (MDRunit::GigaWattHour, Dimension::Energy, 3.6e12, 0.0),
+    (MDRunit::TeraJoule, Dimension::Energy, 1e12, 0.0),
+    (MDRunit::TonneOfOilEquvalent, Dimension::Energy, 4.1868e10, 0.0),
+    (MDRunit::Watt, Dimension::Power, 1.0, 0.0),
+    (MDRunit::KiloWatt, Dimension::Power, 1e3, 0.0),
+    (MDRunit::MegaWatt, Dimension::Power, 1e6, 0.0),
+    (MDRunit::MetrePerSecond, Dimension::Speed, 1.0, 0.0),
+    (MDRunit::KilometrePerHour, Dimension::Speed, 1.0 / 3.6, 0.0),
+    (MDRunit::Bit, Dimension::Information, 1.0, 0.0),
+    (MDRunit::Byte, Dimension::Information, 8.0, 0.0),
+    (MDRunit::GigaByte, Dimension::Information, 8e9, 0.0),
+    (MDRunit::Pascal, Dimension::Pressure, 1.0, 0.0),
+    (MDRunit::Bar, Dimension::Pressure, 1e5, 0.0),
+    (MDRunit::Kelvin, Dimension::Temperature, 1.0, 0.0),
+    (MDRunit::DegreeCelsius, Dimension::Temperature, 1.0, 273.15),
+];
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "2N"  => Ok(MDRunit::Decibel),
-            "3C"  => Ok(MDRunit::Manmonth),
-            "AD"  => Ok(MDRunit::Byte),
-            "AMP" => Ok(MDRunit::Ampere),
-            "BAR" => Ok(MDRunit::Bar),
-            "BIT" => Ok(MDRunit::Bit),
-            "BQL" => Ok(MDRunit::Becquerel),
-            "C34" => Ok(MDRunit::Mole),
-            "C45" => Ok(MDRunit::Nanometre),
-            "CDL" => Ok(MDRunit::Candela),
-            "CEL" => Ok(MDRunit::DegreeCelsius),
-            "CMK" => Ok(MDRunit::SquareCentimetre),
-            "CMQ" => Ok(MDRunit::CubicCentimetre),
-            "CMT" => Ok(MDRunit::Centimetre),
-            "D30" => Ok(MDRunit::TeraJoule),
-            "E34" => Ok(MDRunit::GigaByte),
-            "GRM" => Ok(MDRunit::Gram),
-            "GTE" => Ok(MDRunit::GrossTonnage),
-            "GWH" => Ok(MDRunit::GigaWattHour),
-            "HAR" => Ok(MDRunit::Hectare),
-            "HLT" => Ok(MDRunit::HectoLitre),
-            "HTZ" => Ok(MDRunit::Hertz),
-            "HUR" => Ok(MDRunit::Hour),
-            "JOU" => Ok(MDRunit::Joule),
-            "KEL" => Ok(MDRunit::Kelvin),
-            "KGM" => Ok(MDRunit::Kilogram),
-            "KMH" => Ok(MDRunit::KilometrePerHour),
-            "KMK" => Ok(MDRunit::SquareKilometre),
-            "KTM" => Ok(MDRunit::Kilometre),
-            "KWH" => Ok(MDRunit::KiloWattHour),
-            "KWT" => Ok(MDRunit::KiloWatt),
-            "LH"  => Ok(MDRunit::LabourHour),
-            "LTR" => Ok(MDRunit::Litre),
-            "MAW" => Ok(MDRunit::MegaWatt),
-            "MGM" => Ok(MDRunit::MilliGram),
-            "MIN" => Ok(MDRunit::Minute),
-            "MLT" => Ok(MDRunit::MilliLitre),
-            "MMT" => Ok(MDRunit::MilliMetre),
-            "MTK" => Ok(MDRunit::SquareMetre),
-            "MTR" => Ok(MDRunit::Metre),
-            "MTS" => Ok(MDRunit::MetrePerSecond),
-            "NEW" => Ok(MDRunit::Newton),
-            "PAL" => Ok(MDRunit::Pascal),
-            "SEC" => Ok(MDRunit::Second),
-            "TKM" => Ok(MDRunit::TonneKilometre),
-            "TNE" => Ok(MDRunit::Tonne),
-            "TOE" => Ok(MDRunit::TonneOfOilEquvalent),
-            "VLT" => Ok(MDRunit::Volt),
-            "WTT" => Ok(MDRunit::Watt),
-            _ => Err("Could not form a Europass measure from a string…")
+impl MDRunit {
+    fn conversion(&self) -> Option<&'static (MDRunit, Dimension, f64, f64)> {
+        UNIT_CONVERSIONS.iter().find(|(unit, _, _, _)| unit == self)
+    }
+
+    /// The UN/ECE Recommendation 20 common code of this unit,
+    /// such as `HUR` for hours, as used in the authority table.
+    pub fn common_code(&self) -> &'static str {
+        self.notation()
+    }
+
+    /// The unit with the given UN/ECE Recommendation 20 common code.
+    pub fn from_common_code(code: &str) -> Option<MDRunit> {
+        MDRunit::from_notation(code)
+    }
+
+    /// The dimension of this unit, if it can be converted to other units.
+    pub fn dimension(&self) -> Option<Dimension> {
+        self.conversion().map(|(_, dimension, _, _)| *dimension)
+    }
+
+    /// Whether values in this unit can be converted to the other unit.
+    pub fn is_compatible_with(&self, other: MDRunit) -> bool {
+        *self == other || (self.dimension().is_some() && self.dimension() == other.dimension())
+    }
+
+    /// Converts a value in this unit to the given unit
+    /// of the same dimension.
+    pub fn convert(&self, value: f64, to: MDRunit) -> Result<f64, String> {
+        if *self == to {
+            return Ok(value);
+        }
+        match (self.conversion(), to.conversion()) {
+            (Some((_, from_dimension, from_factor, from_offset)), Some((_, to_dimension, to_factor, to_offset)))
+                if from_dimension == to_dimension =>
+            {
+                Ok((value * from_factor + from_offset - to_offset) / to_factor)
+            }
+            _ => Err(format!(
+                "Could not convert from unit {} to incompatible unit {}…",
+                self.common_code(),
+                to.common_code()
+            )),
         }
     }
 }
 
-/// The Currency authority table is a controlled vocabulary that lists concepts associated with currencies and currency subunits.
-/// The concepts included are correlated with the ISO 4217 international standard.
-/// The Currency authority table is updated based on the stakeholders’ needs.
-/// Contributions to it are accepted following a review made by EU Vocabularies team of the Publications Office of the EU.
-/// The Currency authority table is maintained by the Publications Office of the European Union on the EU Vocabularies website.
-/// See http://publications.europa.eu/resource/authority/currency.
-///
-/// Can only be constructed from a known MDR currency code.
-pub struct MDRcurrency {
-    code: String
-}
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use crate::controlled_vocabularies::*;
 
-// Implement std::convert::TryFrom to disallow unknown currency codes.
-impl std::convert::TryFrom<&str> for MDRcurrency {
-    type Error = String;
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "ADP" => Ok(MDRcurrency {code:String::from(value)}),
-            "AED" => Ok(MDRcurrency {code:String::from(value)}),
-            "AFA" => Ok(MDRcurrency {code:String::from(value)}),
-            "AFN" => Ok(MDRcurrency {code:String::from(value)}),
-            "ALK" => Ok(MDRcurrency {code:String::from(value)}),
-            "ALL" => Ok(MDRcurrency {code:String::from(value)}),
-            "AMD" => Ok(MDRcurrency {code:String::from(value)}),
-            "ANG" => Ok(MDRcurrency {code:String::from(value)}),
-            "AOA" => Ok(MDRcurrency {code:String::from(value)}),
-            "AON" => Ok(MDRcurrency {code:String::from(value)}),
-            "AOR" => Ok(MDRcurrency {code:String::from(value)}),
-            "ARA" => Ok(MDRcurrency {code:String::from(value)}),
-            "ARM" => Ok(MDRcurrency {code:String::from(value)}),
-            "ARP" => Ok(MDRcurrency {code:String::from(value)}),
-            "ARS" => Ok(MDRcurrency {code:String::from(value)}),
-            "ATS" => Ok(MDRcurrency {code:String::from(value)}),
-            "AUD" => Ok(MDRcurrency {code:String::from(value)}),
-            "AWG" => Ok(MDRcurrency {code:String::from(value)}),
-            "AZM" => Ok(MDRcurrency {code:String::from(value)}),
-            "AZN" => Ok(MDRcurrency {code:String::from(value)}),
-            "BAM" => Ok(MDRcurrency {code:String::from(value)}),
-            "BBD" => Ok(MDRcurrency {code:String::from(value)}),
-            "BDT" => Ok(MDRcurrency {code:String::from(value)}),
-            "BEF" => Ok(MDRcurrency {code:String::from(value)}),
-            "BGJ" => Ok(MDRcurrency {code:String::from(value)}),
-            "BGK" => Ok(MDRcurrency {code:String::from(value)}),
-            "BGL" => Ok(MDRcurrency {code:String::from(value)}),
-            "BGN" => Ok(MDRcurrency {code:String::from(value)}),
-            "BHD" => Ok(MDRcurrency {code:String::from(value)}),
-            "BIF" => Ok(MDRcurrency {code:String::from(value)}),
-            "BMD" => Ok(MDRcurrency {code:String::from(value)}),
-            "BND" => Ok(MDRcurrency {code:String::from(value)}),
-            "BOB" => Ok(MDRcurrency {code:String::from(value)}),
-            "BOP" => Ok(MDRcurrency {code:String::from(value)}),
-            "BOV" => Ok(MDRcurrency {code:String::from(value)}),
-            "BRB" => Ok(MDRcurrency {code:String::from(value)}),
-            "BRC" => Ok(MDRcurrency {code:String::from(value)}),
-            "BRE" => Ok(MDRcurrency {code:String::from(value)}),
-            "BRL" => Ok(MDRcurrency {code:String::from(value)}),
-            "BRN" => Ok(MDRcurrency {code:String::from(value)}),
-            "BRR" => Ok(MDRcurrency {code:String::from(value)}),
-            "BRZ" => Ok(MDRcurrency {code:String::from(value)}),
-            "BSD" => Ok(MDRcurrency {code:String::from(value)}),
-            "BTN" => Ok(MDRcurrency {code:String::from(value)}),
-            "BWP" => Ok(MDRcurrency {code:String::from(value)}),
-            "BYN" => Ok(MDRcurrency {code:String::from(value)}),
-            "BYR" => Ok(MDRcurrency {code:String::from(value)}),
-            "BZD" => Ok(MDRcurrency {code:String::from(value)}),
-            "CAD" => Ok(MDRcurrency {code:String::from(value)}),
-            "CDF" => Ok(MDRcurrency {code:String::from(value)}),
-            "CHE" => Ok(MDRcurrency {code:String::from(value)}),
-            "CHF" => Ok(MDRcurrency {code:String::from(value)}),
-            "CHW" => Ok(MDRcurrency {code:String::from(value)}),
-            "CLE" => Ok(MDRcurrency {code:String::from(value)}),
-            "CLF" => Ok(MDRcurrency {code:String::from(value)}),
-            "CLP" => Ok(MDRcurrency {code:String::from(value)}),
-            "CNY" => Ok(MDRcurrency {code:String::from(value)}),
-            "COP" => Ok(MDRcurrency {code:String::from(value)}),
-            "COU" => Ok(MDRcurrency {code:String::from(value)}),
-            "CRC" => Ok(MDRcurrency {code:String::from(value)}),
-            "CSJ" => Ok(MDRcurrency {code:String::from(value)}),
-            "CSK" => Ok(MDRcurrency {code:String::from(value)}),
-            "CUC" => Ok(MDRcurrency {code:String::from(value)}),
-            "CUP" => Ok(MDRcurrency {code:String::from(value)}),
-            "CVE" => Ok(MDRcurrency {code:String::from(value)}),
-            "CYP" => Ok(MDRcurrency {code:String::from(value)}),
-            "CZK" => Ok(MDRcurrency {code:String::from(value)}),
-            "DDM" => Ok(MDRcurrency {code:String::from(value)}),
-            "DEM" => Ok(MDRcurrency {code:String::from(value)}),
-            "DJF" => Ok(MDRcurrency {code:String::from(value)}),
-            "DKK" => Ok(MDRcurrency {code:String::from(value)}),
-            "DOP" => Ok(MDRcurrency {code:String::from(value)}),
-            "DZD" => Ok(MDRcurrency {code:String::from(value)}),
-            "ECS" => Ok(MDRcurrency {code:String::from(value)}),
-            "EEK" => Ok(MDRcurrency {code:String::from(value)}),
-            "EGP" => Ok(MDRcurrency {code:String::from(value)}),
-            "ERN" => Ok(MDRcurrency {code:String::from(value)}),
-            "ESP" => Ok(MDRcurrency {code:String::from(value)}),
-            "ETB" => Ok(MDRcurrency {code:String::from(value)}),
-            "EUR" => Ok(MDRcurrency {code:String::from(value)}),
-            "FIM" => Ok(MDRcurrency {code:String::from(value)}),
-            "FJD" => Ok(MDRcurrency {code:String::from(value)}),
-            "FKP" => Ok(MDRcurrency {code:String::from(value)}),
-            "FRF" => Ok(MDRcurrency {code:String::from(value)}),
-            "GBP" => Ok(MDRcurrency {code:String::from(value)}),
-            "GEL" => Ok(MDRcurrency {code:String::from(value)}),
-            "GHC" => Ok(MDRcurrency {code:String::from(value)}),
-            "GHS" => Ok(MDRcurrency {code:String::from(value)}),
-            "GIP" => Ok(MDRcurrency {code:String::from(value)}),
-            "GMD" => Ok(MDRcurrency {code:String::from(value)}),
-            "GNE" => Ok(MDRcurrency {code:String::from(value)}),
-            "GNF" => Ok(MDRcurrency {code:String::from(value)}),
-            "GQE" => Ok(MDRcurrency {code:String::from(value)}),
-            "GRD" => Ok(MDRcurrency {code:String::from(value)}),
-            "GTQ" => Ok(MDRcurrency {code:String::from(value)}),
-            "GWP" => Ok(MDRcurrency {code:String::from(value)}),
-            "GYD" => Ok(MDRcurrency {code:String::from(value)}),
-            "HKD" => Ok(MDRcurrency {code:String::from(value)}),
-            "HNL" => Ok(MDRcurrency {code:String::from(value)}),
-            "HRK" => Ok(MDRcurrency {code:String::from(value)}),
-            "HTG" => Ok(MDRcurrency {code:String::from(value)}),
-            "HUF" => Ok(MDRcurrency {code:String::from(value)}),
-            "IDR" => Ok(MDRcurrency {code:String::from(value)}),
-            "IEP" => Ok(MDRcurrency {code:String::from(value)}),
-            "ILP" => Ok(MDRcurrency {code:String::from(value)}),
-            "ILR" => Ok(MDRcurrency {code:String::from(value)}),
-            "ILS" => Ok(MDRcurrency {code:String::from(value)}),
-            "INR" => Ok(MDRcurrency {code:String::from(value)}),
-            "IQD" => Ok(MDRcurrency {code:String::from(value)}),
-            "IRR" => Ok(MDRcurrency {code:String::from(value)}),
-            "ISJ" => Ok(MDRcurrency {code:String::from(value)}),
-            "ISK" => Ok(MDRcurrency {code:String::from(value)}),
-            "ITL" => Ok(MDRcurrency {code:String::from(value)}),
-            "JMD" => Ok(MDRcurrency {code:String::from(value)}),
-            "JOD" => Ok(MDRcurrency {code:String::from(value)}),
-            "JPY" => Ok(MDRcurrency {code:String::from(value)}),
-            "KES" => Ok(MDRcurrency {code:String::from(value)}),
-            "KGS" => Ok(MDRcurrency {code:String::from(value)}),
-            "KHR" => Ok(MDRcurrency {code:String::from(value)}),
-            "KMF" => Ok(MDRcurrency {code:String::from(value)}),
-            "KPW" => Ok(MDRcurrency {code:String::from(value)}),
-            "KRW" => Ok(MDRcurrency {code:String::from(value)}),
-            "KWD" => Ok(MDRcurrency {code:String::from(value)}),
-            "KYD" => Ok(MDRcurrency {code:String::from(value)}),
-            "KZT" => Ok(MDRcurrency {code:String::from(value)}),
-            "LAJ" => Ok(MDRcurrency {code:String::from(value)}),
-            "LAK" => Ok(MDRcurrency {code:String::from(value)}),
-            "LBP" => Ok(MDRcurrency {code:String::from(value)}),
-            "LKR" => Ok(MDRcurrency {code:String::from(value)}),
-            "LRD" => Ok(MDRcurrency {code:String::from(value)}),
-            "LSL" => Ok(MDRcurrency {code:String::from(value)}),
-            "LTL" => Ok(MDRcurrency {code:String::from(value)}),
-            "LUF" => Ok(MDRcurrency {code:String::from(value)}),
-            "LVL" => Ok(MDRcurrency {code:String::from(value)}),
-            "LYD" => Ok(MDRcurrency {code:String::from(value)}),
-            "MAD" => Ok(MDRcurrency {code:String::from(value)}),
-            "MDL" => Ok(MDRcurrency {code:String::from(value)}),
-            "MGA" => Ok(MDRcurrency {code:String::from(value)}),
-            "MGF" => Ok(MDRcurrency {code:String::from(value)}),
-            "MKD" => Ok(MDRcurrency {code:String::from(value)}),
-            "MLF" => Ok(MDRcurrency {code:String::from(value)}),
-            "MMK" => Ok(MDRcurrency {code:String::from(value)}),
-            "MNT" => Ok(MDRcurrency {code:String::from(value)}),
-            "MOP" => Ok(MDRcurrency {code:String::from(value)}),
-            "MRO" => Ok(MDRcurrency {code:String::from(value)}),
-            "MRU" => Ok(MDRcurrency {code:String::from(value)}),
-            "MTL" => Ok(MDRcurrency {code:String::from(value)}),
-            "MTP" => Ok(MDRcurrency {code:String::from(value)}),
-            "MUR" => Ok(MDRcurrency {code:String::from(value)}),
-            "MVQ" => Ok(MDRcurrency {code:String::from(value)}),
-            "MVR" => Ok(MDRcurrency {code:String::from(value)}),
-            "MWK" => Ok(MDRcurrency {code:String::from(value)}),
-            "MXN" => Ok(MDRcurrency {code:String::from(value)}),
-            "MXP" => Ok(MDRcurrency {code:String::from(value)}),
-            "MXV" => Ok(MDRcurrency {code:String::from(value)}),
-            "MYR" => Ok(MDRcurrency {code:String::from(value)}),
-            "MZM" => Ok(MDRcurrency {code:String::from(value)}),
-            "MZN" => Ok(MDRcurrency {code:String::from(value)}),
-            "NAD" => Ok(MDRcurrency {code:String::from(value)}),
-            "NFD" => Ok(MDRcurrency {code:String::from(value)}),
-            "NGN" => Ok(MDRcurrency {code:String::from(value)}),
-            "NIO" => Ok(MDRcurrency {code:String::from(value)}),
-            "NLG" => Ok(MDRcurrency {code:String::from(value)}),
-            "NOK" => Ok(MDRcurrency {code:String::from(value)}),
-            "NPR" => Ok(MDRcurrency {code:String::from(value)}),
-            "NZD" => Ok(MDRcurrency {code:String::from(value)}),
-            "OMR" => Ok(MDRcurrency {code:String::from(value)}),
-            "PAB" => Ok(MDRcurrency {code:String::from(value)}),
-            "PEH" => Ok(MDRcurrency {code:String::from(value)}),
-            "PEI" => Ok(MDRcurrency {code:String::from(value)}),
-            "PEN" => Ok(MDRcurrency {code:String::from(value)}),
-            "PGK" => Ok(MDRcurrency {code:String::from(value)}),
-            "PHP" => Ok(MDRcurrency {code:String::from(value)}),
-            "PKR" => Ok(MDRcurrency {code:String::from(value)}),
-            "PLN" => Ok(MDRcurrency {code:String::from(value)}),
-            "PLZ" => Ok(MDRcurrency {code:String::from(value)}),
-            "PTE" => Ok(MDRcurrency {code:String::from(value)}),
-            "PYG" => Ok(MDRcurrency {code:String::from(value)}),
-            "QAR" => Ok(MDRcurrency {code:String::from(value)}),
-            "ROL" => Ok(MDRcurrency {code:String::from(value)}),
-            "RON" => Ok(MDRcurrency {code:String::from(value)}),
-            "RSD" => Ok(MDRcurrency {code:String::from(value)}),
-            "RUB" => Ok(MDRcurrency {code:String::from(value)}),
-            "RUR" => Ok(MDRcurrency {code:String::from(value)}),
-            "RWF" => Ok(MDRcurrency {code:String::from(value)}),
-            "SAR" => Ok(MDRcurrency {code:String::from(value)}),
-            "SBD" => Ok(MDRcurrency {code:String::from(value)}),
-            "SCR" => Ok(MDRcurrency {code:String::from(value)}),
-            "SDD" => Ok(MDRcurrency {code:String::from(value)}),
-            "SDG" => Ok(MDRcurrency {code:String::from(value)}),
-            "SEK" => Ok(MDRcurrency {code:String::from(value)}),
-            "SGD" => Ok(MDRcurrency {code:String::from(value)}),
-            "SHP" => Ok(MDRcurrency {code:String::from(value)}),
-            "SIT" => Ok(MDRcurrency {code:String::from(value)}),
-            "SKK" => Ok(MDRcurrency {code:String::from(value)}),
-            "SLL" => Ok(MDRcurrency {code:String::from(value)}),
-            "SOS" => Ok(MDRcurrency {code:String::from(value)}),
-            "SRD" => Ok(MDRcurrency {code:String::from(value)}),
-            "SRG" => Ok(MDRcurrency {code:String::from(value)}),
-            "SSP" => Ok(MDRcurrency {code:String::from(value)}),
-            "STD" => Ok(MDRcurrency {code:String::from(value)}),
-            "STN" => Ok(MDRcurrency {code:String::from(value)}),
-            "SUR" => Ok(MDRcurrency {code:String::from(value)}),
-            "SVC" => Ok(MDRcurrency {code:String::from(value)}),
-            "SYP" => Ok(MDRcurrency {code:String::from(value)}),
-            "SZL" => Ok(MDRcurrency {code:String::from(value)}),
-            "THB" => Ok(MDRcurrency {code:String::from(value)}),
-            "TJR" => Ok(MDRcurrency {code:String::from(value)}),
-            "TJS" => Ok(MDRcurrency {code:String::from(value)}),
-            "TMM" => Ok(MDRcurrency {code:String::from(value)}),
-            "TMT" => Ok(MDRcurrency {code:String::from(value)}),
-            "TND" => Ok(MDRcurrency {code:String::from(value)}),
-            "TOP" => Ok(MDRcurrency {code:String::from(value)}),
-            "TPE" => Ok(MDRcurrency {code:String::from(value)}),
-            "TRL" => Ok(MDRcurrency {code:String::from(value)}),
-            "TRY" => Ok(MDRcurrency {code:String::from(value)}),
-            "TTD" => Ok(MDRcurrency {code:String::from(value)}),
-            "TWD" => Ok(MDRcurrency {code:String::from(value)}),
-            "TZS" => Ok(MDRcurrency {code:String::from(value)}),
-            "UAH" => Ok(MDRcurrency {code:String::from(value)}),
-            "UAK" => Ok(MDRcurrency {code:String::from(value)}),
-            "UGS" => Ok(MDRcurrency {code:String::from(value)}),
-            "UGX" => Ok(MDRcurrency {code:String::from(value)}),
-            "USD" => Ok(MDRcurrency {code:String::from(value)}),
-            "USN" => Ok(MDRcurrency {code:String::from(value)}),
-            "USS" => Ok(MDRcurrency {code:String::from(value)}),
-            "UYI" => Ok(MDRcurrency {code:String::from(value)}),
-            "UYN" => Ok(MDRcurrency {code:String::from(value)}),
-            "UYU" => Ok(MDRcurrency {code:String::from(value)}),
-            "UYW" => Ok(MDRcurrency {code:String::from(value)}),
-            "UZS" => Ok(MDRcurrency {code:String::from(value)}),
-            "VEB" => Ok(MDRcurrency {code:String::from(value)}),
-            "VEF" => Ok(MDRcurrency {code:String::from(value)}),
-            "VES" => Ok(MDRcurrency {code:String::from(value)}),
-            "VNC" => Ok(MDRcurrency {code:String::from(value)}),
-            "VND" => Ok(MDRcurrency {code:String::from(value)}),
-            "VUV" => Ok(MDRcurrency {code:String::from(value)}),
-            "WST" => Ok(MDRcurrency {code:String::from(value)}),
-            "XAF" => Ok(MDRcurrency {code:String::from(value)}),
-            "XAG" => Ok(MDRcurrency {code:String::from(value)}),
-            "XAU" => Ok(MDRcurrency {code:String::from(value)}),
-            "XBA" => Ok(MDRcurrency {code:String::from(value)}),
-            "XBB" => Ok(MDRcurrency {code:String::from(value)}),
-            "XBC" => Ok(MDRcurrency {code:String::from(value)}),
-            "XBD" => Ok(MDRcurrency {code:String::from(value)}),
-            "XCD" => Ok(MDRcurrency {code:String::from(value)}),
-            "XDR" => Ok(MDRcurrency {code:String::from(value)}),
-            "XEU" => Ok(MDRcurrency {code:String::from(value)}),
-            "XOF" => Ok(MDRcurrency {code:String::from(value)}),
-            "XPD" => Ok(MDRcurrency {code:String::from(value)}),
-            "XPF" => Ok(MDRcurrency {code:String::from(value)}),
-            "XPT" => Ok(MDRcurrency {code:String::from(value)}),
-            "XSU" => Ok(MDRcurrency {code:String::from(value)}),
-            "XTS" => Ok(MDRcurrency {code:String::from(value)}),
-            "XUA" => Ok(MDRcurrency {code:String::from(value)}),
-            "XXX" => Ok(MDRcurrency {code:String::from(value)}),
-            "YDD" => Ok(MDRcurrency {code:String::from(value)}),
-            "YER" => Ok(MDRcurrency {code:String::from(value)}),
-            "YUD" => Ok(MDRcurrency {code:String::from(value)}),
-            "YUF" => Ok(MDRcurrency {code:String::from(value)}),
-            "YUM" => Ok(MDRcurrency {code:String::from(value)}),
-            "YUN" => Ok(MDRcurrency {code:String::from(value)}),
-            "YUS" => Ok(MDRcurrency {code:String::from(value)}),
-            "ZAR" => Ok(MDRcurrency {code:String::from(value)}),
-            "ZMK" => Ok(MDRcurrency {code:String::from(value)}),
-            "ZMW" => Ok(MDRcurrency {code:String::from(value)}),
-            "ZRN" => Ok(MDRcurrency {code:String::from(value)}),
-            "ZRZ" => Ok(MDRcurrency {code:String::from(value)}),
-            "ZWC" => Ok(MDRcurrency {code:String::from(value)}),
-            "ZWD" => Ok(MDRcurrency {code:String::from(value)}),
-            "ZWL" => Ok(MDRcurrency {code:String::from(value)}),
-            "ZWN" => Ok(MDRcurrency {code:String::from(value)}),
-            "ZWR" => Ok(MDRcurrency {code:String::from(value)}),
-            _ => Err(
-                format!(
-                    "Could not form an MDR standard currency code from string {}…",
-                    value
-                )
-            )
+    #[test]
+    fn language_from_codes () {
+        use crate::controlled_vocabularies::Language;
+        // ISO 639-1, ISO 639-3, NAL URIs and SISU URNs all resolve to the same language.
+        for value in &[
+            "fi",
+            "FI",
+            "fin",
+            "FIN",
+            "http://publications.europa.eu/resource/authority/language/FIN",
+            "urn:code:language:fi",
+        ] {
+            assert_eq!(Language::try_from(*value), Ok(Language::Finnish), "{}", value);
+        }
+        // Languages without an ISO 639-1 code are found by their ISO 639-3 code.
+        assert_eq!(Language::try_from("urn:code:language:smn"), Ok(Language::InariSami));
+        assert_eq!(Language::try_from("urn:code:language:sw"), Ok(Language::Swahili));
+        assert_eq!(Language::Swahili.iso_639_3(), "swa");
+        assert!(Language::try_from("string").is_err());
+        assert!(Language::try_from("urn:code:language:xx").is_err());
+    }
+    #[test]
+    fn language_codes () {
+        use crate::controlled_vocabularies::Language;
+        assert_eq!(Language::Swedish.iso_639_1(), Some("sv"));
+        assert_eq!(Language::Swedish.iso_639_3(), "swe");
+        assert_eq!(
+            Language::Swedish.nal_uri(),
+            "http://publications.europa.eu/resource/authority/language/SWE"
+        );
+        assert_eq!(Language::Karelian.iso_639_1(), None);
+    }
+    /// Checks that every concept of a vocabulary can be formed back
+    /// from its URI and notation and is labelled in English.
+    fn assert_round_trips<T>()
+    where
+        T: ControlledVocabulary + std::fmt::Debug + for<'a> TryFrom<&'a str>,
+    {
+        for concept in T::concepts() {
+            let value = concept.value;
+            assert_eq!(T::from_uri(value.uri()), Some(value));
+            assert_eq!(T::from_notation(value.notation()), Some(value));
+            assert!(T::try_from(value.uri()).ok() == Some(value), "{:?}", value);
+            assert!(value.pref_label("en").is_some(), "{:?}", value);
+        }
+    }
+    #[test]
+    fn controlled_vocabularies_round_trip () {
+        assert_round_trips::<AccreditationType>();
+        assert_round_trips::<AssessmentType>();
+        assert_round_trips::<CommunicationChannelType>();
+        assert_round_trips::<CommunicationChannelUsageType>();
+        assert_round_trips::<ContentEncodingTypes>();
+        assert_round_trips::<EducationalCreditSystems>();
+        assert_round_trips::<CredentialType>();
+        assert_round_trips::<EntitlementStatus>();
+        assert_round_trips::<EntitlementType>();
+        assert_round_trips::<LearningActivityType>();
+        assert_round_trips::<LearningOpportunityType>();
+        assert_round_trips::<LearningScheduleType>();
+        assert_round_trips::<LearningSettingType>();
+        assert_round_trips::<ModeOfLearningType>();
+        assert_round_trips::<LearningTargetGroup>();
+        assert_round_trips::<VerificationStatus>();
+        assert_round_trips::<VerificationType>();
+        assert_round_trips::<MDRunit>();
+        assert_round_trips::<MDRcurrency>();
+        assert_round_trips::<Language>();
+    }
+    #[test]
+    fn controlled_vocabulary_uris_and_labels () {
+        assert_eq!(
+            CredentialType::DiplomaSupplement.uri(),
+            format!("{}credential/diploma-supplement", STAND_IN_CONCEPT_NAMESPACE)
+        );
+        assert_eq!(VerificationStatus::SCHEME_URI, "http://data.europa.eu/snb/verification-status/25831c2");
+        assert_eq!(VerificationStatus::Green.pref_label("fi"), Some("Vihreä"));
+        assert_eq!(VerificationStatus::Green.pref_label("de"), None);
+        assert_eq!(
+            MDRunit::try_from("http://publications.europa.eu/resource/authority/measurement-unit/HUR"),
+            Ok(MDRunit::Hour)
+        );
+        assert_eq!(MDRunit::try_from("HUR"), Ok(MDRunit::Hour));
+        assert!(MDRunit::try_from("hour").is_err());
+        assert_eq!(MDRcurrency::try_from("EUR").map(|currency| currency.pref_label("en")), Ok(Some("Euro")));
+        assert!(MDRcurrency::try_from("http://data.europa.eu/snb/credential/EUR").is_err());
+        let code = LearningSettingType::FormalLearning.to_code();
+        assert_eq!(code.target_notation(), "formal-learning");
+        assert_eq!(code.uri(), format!("{}learning-setting/formal-learning", STAND_IN_CONCEPT_NAMESPACE));
+    }
+    #[test]
+    fn deprecated_concepts_and_replacements () {
+        assert!(!MDRcurrency::EUR.is_deprecated());
+        assert_eq!(MDRcurrency::EUR.replacement(), Some(MDRcurrency::EUR));
+        // Historic currencies are replaced by their successors.
+        assert!(MDRcurrency::AFA.is_deprecated());
+        assert_eq!(MDRcurrency::AFA.replacement(), Some(MDRcurrency::AFN));
+        assert_eq!(MDRcurrency::CSK.replaced_by(), vec![MDRcurrency::CZK, MDRcurrency::SKK]);
+        // Replacements are followed until a current concept is found.
+        assert_eq!(MDRcurrency::ZWD.replacement(), Some(MDRcurrency::ZWG));
+        assert_eq!(MDRcurrency::FIM.replacement(), Some(MDRcurrency::EUR));
+        // A deprecated concept without a replacement has no current concept.
+        assert_eq!(MDRcurrency::USS.replacement(), None);
+        // Deprecated concepts can still be read.
+        assert_eq!(MDRcurrency::try_from("ZWD"), Ok(MDRcurrency::ZWD));
+        assert!(!MDRcurrency::SCHEME_VERSION.is_empty());
+    }
+    #[test]
+    fn withdrawn_and_current_currencies () {
+        for (withdrawn, current) in [
+            (MDRcurrency::ADP, MDRcurrency::EUR),
+            (MDRcurrency::CUC, MDRcurrency::CUP),
+            (MDRcurrency::HRK, MDRcurrency::EUR),
+            (MDRcurrency::SLL, MDRcurrency::SLE),
+            (MDRcurrency::ZWL, MDRcurrency::ZWG),
+        ] {
+            assert!(withdrawn.is_deprecated(), "{:?}", withdrawn);
+            assert_eq!(withdrawn.numeric_code(), None, "{:?}", withdrawn);
+            assert_eq!(withdrawn.replacement(), Some(current), "{:?}", withdrawn);
+        }
+        for (current, numeric_code) in [
+            (MDRcurrency::EUR, 978),
+            (MDRcurrency::CUP, 192),
+            (MDRcurrency::SLE, 925),
+            (MDRcurrency::VED, 926),
+            (MDRcurrency::VES, 928),
+            (MDRcurrency::ZWG, 924),
+        ] {
+            assert!(!current.is_deprecated(), "{:?}", current);
+            assert_eq!(current.numeric_code(), Some(numeric_code), "{:?}", current);
+        }
+    }
+    #[test]
+    fn currency_codes_and_names () {
+        assert_eq!(MDRcurrency::EUR.alphabetic_code(), "EUR");
+        assert_eq!(MDRcurrency::EUR.numeric_code(), Some(978));
+        assert_eq!(MDRcurrency::from_numeric_code(752), Some(MDRcurrency::SEK));
+        assert_eq!(MDRcurrency::EUR.minor_units(), Some(2));
+        assert_eq!(MDRcurrency::JPY.minor_units(), Some(0));
+        assert_eq!(MDRcurrency::KWD.minor_units(), Some(3));
+        // Precious metals have no minor units and historic currencies no ISO 4217 data.
+        assert_eq!(MDRcurrency::XAU.minor_units(), None);
+        assert_eq!(MDRcurrency::FIM.numeric_code(), None);
+        assert_eq!(MDRcurrency::SEK.name("fi"), "Ruotsin kruunu");
+        assert_eq!(MDRcurrency::SEK.name("sv"), "svensk krona");
+        // Names missing in a language fall back to English.
+        assert_eq!(MDRcurrency::KES.name("fi"), "Kenyan Shilling");
+        for (currency, _, _) in ISO_4217 {
+            assert!(!currency.is_deprecated(), "{:?} is deprecated", currency);
         }
     }
+    #[test]
+    fn unit_codes_and_conversions () {
+        assert_eq!(MDRunit::Hour.common_code(), "HUR");
+        assert_eq!(MDRunit::from_common_code("LH"), Some(MDRunit::LabourHour));
+        assert_eq!(MDRunit::Minute.dimension(), Some(Dimension::Time));
+        assert_eq!(MDRunit::Hour.convert(1.5, MDRunit::Minute), Ok(90.0));
+        assert_eq!(MDRunit::Manmonth.convert(1.0, MDRunit::LabourHour), Ok(160.0));
+        assert_eq!(MDRunit::Kilometre.convert(2.5, MDRunit::Metre), Ok(2500.0));
+        assert_eq!(MDRunit::DegreeCelsius.convert(100.0, MDRunit::Kelvin), Ok(373.15));
+        // Units of different dimensions cannot be converted.
+        assert!(!MDRunit::Hour.is_compatible_with(MDRunit::Metre));
+        assert!(MDRunit::Hour.convert(1.0, MDRunit::Metre).is_err());
+        // Units without a dimension only convert to themselves.
+        assert_eq!(MDRunit::Volt.convert(230.0, MDRunit::Volt), Ok(230.0));
+        assert!(MDRunit::Volt.convert(230.0, MDRunit::Ampere).is_err());
+    }
 }
-
-/// The list of languages recognized by the European Union. See:
-/// https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://publications.europa.eu/resource/authority/language
-/// for details.
-pub enum Language {}
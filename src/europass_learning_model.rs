@@ -6,10 +6,12 @@ and the types required in the construction of its fields.
 See https://github.com/european-commission-europass/Europass-Learning-Model/blob/master/Europass_Learning_Model.md
 */
 
-use chrono;
 use crate::national_qualification_frameworks as nqf;
 use crate::european_qualifications_framework as eqf;
-use crate::controlled_vocabularies;
+use crate::controlled_vocabularies::{self, ControlledVocabulary};
+use crate::status_list::{BitstringStatusList, StatusListEntry};
+use crate::json_ld::{self, ToJsonLd};
+use std::convert::TryFrom;
 
 /// A set of one or more claims made by an issuer.
 /// A credential is a set of one or more claims made by the same entity.
@@ -17,7 +19,12 @@ use crate::controlled_vocabularies;
 /// authorship that can be cryptographically verified.
 /// Verifiable credentials can be used to build verifiable presentations,
 /// which can also be cryptographically verified.
-struct VerifiableCredential {
+///
+/// The envelope is serialized both as a Europass XML credential,
+/// which is sealed before it is issued, and in the JSON-LD form of
+/// the [W3C Verifiable Credentials Data Model](https://www.w3.org/TR/vc-data-model-2.0/)
+/// used by digital wallets.
+pub struct VerifiableCredential {
     /// A unique portable identifier of the credential.
     /// Has to be a valid URI.
     id: URI,
@@ -27,11 +34,388 @@ struct VerifiableCredential {
     issued: chrono::naive::NaiveDateTime,
     /// The earliest date when the information associated with
     /// the credentialSubject property became valid.
-    valid_from: chrono::naive::NaiveDateTime,
-    /// The expiration date of this credential.
-    expiration_date: chrono::naive::NaiveDateTime,
+    valid_from: Option<chrono::naive::NaiveDateTime>,
+    /// The expiration date of this credential, if it expires.
+    expiration_date: Option<chrono::naive::NaiveDateTime>,
     /// The Europass Credential carried within this verifiable credential.
     europass_credential: EuropassCredential,
+    /// The entry of this credential in a status list, if it has one.
+    credential_status: Option<StatusListEntry>,
+}
+
+impl VerifiableCredential {
+    /// Wraps a Europass credential issued at the time `issued` and valid during `validity`.
+    /// The identifier of the credential is a UUID URN derived from the identifier
+    /// of the issuer, `source_id`, the identifier of the record the credential
+    /// was issued for, such as a SISU attainment, and the time of issuance.
+    /// A record issued again at another time gets a new identifier, so that
+    /// a corrected credential is not revoked along with the one it replaces.
+    pub fn new(
+        europass_credential: EuropassCredential,
+        source_id: &str,
+        issued: chrono::naive::NaiveDateTime,
+        validity: ValidityPeriod,
+    ) -> VerifiableCredential {
+        let id = credential_id(europass_credential.issuer.id(), source_id, issued);
+        VerifiableCredential {
+            id: URI(id),
+            issuance_date: issued,
+            issued,
+            valid_from: validity.valid_from,
+            expiration_date: validity.expiration_date,
+            europass_credential,
+            credential_status: None,
+        }
+    }
+
+    /// The identifier of the credential.
+    pub fn id(&self) -> &str {
+        &self.id.0
+    }
+
+    /// The issuance date of the credential.
+    pub fn issuance_date(&self) -> chrono::naive::NaiveDateTime {
+        self.issuance_date
+    }
+
+    /// The date and time the credential was issued.
+    pub fn issued(&self) -> chrono::naive::NaiveDateTime {
+        self.issued
+    }
+
+    /// The Europass credential carried within this verifiable credential.
+    pub fn europass_credential(&self) -> &EuropassCredential {
+        &self.europass_credential
+    }
+
+    /// The entry of this credential in a status list, if it has one.
+    pub fn credential_status(&self) -> Option<&StatusListEntry> {
+        self.credential_status.as_ref()
+    }
+
+    /// Allocates an index for this credential in a status list,
+    /// which is kept if the credential already has one in that list.
+    pub fn allocate_status(&mut self, list: &mut BitstringStatusList) -> Result<&StatusListEntry, String> {
+        let entry = list.allocate(self.id())?;
+        Ok(self.credential_status.insert(entry))
+    }
+
+    /// The period during which this credential is valid.
+    pub fn validity(&self) -> ValidityPeriod {
+        ValidityPeriod { valid_from: self.valid_from, expiration_date: self.expiration_date }
+    }
+
+    /// Sets the period during which this credential is valid.
+    fn set_validity(&mut self, validity: ValidityPeriod) {
+        self.valid_from = validity.valid_from;
+        self.expiration_date = validity.expiration_date;
+    }
+
+    /// Checks that this credential is valid at the time `as_of`.
+    fn check_validity(&self, as_of: chrono::naive::NaiveDateTime) -> VerificationCheck {
+        let (status, description) = self.validity().status_at(as_of);
+        VerificationCheck::new(
+            controlled_vocabularies::VerificationType::Validity,
+            &self.id.0,
+            status,
+            Note::new(Text::new(EuropeanLanguage::English, &description)),
+        )
+    }
+
+    /// The identifier of the learning achievement awarding the achievement
+    /// of the credential with the given index.
+    pub(crate) fn achievement_id(&self, index: usize) -> String {
+        format!("{}#achievement-{}", self.id(), index + 1)
+    }
+
+    /// The identifier of the awarding process of the learning achievement
+    /// with the given index.
+    pub(crate) fn awarding_process_id(&self, index: usize) -> String {
+        format!("{}#awarding-{}", self.id(), index + 1)
+    }
+
+    /// The date the achievements of the credential were awarded, if known.
+    /// They are awarded when the credential becomes valid, such as on
+    /// the date of a SISU attainment.
+    pub(crate) fn awarding_date(&self) -> Option<chrono::naive::NaiveDateTime> {
+        self.valid_from
+    }
+
+    /// The credential as an unsealed Europass XML document.
+    /// The learning achievements refer to their learning specifications and
+    /// awarding processes, which are given among the references of the document.
+    pub fn to_xml(&self) -> Result<String, String> {
+        let credential = &self.europass_credential;
+        let mut xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<europassCredential xmlns=\"{}\" id=\"{}\">",
+            crate::verification::EUROPASS_NAMESPACE,
+            escape_xml(self.id())
+        );
+        xml.push_str(&format!("<type uri=\"{}\"/>", credential.credential_type.uri()));
+        xml.push_str(&xml_element("issuanceDate", &xml_date_time(self.issuance_date)));
+        xml.push_str(&xml_element("issued", &xml_date_time(self.issued)));
+        if let Some(valid_from) = self.valid_from {
+            xml.push_str(&xml_element("validFrom", &xml_date_time(valid_from)));
+        }
+        if let Some(expiration_date) = self.expiration_date {
+            xml.push_str(&xml_element("expirationDate", &xml_date_time(expiration_date)));
+        }
+        xml.push_str(&xml_text("title", &credential.title)?);
+        if let Some(description) = &credential.description {
+            xml.push_str(&xml_text("description", description)?);
+        }
+        xml.push_str(&format!("<issuer idref=\"{}\"/>", escape_xml(credential.issuer.id())));
+        let subject = &credential.credential_subject;
+        xml.push_str(&format!("<credentialSubject id=\"{}\">", escape_xml(subject.id())));
+        xml.push_str(&xml_text("fullName", &subject.full_name)?);
+        xml.push_str(&xml_text("givenNames", &subject.given_names)?);
+        xml.push_str(&xml_text("familyName", &subject.family_name)?);
+        if let Some(date_of_birth) = subject.date_of_birth {
+            xml.push_str(&xml_element("dateOfBirth", &date_of_birth.format("%Y-%m-%d").to_string()));
+        }
+        if !credential.achievements.is_empty() {
+            xml.push_str("<achievements>");
+            for (index, specification) in credential.achievements.iter().enumerate() {
+                xml.push_str(&format!("<learningAchievement id=\"{}\">", escape_xml(&self.achievement_id(index))));
+                xml.push_str(&xml_text("title", &specification.title)?);
+                xml.push_str(&format!("<specifiedBy idref=\"{}\"/>", escape_xml(specification.id())));
+                xml.push_str(&format!("<wasAwardedBy idref=\"{}\"/>", escape_xml(&self.awarding_process_id(index))));
+                xml.push_str("</learningAchievement>");
+            }
+            xml.push_str("</achievements>");
+        }
+        xml.push_str("</credentialSubject>");
+        if let Some(entry) = &self.credential_status {
+            xml.push_str(&entry.to_xml());
+        }
+        if !credential.achievements.is_empty() {
+            xml.push_str("<learningSpecificationReferences>");
+            for specification in &credential.achievements {
+                xml.push_str(&format!("<learningSpecification id=\"{}\">", escape_xml(specification.id())));
+                xml.push_str(&xml_text("title", &specification.title)?);
+                if let Some(volume_of_learning) = specification.volume_of_learning {
+                    xml.push_str(&xml_element("volumeOfLearning", &volume_of_learning.to_string()));
+                }
+                if let Some(points) = specification.ects_credit_points() {
+                    xml.push_str(&xml_element("ectsCreditPoints", &points.to_string()));
+                }
+                if let Some(code) = &specification.iscedfc_code {
+                    xml.push_str(&format!("<iscedfCode uri=\"{}\"/>", escape_xml(code.uri())));
+                }
+                if let Some(language) = specification.language {
+                    xml.push_str(&format!("<language uri=\"{}\"/>", language.uri()));
+                }
+                if let Some(qualification) = &specification.qualification {
+                    xml.push_str(&format!("<eqfLevel uri=\"{}\"/>", qualification.eqf_level.uri()));
+                    xml.push_str(&format!("<nqfLevel uri=\"{}\"/>", escape_xml(qualification.nqf_level.uri())));
+                    xml.push_str(&xml_element("isPartialQualification", &qualification.is_partial_qualification.to_string()));
+                }
+                xml.push_str("</learningSpecification>");
+            }
+            xml.push_str("</learningSpecificationReferences>");
+            xml.push_str("<awardingProcessReferences>");
+            for index in 0..credential.achievements.len() {
+                xml.push_str(&format!("<awardingProcess id=\"{}\">", escape_xml(&self.awarding_process_id(index))));
+                xml.push_str(&format!("<awardingBody idref=\"{}\"/>", escape_xml(credential.issuer.id())));
+                if let Some(awarding_date) = self.awarding_date() {
+                    xml.push_str(&xml_element("awardingDate", &xml_date_time(awarding_date)));
+                }
+                if let Some(location) = &credential.awarding_location {
+                    xml.push_str(&format!("<awardingLocation uri=\"{}\"/>", escape_xml(location.uri())));
+                }
+                xml.push_str("</awardingProcess>");
+            }
+            xml.push_str("</awardingProcessReferences>");
+        }
+        let issuer = &credential.issuer;
+        xml.push_str(&format!("<agentReferences><organisation id=\"{}\">", escape_xml(issuer.id())));
+        xml.push_str(&xml_text("preferredName", &issuer.preferred_name)?);
+        for accreditation in &issuer.has_accreditation {
+            xml.push_str(&format!("<hasAccreditation idref=\"{}\"/>", escape_xml(accreditation.id())));
+        }
+        xml.push_str("</organisation></agentReferences></europassCredential>");
+        Ok(xml)
+    }
+
+    /// The credential in the JSON-LD form of the W3C Verifiable Credentials Data Model 2.0
+    /// used by digital wallets. The issuer and the subject are described
+    /// with the terms of the European Digital Credentials application profile,
+    /// whose context is placed after the base context of version 2.0,
+    /// which defines `validFrom` and `validUntil`.
+    /// The data model has no issuance dates, so a credential without a start
+    /// of validity is valid from the time it was issued.
+    pub fn to_vc_json_ld(&self) -> serde_json::Value {
+        let credential = &self.europass_credential;
+        let mut json = serde_json::json!({
+            "@context": [json_ld::VC_V2_CONTEXT, json_ld::EDC_CONTEXT],
+            "id": self.id(),
+            "type": ["VerifiableCredential", "EuropeanDigitalCredential"],
+            "issuer": credential.issuer.to_json_ld(),
+            "validFrom": json_date_time(self.valid_from.unwrap_or(self.issued)),
+            "credentialSubject": json_ld::credential_subject(self),
+            "title": credential.title.to_json_ld(),
+        });
+        if let Some(description) = &credential.description {
+            json["description"] = description.to_json_ld();
+        }
+        if let Some(expiration_date) = self.expiration_date {
+            json["validUntil"] = serde_json::json!(json_date_time(expiration_date));
+        }
+        if let Some(entry) = &self.credential_status {
+            json["credentialStatus"] = entry.to_json();
+        }
+        json
+    }
+
+    /// The credential serialized in the given format.
+    pub fn serialize(&self, format: CredentialFormat) -> Result<String, String> {
+        let json = |json: serde_json::Value| {
+            serde_json::to_string_pretty(&json).map_err(|e| format!("Could not serialize the credential: {}…", e))
+        };
+        match format {
+            CredentialFormat::EuropassXml => self.to_xml(),
+            CredentialFormat::EdcJsonLd => json(json_ld::ToJsonLd::to_json_ld(self)),
+            CredentialFormat::VerifiableCredentialJsonLd => json(self.to_vc_json_ld()),
+        }
+    }
+}
+
+/// The formats a verifiable credential is issued in. Credentials are issued
+/// both in the XML format of the Europass Learning Model 2 and in the JSON-LD
+/// format of the Europass Learning Model 3 during the transition between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CredentialFormat {
+    /// The Europass XML credential of the Europass Learning Model 2.
+    EuropassXml,
+    /// The JSON-LD credential of the European Digital Credentials
+    /// application profile of the Europass Learning Model 3.
+    EdcJsonLd,
+    /// The JSON-LD form of the W3C Verifiable Credentials Data Model 2.0.
+    VerifiableCredentialJsonLd,
+}
+
+impl std::str::FromStr for CredentialFormat {
+    type Err = String;
+
+    /// Accepts `xml`, `jsonld` and `vc`, in any case.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "xml" => Ok(CredentialFormat::EuropassXml),
+            "jsonld" | "json-ld" => Ok(CredentialFormat::EdcJsonLd),
+            "vc" => Ok(CredentialFormat::VerifiableCredentialJsonLd),
+            _ => Err(format!("Could not form a credential format from string {}…", value)),
+        }
+    }
+}
+
+/// A UUID URN derived from the identifier of an issuer, the identifier
+/// of a record it issues a credential for and the time of issuance,
+/// in the form of a version 8 UUID.
+fn credential_id(issuer: &str, source_id: &str, issued: chrono::naive::NaiveDateTime) -> String {
+    use sha2::{Digest, Sha256};
+    let digest = Sha256::digest(format!("{}\n{}\n{}", issuer, source_id, xml_date_time(issued)).as_bytes());
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    bytes[6] = (bytes[6] & 0x0f) | 0x80;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("urn:uuid:{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+/// A time in UTC as an `xsd:dateTime`.
+fn xml_date_time(time: chrono::naive::NaiveDateTime) -> String {
+    time.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+/// A time in UTC as in the JSON-LD forms.
+pub(crate) fn json_date_time(time: chrono::naive::NaiveDateTime) -> String {
+    xml_date_time(time)
+}
+
+fn escape_xml(value: &str) -> String {
+    String::from_utf8_lossy(&quick_xml::escape::escape(value.as_bytes())).into_owned()
+}
+
+/// An element with text content.
+fn xml_element(name: &str, content: &str) -> String {
+    format!("<{0}>{1}</{0}>", name, escape_xml(content))
+}
+
+/// An element with the translations of a `Text` or a `Note` as its `text` elements.
+fn xml_text<T: serde::Serialize>(name: &str, text: &T) -> Result<String, String> {
+    let mut xml = Vec::new();
+    let mut serializer = quick_xml::se::Serializer::with_root(quick_xml::Writer::new(&mut xml), Some(name));
+    text.serialize(&mut serializer)
+        .map_err(|e| format!("Could not serialize {}: {}…", name, e))?;
+    String::from_utf8(xml).map_err(|e| e.to_string())
+}
+
+/// The period during which a credential or an entitlement is valid.
+/// Either end of the period may be open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ValidityPeriod {
+    /// The earliest time of validity.
+    valid_from: Option<chrono::naive::NaiveDateTime>,
+    /// The time of expiry, at which the validity has ended.
+    expiration_date: Option<chrono::naive::NaiveDateTime>,
+}
+
+impl ValidityPeriod {
+    /// Constructs a validity period. The expiration date may not precede the start of validity.
+    pub fn new(
+        valid_from: Option<chrono::naive::NaiveDateTime>,
+        expiration_date: Option<chrono::naive::NaiveDateTime>,
+    ) -> Result<ValidityPeriod, String> {
+        if let (Some(from), Some(expiration)) = (valid_from, expiration_date) {
+            if expiration < from {
+                return Err(format!("Could not form a validity period from {} expiring at {}…", from, expiration));
+            }
+        }
+        Ok(ValidityPeriod { valid_from, expiration_date })
+    }
+
+    /// Constructs a validity period from the first day of validity
+    /// and the day of expiry, which starts at midnight. The day of expiry
+    /// is thus not part of the period.
+    pub fn from_dates(
+        valid_from: Option<chrono::naive::NaiveDate>,
+        expiry_date: Option<chrono::naive::NaiveDate>,
+    ) -> Result<ValidityPeriod, String> {
+        let midnight = |date: chrono::naive::NaiveDate| date.and_time(chrono::naive::NaiveTime::MIN);
+        ValidityPeriod::new(valid_from.map(midnight), expiry_date.map(midnight))
+    }
+
+    /// The earliest time of validity, if any.
+    pub fn valid_from(&self) -> Option<chrono::naive::NaiveDateTime> {
+        self.valid_from
+    }
+
+    /// The time of expiry, if any.
+    pub fn expiration_date(&self) -> Option<chrono::naive::NaiveDateTime> {
+        self.expiration_date
+    }
+
+    /// Whether the period covers the time `as_of`.
+    pub fn is_valid_at(&self, as_of: chrono::naive::NaiveDateTime) -> bool {
+        self.valid_from.is_none_or(|from| from <= as_of)
+            && self.expiration_date.is_none_or(|expiration| as_of < expiration)
+    }
+
+    /// The status of a validity check at the time `as_of` with a description of the result.
+    pub fn status_at(
+        &self,
+        as_of: chrono::naive::NaiveDateTime,
+    ) -> (controlled_vocabularies::VerificationStatus, String) {
+        use controlled_vocabularies::VerificationStatus::{Green, Red};
+        match (self.valid_from, self.expiration_date) {
+            (Some(from), _) if as_of < from => (Red, format!("The credential is not valid before {}…", from)),
+            (_, Some(expiration)) if expiration <= as_of => {
+                (Red, format!("The credential expired at {}…", expiration))
+            }
+            (_, Some(expiration)) => (Green, format!("The credential is valid until {}.", expiration)),
+            (_, None) => (Green, String::from("The credential is valid and does not expire.")),
+        }
+    }
 }
 
 /// A set of claims made by an issuer in Europe, using the Europass Standards.
@@ -40,7 +424,7 @@ struct VerifiableCredential {
 /// learning outcomes through formal, non-formal or informal learning.
 pub struct EuropassCredential {
     /// The identifier of this Europass Credential.
-    identifier: Identifier,
+    identifier: Option<Identifier>,
     /// The type of this credential.
     /// See https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://data.europa.eu/snb/credential/25831c2
     /// for details.
@@ -50,26 +434,106 @@ pub struct EuropassCredential {
     title: Text,
     /// A summary of the claim or group of claims being
     /// made about a person (maximum 140 words).
-    description: Note,
+    description: Option<Note>,
     /// The organisaton that issued the credential and
     /// sealed it with their digital e-seal.
     issuer: Organisation,
     /// The person about which claims are made and who owns the credential.
     credential_subject: Person,
+    /// The specifications of the learning the person has achieved,
+    /// each awarded as a learning achievement of the person.
+    achievements: Vec<LearningSpecification>,
+    /// The country or region where the achievements were awarded, if known.
+    awarding_location: Option<Code>,
     /// The display details of the credential.
-    display: String,
+    display: Option<String>,
     /// Any digital document (PDF, JPEG or PNG format) that an issuer
     /// has attached to the Europass document.
-    attachment: EuropassAttachment,
+    attachment: Option<EuropassAttachment>,
     /// The cryptographic proofs that can be used to detect tampering and
     /// verify the authorship of a credential or presentation.
-    proof: Proof,
+    proof: Option<Proof>,
     /// A credential embedded within the credential.
     /// Smaller sub-credentials (micro-credentials),
     /// that make up this larger credential when combined.
     contains: Option<Box<EuropassCredential>>,
 }
 
+impl EuropassCredential {
+    /// Constructs a credential of the given type issued by `issuer` to `credential_subject`.
+    pub fn new(
+        credential_type: controlled_vocabularies::CredentialType,
+        title: Text,
+        issuer: Organisation,
+        credential_subject: Person,
+    ) -> EuropassCredential {
+        EuropassCredential {
+            identifier: None,
+            credential_type,
+            title,
+            description: None,
+            issuer,
+            credential_subject,
+            achievements: Vec::new(),
+            awarding_location: None,
+            display: None,
+            attachment: None,
+            proof: None,
+            contains: None,
+        }
+    }
+
+    /// Sets the summary of the claims made about the person.
+    pub fn set_description(&mut self, description: Note) {
+        self.description = Some(description);
+    }
+
+    /// The type of this credential.
+    pub fn credential_type(&self) -> controlled_vocabularies::CredentialType {
+        self.credential_type
+    }
+
+    /// The title of this credential.
+    pub fn title(&self) -> &Text {
+        &self.title
+    }
+
+    /// The summary of the claims made about the person, if any.
+    pub fn description(&self) -> Option<&Note> {
+        self.description.as_ref()
+    }
+
+    /// The organisation that issued this credential.
+    pub fn issuer(&self) -> &Organisation {
+        &self.issuer
+    }
+
+    /// The person who owns this credential.
+    pub fn credential_subject(&self) -> &Person {
+        &self.credential_subject
+    }
+
+    /// Awards the person the learning of the given specification.
+    pub fn add_achievement(&mut self, specification: LearningSpecification) {
+        self.achievements.push(specification);
+    }
+
+    /// The specifications of the learning the person has achieved.
+    pub fn achievements(&self) -> &[LearningSpecification] {
+        &self.achievements
+    }
+
+    /// Sets the country or region where the achievements were awarded.
+    pub fn set_awarding_location(&mut self, awarding_location: Code) {
+        self.awarding_location = Some(awarding_location);
+    }
+
+    /// The country or region where the achievements were awarded, if known.
+    pub fn awarding_location(&self) -> Option<&Code> {
+        self.awarding_location.as_ref()
+    }
+}
+
 /// The cryptographic proof that can be used to detect tampering and
 /// verify the authorship of a credential or presentation.
 struct Proof {
@@ -98,14 +562,14 @@ struct Agent {
 }
 
 /// A concrete human instance of an agent.
-struct Person {
+pub struct Person {
     /// The unique and portable identifier of the person.
     id: URI,
     /// The "primary" national identifier of the person.
-    national_id: LegalIdentifier,
+    national_id: Option<LegalIdentifier>,
     /// An (optional) alternative formally-issued identifier for the person,
     /// e.g. social security number, student ID card number, to club membership, etc.
-    identifier: Identifier,
+    identifier: Option<Identifier>,
     /// The complete name of the person as one string.
     full_name: Text,
     /// The given name(s) of the person.
@@ -117,7 +581,7 @@ struct Person {
     /// they are recorded by some public sector information systems.
     /// There is no granularity for birth name - the full name should
     /// be recorded in a single field.
-    birth_name: Text,
+    birth_name: Option<Text>,
     /// Patronymic names are important in some countries.
     /// Iceland does not have a concept of 'family name' in the way
     /// that many other European countries do, for example,
@@ -125,42 +589,110 @@ struct Person {
     /// both offspring of Mangnus, irrespective of his patronymic name.
     /// In Bulgaria and Russia, patronymic names are in every day usage,
     /// for example, the Sergeyevich in 'Mikhail Sergeyevich Gorbachev.'
-    patronymic_name: Text,
-    /// The birth date of the person.
-    date_of_birth: chrono::naive::NaiveDate,
+    patronymic_name: Option<Text>,
+    /// The birth date of the person, if known.
+    date_of_birth: Option<chrono::naive::NaiveDate>,
     /// The place of birth of the person.
-    place_of_birth: Location,
+    place_of_birth: Option<Location>,
     /// The gender of the person.
-    gender: Code,
+    gender: Option<Code>,
     /// The country (or countries) that conferred citizenship
     /// rights on the person.
-    citizenship_country: Code,
+    citizenship_country: Vec<Code>,
     /// A location related to a Person.
     /// For example a person's home or residence location,
     /// a person's work place location,
     /// site location of an organisation, etc.
-    has_location: Location,
-    /// A learning activity that a person participated in or attended.
-    performed: LearningActivity,
-    /// An achievement of the person.
-    achieved: LearningAchievement,
-    /// The entitlement of the person.
-    entitled_to: Entitlement,
+    has_location: Option<Location>,
+    /// Learning activities that the person participated in or attended.
+    performed: Vec<LearningActivity>,
+    /// The achievements of the person.
+    achieved: Vec<LearningAchievement>,
+    /// The entitlements of the person.
+    entitled_to: Vec<Entitlement>,
+}
+
+impl Person {
+    /// Constructs a person with the given names. The full name is formed
+    /// from the given names followed by the family name in each language
+    /// the given names are available in.
+    pub fn new(
+        id: &str,
+        given_names: Text,
+        family_name: Text,
+        date_of_birth: Option<chrono::naive::NaiveDate>,
+    ) -> Person {
+        let family_name_in = |language| family_name.get(language).or_else(|| family_name.primary().map(|(_, name)| name));
+        let full_name = Text::from_translations(
+            given_names.translations().map(|(language, given_names)| {
+                let full_name = match family_name_in(language) {
+                    Some(family_name) => format!("{} {}", given_names, family_name),
+                    None => given_names.to_string(),
+                };
+                (language, full_name)
+            }),
+            &LanguageFallback::new(given_names.translations().map(|(language, _)| language).collect()),
+        );
+        Person {
+            id: URI(id.to_string()),
+            national_id: None,
+            identifier: None,
+            full_name,
+            given_names,
+            family_name,
+            birth_name: None,
+            patronymic_name: None,
+            date_of_birth,
+            place_of_birth: None,
+            gender: None,
+            citizenship_country: Vec::new(),
+            has_location: None,
+            performed: Vec::new(),
+            achieved: Vec::new(),
+            entitled_to: Vec::new(),
+        }
+    }
+
+    /// The identifier of the person.
+    pub fn id(&self) -> &str {
+        &self.id.0
+    }
+
+    /// The complete name of the person.
+    pub fn full_name(&self) -> &Text {
+        &self.full_name
+    }
+
+    /// The given names of the person.
+    pub fn given_names(&self) -> &Text {
+        &self.given_names
+    }
+
+    /// The family name of the person.
+    pub fn family_name(&self) -> &Text {
+        &self.family_name
+    }
+
+    /// The birth date of the person, if known.
+    pub fn date_of_birth(&self) -> Option<chrono::naive::NaiveDate> {
+        self.date_of_birth
+    }
 }
 
 /// A concrete instance of an Agent.
 /// A legal person / registered organisation.
-struct Organisation {
+#[derive(Debug, Clone, PartialEq)]
+pub struct Organisation {
     /// The unique and portable identifier of the organisation.
     id: URI,
     /// Another formally-issued identifier for the organisation.
-    identifier: Identifier,
+    identifier: Option<Identifier>,
     /// The official identification number of the organisation,
     /// as awarded by the relevant national authority.
     ///
     /// See chapter 5.1.4 in Draft ETSI EN 319 412-1 V1.4.2:
     /// https://www.etsi.org/deliver/etsi_en/319400_319499/31941201/01.04.02_20/en_31941201v010402a.pdf
-    eidas_legal_identifier: Identifier,
+    eidas_legal_identifier: Option<Identifier>,
     /// The legal identifier of an organization.
     /// The identifier given to a registered organization by the authority
     /// with which it is registered. The legal status of
@@ -168,31 +700,84 @@ struct Organisation {
     /// within a given jurisdiction. The Legal Identifier is therefore
     /// a fundamental relationship between an organization and the authority
     /// with which it is registered.
-    registration: Identifier,
+    registration: Option<Identifier>,
     /// The Value-Added Tax ID.
-    vat_identifier: Identifier,
+    vat_identifier: Option<Identifier>,
     /// Fiscal ID of the organisation.
-    tax_identifier: Identifier,
+    tax_identifier: Option<Identifier>,
     /// The primary name of the organisation.
     preferred_name: Text,
     /// An (optional) alternative name of the organisation as typically
     /// used in documents, including credentials.
-    alternative_name: Text,
+    alternative_name: Option<Text>,
     /// A homepage of the organisation.
-    home_page: WebDocument,
+    home_page: Option<WebDocument>,
     /// The legally registered site of the organisation.
-    has_location: Location,
+    has_location: Option<Location>,
     /// Accreditation Records associated with the organisation.
     /// More information about the accreditation database is available here.
-    has_accreditation: Accreditation,
+    has_accreditation: Vec<Accreditation>,
     /// A smaller organisation of which forms part of this organisation,
     /// e.g. a Department within a larger Organisation.
-    has_unit: Box<Organisation>,
+    has_unit: Vec<Organisation>,
     /// Indicates a larger Organisation of which this Unit is a part of,
     /// e.g. the Organisation within which a Department operates.
-    unit_of: Box<Organisation>,
+    unit_of: Option<Box<Organisation>>,
     /// The logo of the organisation.
-    logo: ImageObject,
+    logo: Option<ImageObject>,
+}
+
+impl Organisation {
+    /// Constructs an organisation with the given identifier and name
+    /// and no accreditations.
+    pub fn new(id: &str, preferred_name: Text) -> Organisation {
+        Organisation {
+            id: URI(id.to_string()),
+            identifier: None,
+            eidas_legal_identifier: None,
+            registration: None,
+            vat_identifier: None,
+            tax_identifier: None,
+            preferred_name,
+            alternative_name: None,
+            home_page: None,
+            has_location: None,
+            has_accreditation: Vec::new(),
+            has_unit: Vec::new(),
+            unit_of: None,
+            logo: None,
+        }
+    }
+
+    /// The identifier of the organisation.
+    pub fn id(&self) -> &str {
+        &self.id.0
+    }
+
+    /// The primary name of the organisation.
+    pub fn preferred_name(&self) -> &Text {
+        &self.preferred_name
+    }
+
+    /// The accreditation records of the organisation.
+    pub fn accreditations(&self) -> &[Accreditation] {
+        &self.has_accreditation
+    }
+
+    /// Associates an accreditation record with the organisation.
+    /// Records of other organisations are refused.
+    pub fn add_accreditation(&mut self, accreditation: Accreditation) -> Result<(), String> {
+        if accreditation.organisation != self.id {
+            return Err(format!(
+                "Could not associate accreditation {} of {} with organisation {}…",
+                accreditation.id.0, accreditation.organisation.0, self.id.0
+            ));
+        }
+        if !self.has_accreditation.iter().any(|existing| existing.id == accreditation.id) {
+            self.has_accreditation.push(accreditation);
+        }
+        Ok(())
+    }
 }
 
 /// Details to Contact an Agent. A contact point for an agent.
@@ -220,49 +805,190 @@ struct ContactInformation {
 /// *   the quality assurance and/or licensing of an organisation.
 /// *   the quality assurance and/or licensing of an organisation
 ///     with respect to a specific qualification.
-struct Accreditation {
+#[derive(Debug, Clone, PartialEq)]
+pub struct Accreditation {
     /// The portable and unique identifier of the accreditation record.
     id: URI,
     /// An alternative Identifier of the Accreditation,
     /// as assigned to it by the accrediting agent.
-    identifier: Identifier,
+    identifier: Option<Identifier>,
     /// The type of accreditation.
     accreditation_type: controlled_vocabularies::AccreditationType,
     /// The title of the accreditation.
     title: Text,
     /// A description of this accreditation.
-    description: Note,
+    description: Option<Note>,
     /// The Quality Decision issued by the Quality Assuring Authority.
-    decision: TextualScore,
+    decision: Option<TextualScore>,
     /// A publicly accessible report of the quality assurance decision.
-    report: WebDocument,
-    /// The organisation whose activities are being accredited.
-    organisation: Box<Organisation>,
-    /// The qualification that was accredited.
-    limit_qualification: Qualification,
+    report: Option<WebDocument>,
+    /// The identifier of the organisation whose activities are being accredited.
+    /// The organisation is referred to by its identifier, as it refers
+    /// back to its accreditations.
+    organisation: URI,
+    /// The identifier of the qualification that was accredited, if the
+    /// accreditation is limited to one.
+    limit_qualification: Option<URI>,
     /// The field of education for which the accreditation is valid.
-    limit_field: Code,
+    limit_field: Option<Code>,
     /// The european qualification level for which the accreditation is valid.
-    limit_eqf_level: eqf::EQFLevel,
+    limit_eqf_level: Option<eqf::EQFLevel>,
     /// The jurisdiction for which the accreditation is valid.
-    limit_jurisdiction: Code,
-    /// The Quality Assuring Authority. (i.e assurer)
-    accrediting_agent: Box<Organisation>,
+    limit_jurisdiction: Option<Code>,
+    /// The identifier of the Quality Assuring Authority. (i.e assurer)
+    accrediting_agent: Option<URI>,
     /// The date when the accreditation was formally approved/issued.
     issue_date: chrono::naive::NaiveDateTime,
     /// The date when the accreditation has to be re-viewed.
-    review_date: chrono::naive::NaiveDateTime,
-    /// The date when the accreditation expires or was expired.
-    expiry_date: chrono::naive::NaiveDateTime,
+    review_date: Option<chrono::naive::NaiveDateTime>,
+    /// The date when the accreditation expires or was expired, if it expires.
+    expiry_date: Option<chrono::naive::NaiveDateTime>,
     /// An additional free text note about the accreditation.
-    additional_note: Note,
+    additional_note: Option<Note>,
     /// The homepage of the accreditation.
-    home_page: WebDocument,
+    home_page: Option<WebDocument>,
     /// The landingpage of the accreditation.
-    landing_page: WebDocument,
+    landing_page: Option<WebDocument>,
     /// A public web document containing additional
     /// documentation describing the Accreditation Procedures and Standards
-    supplementary_document: WebDocument,
+    supplementary_document: Option<WebDocument>,
+}
+
+impl Accreditation {
+    /// Constructs an accreditation of the organisation with the identifier
+    /// `organisation`, valid from `issue_date` until `expiry_date`,
+    /// without any limits.
+    pub fn new(
+        id: &str,
+        accreditation_type: controlled_vocabularies::AccreditationType,
+        title: Text,
+        organisation: &str,
+        issue_date: chrono::naive::NaiveDateTime,
+        expiry_date: Option<chrono::naive::NaiveDateTime>,
+    ) -> Result<Accreditation, String> {
+        if expiry_date.is_some_and(|expiry_date| expiry_date < issue_date) {
+            return Err(format!("Could not form accreditation {}: it expires before it was issued…", id));
+        }
+        Ok(Accreditation {
+            id: URI(id.to_string()),
+            identifier: None,
+            accreditation_type,
+            title,
+            description: None,
+            decision: None,
+            report: None,
+            organisation: URI(organisation.to_string()),
+            limit_qualification: None,
+            limit_field: None,
+            limit_eqf_level: None,
+            limit_jurisdiction: None,
+            accrediting_agent: None,
+            issue_date,
+            review_date: None,
+            expiry_date,
+            additional_note: None,
+            home_page: None,
+            landing_page: None,
+            supplementary_document: None,
+        })
+    }
+
+    /// Limits the accreditation to the qualification with the identifier
+    /// `qualification`, to an EQF level and to a jurisdiction.
+    /// `None` leaves the accreditation unlimited in that respect.
+    pub fn limit(
+        &mut self,
+        qualification: Option<&str>,
+        eqf_level: Option<eqf::EQFLevel>,
+        jurisdiction: Option<Code>,
+    ) {
+        self.limit_qualification = qualification.map(|id| URI(id.to_string()));
+        self.limit_eqf_level = eqf_level;
+        self.limit_jurisdiction = jurisdiction;
+    }
+
+    /// Sets the identifier of the Quality Assuring Authority.
+    pub fn set_accrediting_agent(&mut self, accrediting_agent: &str) {
+        self.accrediting_agent = Some(URI(accrediting_agent.to_string()));
+    }
+
+    /// The identifier of the accreditation record.
+    pub fn id(&self) -> &str {
+        &self.id.0
+    }
+
+    /// The type of accreditation.
+    pub fn accreditation_type(&self) -> controlled_vocabularies::AccreditationType {
+        self.accreditation_type
+    }
+
+    /// The title of the accreditation.
+    pub fn title(&self) -> &Text {
+        &self.title
+    }
+
+    /// The identifier of the accredited organisation.
+    pub fn organisation(&self) -> &str {
+        &self.organisation.0
+    }
+
+    /// The identifier of the accredited qualification, if the accreditation is limited to one.
+    pub fn limit_qualification(&self) -> Option<&str> {
+        self.limit_qualification.as_ref().map(|uri| uri.0.as_str())
+    }
+
+    /// The EQF level the accreditation is limited to, if any.
+    pub fn limit_eqf_level(&self) -> Option<eqf::EQFLevel> {
+        self.limit_eqf_level
+    }
+
+    /// The jurisdiction the accreditation is limited to, if any.
+    pub fn limit_jurisdiction(&self) -> Option<&Code> {
+        self.limit_jurisdiction.as_ref()
+    }
+
+    /// The identifier of the Quality Assuring Authority, if known.
+    pub fn accrediting_agent(&self) -> Option<&str> {
+        self.accrediting_agent.as_ref().map(|uri| uri.0.as_str())
+    }
+
+    /// The date when the accreditation was issued.
+    pub fn issue_date(&self) -> chrono::naive::NaiveDateTime {
+        self.issue_date
+    }
+
+    /// The date when the accreditation expires, if it expires.
+    pub fn expiry_date(&self) -> Option<chrono::naive::NaiveDateTime> {
+        self.expiry_date
+    }
+
+    /// Whether the accreditation was in force at the time `at`.
+    pub fn is_in_force_at(&self, at: chrono::naive::NaiveDateTime) -> bool {
+        self.issue_date <= at && self.expiry_date.is_none_or(|expiry_date| at < expiry_date)
+    }
+
+    /// Whether the accreditation covers awarding the qualification with
+    /// the identifier `qualification` at the given EQF level at the time `at`
+    /// in the awarding location, the URI of a country or region.
+    /// An accreditation limited to a jurisdiction does not cover awarding
+    /// in an unknown location.
+    pub fn covers(
+        &self,
+        qualification: &str,
+        eqf_level: Option<eqf::EQFLevel>,
+        location: Option<&str>,
+        at: chrono::naive::NaiveDateTime,
+    ) -> bool {
+        let within = |limit: Option<&str>, value: Option<&str>| match (limit, value) {
+            (Some(limit), Some(value)) => limit == value,
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+        self.is_in_force_at(at)
+            && within(self.limit_qualification(), Some(qualification))
+            && (self.limit_eqf_level.is_none() || self.limit_eqf_level == eqf_level)
+            && within(self.limit_jurisdiction.as_ref().map(Code::uri), location)
+    }
 }
 
 /// A verifiable presentation of a set of credentials.
@@ -277,7 +1003,7 @@ struct VerifiablePresentation {
 struct EuropassPresentation {
     /// A verifiable EuropassCredential.
     verfiable_credential: EuropassCredential,
-    ///
+    /// The verification check performed on the credential.
     verification_check: VerificationCheck,
     /// The cryptographic proof that can be used
     /// to detect tampering and verify the authorship of a presentation.
@@ -285,20 +1011,81 @@ struct EuropassPresentation {
 }
 
 /// A verification check.
-struct VerificationCheck {
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationCheck {
     /// The portable and unique identifier of the verification check
     id: URI,
     /// The type of verification check.
     /// One of Europass standard list of verification types.
     check_type: controlled_vocabularies::VerificationType,
-    /// The credential subject of this verififcation check.
-    subject: EuropassCredential,
+    /// The identifier of the credential subject of this verififcation check.
+    /// The credential is referred to by its identifier, as credentials
+    /// received from other institutions are verified in their serialized form.
+    subject: URI,
     /// The result of the check.
     status: controlled_vocabularies::VerificationStatus,
     /// A free text description of the check and the result.
     description: Note,
 }
 
+impl VerificationCheck {
+    /// Constructs the result of a check of the given type performed on
+    /// the credential with the identifier `subject`. The identifier of
+    /// the check is formed from the identifier of the credential and
+    /// the notation of the check type.
+    pub fn new(
+        check_type: controlled_vocabularies::VerificationType,
+        subject: &str,
+        status: controlled_vocabularies::VerificationStatus,
+        description: Note,
+    ) -> VerificationCheck {
+        VerificationCheck::named(check_type.notation(), check_type, subject, status, description)
+    }
+
+    /// Constructs the result of a check like `new`, but forms the identifier
+    /// of the check from `name`, which distinguishes custom checks from each other.
+    pub fn named(
+        name: &str,
+        check_type: controlled_vocabularies::VerificationType,
+        subject: &str,
+        status: controlled_vocabularies::VerificationStatus,
+        description: Note,
+    ) -> VerificationCheck {
+        VerificationCheck {
+            id: URI(format!("{}#verification-{}", subject, name)),
+            check_type,
+            subject: URI(subject.to_string()),
+            status,
+            description,
+        }
+    }
+
+    /// The identifier of this check.
+    pub fn id(&self) -> &str {
+        &self.id.0
+    }
+
+    /// The type of this check.
+    pub fn check_type(&self) -> controlled_vocabularies::VerificationType {
+        self.check_type
+    }
+
+    /// The identifier of the credential this check was performed on.
+    pub fn subject(&self) -> &str {
+        &self.subject.0
+    }
+
+    /// The result of this check.
+    pub fn status(&self) -> controlled_vocabularies::VerificationStatus {
+        self.status
+    }
+
+    /// The description of this check and its result.
+    pub fn description(&self) -> &Note {
+        &self.description
+    }
+}
+
 /// The types of attachments that might come with a Europass credential.
 enum EuropassAttachment {
     /// Portable Document Format
@@ -311,93 +1098,191 @@ enum EuropassAttachment {
 
 /// A description of what a person may learn using the opportunity,
 /// expressed as learning outcomes. A specification of learning.
-struct LearningSpecification {
+pub struct LearningSpecification {
     /// A portable and unique identifier of the learning specification.
     id: URI,
     /// An alternative identifier of the learning specification,
     /// as assigned to it by the organisation who designed the specification.
-    identifier: Identifier,
+    identifier: Option<Identifier>,
     /// The type of learning opportunity.
-    learning_opportunity_type: Code,
+    learning_opportunity_type: Option<Code>,
     /// The title of the learning specification.
     title: Text,
     /// An alternative name of the learning specification.
-    alternative_labe: Text,
+    alternative_labe: Option<Text>,
     /// Short and abstract description about the learning specification.
-    definition: Note,
+    definition: Option<Note>,
     /// The full learning outcome description of the learning specification.
-    learning_outcome_description: Note,
+    learning_outcome_description: Option<Note>,
     /// An additional free text note about the learning specification.
-    addtional_note: Note,
+    addtional_note: Option<Note>,
     /// The homepage (a public web document) of the learning specification.
-    home_page: WebDocument,
+    home_page: Option<WebDocument>,
     /// A public web document containing additional documentation
     /// about the learning specification.
-    supplemenary_document: WebDocument,
+    supplemenary_document: Option<WebDocument>,
     /// Thematic Area according to the ISCED-F 2013 Classification
-    iscedfc_code: Code,
+    iscedfc_code: Option<Code>,
     /// An associated field of education from another
     /// semantic framework than the ISCED classification.
-    education_subject: EducationSubjectAssociation,
+    education_subject: Option<EducationSubjectAssociation>,
     /// The estimated number of hours the learner is expected to spend
     /// engaged in learning to earn the award. This would include
     /// the notional number of hours in class, in group work, in practicals,
     /// as well as hours engaged in self-motivated study.
-    volume_of_learning: Duration,
+    volume_of_learning: Option<Duration>,
     /// The credit points assigned to the learning specification,
     /// following the ECTS credit system.
-    ects_credit_points: NumericScore,
+    ects_credit_points: Option<NumericScore>,
     /// The credit points assigned to the learning specification,
     /// following an alternative educational credit system.
-    credit_points: NumericScore,
+    credit_points: Option<NumericScore>,
     /// An associated level of education within a semantic framework
     /// describing education levels.
-    education_level: EducationLevelAssociation,
+    education_level: Option<EducationLevelAssociation>,
     /// The instruction and/or assessment language(s) used.
-    language: crate::controlled_vocabularies::Language,
+    language: Option<crate::controlled_vocabularies::Language>,
     /// The mode of learning and or assessment.
-    mode: crate::controlled_vocabularies::ModeOfLearningType,
+    mode: Option<crate::controlled_vocabularies::ModeOfLearningType>,
     /// The type of learning setting (formal, non-formal).
-    learning_setting: crate::controlled_vocabularies::LearningSettingType,
+    learning_setting: Option<crate::controlled_vocabularies::LearningSettingType>,
     /// The maximum duration (in months) that a person may use
     /// to complete the learning opportunity.
-    maximum_duration: Duration,
+    maximum_duration: Option<Duration>,
     /// A specific target group or category for which this specification is designed.
-    target_group: crate::controlled_vocabularies::LearningTargetGroup,
+    target_group: Option<crate::controlled_vocabularies::LearningTargetGroup>,
     /// Specific entry requirements or prerequisites of individuals
     /// for which this specification is designed to start this learning opportunity.
-    entry_requirements_note: Note,
+    entry_requirements_note: Option<Note>,
     /// An individual (expected) learning outcome of the learning specification.
-    learning_outcome: LearningOutcome,
+    learning_outcome: Vec<LearningOutcome>,
     /// Activities which a person can perform to acquire
     /// the expected learning outcomes.
-    learning_activity_specification: LearningActivitySpecification,
+    learning_activity_specification: Vec<LearningActivitySpecification>,
     /// Assessments a person can undergo to prove
     /// the acquisition of the learning outcomes
-    assessment_sppecification: AssessmentSpecification,
+    assessment_sppecification: Vec<AssessmentSpecification>,
     /// Rights, such as which the person may acquire as
     /// a result of acquiring the learning outcomes.
-    entitlement_specification: EntitlementSpecification,
+    entitlement_specification: Vec<EntitlementSpecification>,
     /// Refers to an activity related to the awarding of
     /// the learning specification, such as the country or region
     /// where the qualifi-cation is awarded, the awarding body and
     /// optionally the awarding period now or in the past.
-    awarding_opportunity: AwardingOpportunity,
+    awarding_opportunity: Vec<AwardingOpportunity>,
     /// A learning specification can be composed of other "narrower"
     /// learning specifications which when combined make up this
     /// learning specification.
-    has_part: Box<LearningSpecification>,
+    has_part: Vec<LearningSpecification>,
     /// A learning specification (e.g. a standard) of which
     /// this specification is a specialisation.
     ///
     /// TODO: To be imlemented at a later stage.
-    specialisation_of: Box<LearningSpecification>,
+    specialisation_of: Option<Box<LearningSpecification>>,
+    /// The qualification awarded on completing the learning, if any,
+    /// which makes this a specification of a qualification.
+    qualification: Option<Qualification>,
+}
+
+impl LearningSpecification {
+    /// Constructs a learning specification with the given identifier and title.
+    pub fn new(id: &str, title: Text) -> LearningSpecification {
+        LearningSpecification {
+            id: URI(id.to_string()),
+            identifier: None,
+            learning_opportunity_type: None,
+            title,
+            alternative_labe: None,
+            definition: None,
+            learning_outcome_description: None,
+            addtional_note: None,
+            home_page: None,
+            supplemenary_document: None,
+            iscedfc_code: None,
+            education_subject: None,
+            volume_of_learning: None,
+            ects_credit_points: None,
+            credit_points: None,
+            education_level: None,
+            language: None,
+            mode: None,
+            learning_setting: None,
+            maximum_duration: None,
+            target_group: None,
+            entry_requirements_note: None,
+            learning_outcome: Vec::new(),
+            learning_activity_specification: Vec::new(),
+            assessment_sppecification: Vec::new(),
+            entitlement_specification: Vec::new(),
+            awarding_opportunity: Vec::new(),
+            has_part: Vec::new(),
+            specialisation_of: None,
+            qualification: None,
+        }
+    }
+
+    /// Sets the ECTS credit points of the learning and the expected workload
+    /// of earning them, as derived with a `CreditWorkloadRule`.
+    pub fn set_credit_points(&mut self, ects_credit_points: f64, volume_of_learning: Duration) {
+        self.ects_credit_points = Some(NumericScore { content: ects_credit_points });
+        self.volume_of_learning = Some(volume_of_learning);
+    }
+
+    /// Sets the thematic area of the learning, a field of education of ISCED-F 2013.
+    pub fn set_iscedf_code(&mut self, iscedf_code: Code) {
+        self.iscedfc_code = Some(iscedf_code);
+    }
+
+    /// Sets the language of instruction and assessment of the learning.
+    pub fn set_language(&mut self, language: crate::controlled_vocabularies::Language) {
+        self.language = Some(language);
+    }
+
+    /// Sets the qualification awarded on completing the learning.
+    pub fn set_qualification(&mut self, qualification: Qualification) {
+        self.qualification = Some(qualification);
+    }
+
+    /// The identifier of the learning specification.
+    pub fn id(&self) -> &str {
+        &self.id.0
+    }
+
+    /// The title of the learning specification.
+    pub fn title(&self) -> &Text {
+        &self.title
+    }
+
+    /// The ECTS credit points of the learning, if known.
+    pub fn ects_credit_points(&self) -> Option<f64> {
+        self.ects_credit_points.as_ref().map(|points| points.content)
+    }
+
+    /// The expected workload of the learning, if known.
+    pub fn volume_of_learning(&self) -> Option<Duration> {
+        self.volume_of_learning
+    }
+
+    /// The thematic area of the learning in ISCED-F 2013, if known.
+    pub fn iscedf_code(&self) -> Option<&Code> {
+        self.iscedfc_code.as_ref()
+    }
+
+    /// The language of instruction and assessment of the learning, if known.
+    pub fn language(&self) -> Option<crate::controlled_vocabularies::Language> {
+        self.language
+    }
+
+    /// The qualification awarded on completing the learning, if any.
+    pub fn qualification(&self) -> Option<&Qualification> {
+        self.qualification.as_ref()
+    }
 }
 
 /// A specification of an assessment and validation process which is
 /// obtained when a competent authority determines that an individual
 /// has achieved learning outcomes to given standards.
-struct Qualification {
+pub struct Qualification {
     /// Indicates whether a qualification is a
     /// full qualification or part of another qualification.
     is_partial_qualification: IndicatorType,
@@ -407,8 +1292,63 @@ struct Qualification {
     /// The qualification level as specified by
     /// a National Qualification Framework.
     nqf_level: nqf::NQF,
-    /// The accreditation of a qualification.
-    has_accreditation: Box<Accreditation>,
+    /// The accreditation of a qualification, if any.
+    has_accreditation: Option<Box<Accreditation>>,
+}
+
+impl Qualification {
+    /// Constructs an unaccredited qualification at the given levels,
+    /// which must be consistent with each other.
+    pub fn new(
+        is_partial_qualification: IndicatorType,
+        eqf_level: eqf::EQFLevel,
+        nqf_level: nqf::NQF,
+    ) -> Result<Qualification, String> {
+        let qualification = Qualification {
+            is_partial_qualification,
+            eqf_level,
+            nqf_level,
+            has_accreditation: None,
+        };
+        qualification.check_levels()?;
+        Ok(qualification)
+    }
+
+    /// Constructs an unaccredited qualification at the given NQF level
+    /// and the EQF level it was referenced to.
+    pub fn from_nqf_level(is_partial_qualification: IndicatorType, nqf_level: nqf::NQF) -> Result<Qualification, String> {
+        let eqf_level = nqf_level.eqf_level().ok_or_else(|| {
+            format!("Could not form a qualification from NQF level {} without an EQF level…", nqf_level.uri())
+        })?;
+        Qualification::new(is_partial_qualification, eqf_level, nqf_level)
+    }
+
+    /// Whether this is a part of another qualification.
+    pub fn is_partial_qualification(&self) -> IndicatorType {
+        self.is_partial_qualification
+    }
+
+    /// The EQF level of this qualification.
+    pub fn eqf_level(&self) -> eqf::EQFLevel {
+        self.eqf_level
+    }
+
+    /// The NQF level of this qualification.
+    pub fn nqf_level(&self) -> nqf::NQF {
+        self.nqf_level
+    }
+
+    /// The accreditation of this qualification, if any.
+    pub fn accreditation(&self) -> Option<&Accreditation> {
+        self.has_accreditation.as_deref()
+    }
+
+    /// Checks that the NQF level of the qualification was referenced
+    /// to its EQF level, so that for example a level 6 NQF qualification
+    /// cannot be issued with EQF level 7.
+    fn check_levels(&self) -> Result<(), String> {
+        self.nqf_level.check_eqf_level(self.eqf_level)
+    }
 }
 
 /// A statement regarding what a learner knows, understands and is able
@@ -553,7 +1493,7 @@ struct AssessmentSpecification {
     /// The type of assessment.
     assessment_type: controlled_vocabularies::AssessmentType,
     /// The language(s) of assessment used.
-    language: Code,
+    language: controlled_vocabularies::Language,
     /// The mode of learning and or assessment.
     mode: Code,
     /// A description of the specification of which
@@ -780,8 +1720,8 @@ struct Entitlement {
     description: Note,
     /// The date from which the entitlement was conferred.
     issued_date: chrono::naive::NaiveDate,
-    /// The date until which the entitlment was conferred.
-    expiry_date: chrono::naive::NaiveDate,
+    /// The date on which the entitlement expires, if it does.
+    expiry_date: Option<chrono::naive::NaiveDate>,
     /// An additional free text note about the entitlement.
     additional_note: Note,
     /// A learning achievement which gave rise to the entitlement.
@@ -793,6 +1733,15 @@ struct Entitlement {
     has_part: Box<Entitlement>,
 }
 
+impl Entitlement {
+    /// The period during which this entitlement is valid.
+    /// The entitlement expires at the start of its expiry date,
+    /// like the credentials of SISU attainments.
+    fn validity(&self) -> Result<ValidityPeriod, String> {
+        ValidityPeriod::from_dates(Some(self.issued_date), self.expiry_date)
+    }
+}
+
 /// The specification of a right a person has access to,
 /// typically as a result of a learning achievement.
 /// It may take the form of the right to be a member of an organisation,
@@ -839,7 +1788,12 @@ struct EntitlementSpecification {
 /// An enumeration of the official languages used in the European Union,
 /// as of 2013-07-01. See https://eur-lex.europa.eu/eli/reg/1958/1(1)/2013-07-01
 /// for details.
-enum EuropeanLanguage {
+///
+/// Also includes the languages of Finland and its neighbouring countries
+/// that are not official languages of the European Union, as they are used
+/// in teaching and assessment at Finnish universities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EuropeanLanguage {
     Bulgarian,
     Croatian,
     Czech,
@@ -863,7 +1817,169 @@ enum EuropeanLanguage {
     Slovak,
     Slovene,
     Spanish,
-    Swedish
+    Swedish,
+    // Languages outside the official languages of the European Union
+    // used in teaching at Finnish universities.
+    NorthernSami,
+    InariSami,
+    SkoltSami,
+    Karelian,
+    Icelandic,
+    Norwegian,
+    Russian,
+    Ukrainian,
+}
+
+/// The authority code of each language in the language authority table.
+const EUROPEAN_LANGUAGES: &[(EuropeanLanguage, &str)] = &[
+    (EuropeanLanguage::Bulgarian, "BUL"),
+    (EuropeanLanguage::Croatian, "HRV"),
+    (EuropeanLanguage::Czech, "CES"),
+    (EuropeanLanguage::Danish, "DAN"),
+    (EuropeanLanguage::Dutch, "NLD"),
+    (EuropeanLanguage::English, "ENG"),
+    (EuropeanLanguage::Estonian, "EST"),
+    (EuropeanLanguage::Finnish, "FIN"),
+    (EuropeanLanguage::French, "FRA"),
+    (EuropeanLanguage::German, "DEU"),
+    (EuropeanLanguage::Greek, "ELL"),
+    (EuropeanLanguage::Hungarian, "HUN"),
+    (EuropeanLanguage::Irish, "GLE"),
+    (EuropeanLanguage::Italian, "ITA"),
+    (EuropeanLanguage::Latvian, "LAV"),
+    (EuropeanLanguage::Lithuanian, "LIT"),
+    (EuropeanLanguage::Maltese, "MLT"),
+    (EuropeanLanguage::Polish, "POL"),
+    (EuropeanLanguage::Portugese, "POR"),
+    (EuropeanLanguage::Romanian, "RON"),
+    (EuropeanLanguage::Slovak, "SLK"),
+    (EuropeanLanguage::Slovene, "SLV"),
+    (EuropeanLanguage::Spanish, "SPA"),
+    (EuropeanLanguage::Swedish, "SWE"),
+    (EuropeanLanguage::NorthernSami, "SME"),
+    (EuropeanLanguage::InariSami, "SMN"),
+    (EuropeanLanguage::SkoltSami, "SMS"),
+    (EuropeanLanguage::Karelian, "KRL"),
+    (EuropeanLanguage::Icelandic, "ISL"),
+    (EuropeanLanguage::Norwegian, "NOR"),
+    (EuropeanLanguage::Russian, "RUS"),
+    (EuropeanLanguage::Ukrainian, "UKR"),
+];
+
+impl EuropeanLanguage {
+    fn entry(&self) -> &'static (EuropeanLanguage, &'static str) {
+        EUROPEAN_LANGUAGES
+            .iter()
+            .find(|(language, _)| language == self)
+            .expect("Every language has an entry in EUROPEAN_LANGUAGES")
+    }
+
+    /// The entry of this language in the language authority table.
+    pub fn authority_language(&self) -> controlled_vocabularies::Language {
+        std::convert::TryFrom::try_from(self.entry().1)
+            .expect("Every European language is in the language authority table")
+    }
+
+    /// The two letter ISO 639-1 code of this language, if it has one.
+    pub fn iso_639_1(&self) -> Option<&'static str> {
+        self.authority_language().iso_639_1()
+    }
+
+    /// The three letter ISO 639-3 code of this language.
+    pub fn iso_639_3(&self) -> String {
+        self.authority_language().iso_639_3()
+    }
+
+    /// The URI of this language in the language authority table.
+    pub fn nal_uri(&self) -> String {
+        self.authority_language().nal_uri()
+    }
+
+    /// The language tag of this language, used to mark the language of texts:
+    /// the ISO 639-1 code if the language has one and the ISO 639-3 code if not.
+    pub fn language_tag(&self) -> String {
+        match self.iso_639_1() {
+            Some(code) => code.to_string(),
+            None => self.iso_639_3(),
+        }
+    }
+
+    /// The name of this language in the given language,
+    /// as labelled in the language authority table.
+    /// Names are available in English, Finnish and Swedish.
+    pub fn label(&self, in_language: EuropeanLanguage) -> Option<&'static str> {
+        self.authority_language().pref_label(&in_language.language_tag())
+    }
+}
+
+impl From<EuropeanLanguage> for controlled_vocabularies::Language {
+    fn from(language: EuropeanLanguage) -> Self {
+        language.authority_language()
+    }
+}
+
+impl std::fmt::Display for EuropeanLanguage {
+    /// Formats the language as its language tag.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.language_tag())
+    }
+}
+
+impl std::str::FromStr for EuropeanLanguage {
+    type Err = String;
+
+    /// Accepts language tags with an optional region subtag (e.g. `fi-FI`),
+    /// ISO 639-1 and ISO 639-3 codes, URIs of the language authority table
+    /// and SISU language URNs.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || format!("Could not form a European language from string {}…", value);
+        let code = match value.find('-') {
+            Some(index) if !value.contains(':') => &value[..index],
+            _ => value,
+        };
+        let language = controlled_vocabularies::Language::try_from(code).map_err(|_| error())?;
+        EUROPEAN_LANGUAGES
+            .iter()
+            .find(|(_, authority_code)| *authority_code == language.authority_code())
+            .map(|(european_language, _)| *european_language)
+            .ok_or_else(error)
+    }
+}
+
+/// The order of preference of languages, used to pick the primary language
+/// of a multilingual text. The primary language of a text is the first
+/// language of the chain the text has a translation in, or the first
+/// translation of the text if it has none of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageFallback {
+    chain: Vec<EuropeanLanguage>,
+}
+
+impl LanguageFallback {
+    /// Constructs a fallback chain preferring languages in the given order.
+    pub fn new(chain: Vec<EuropeanLanguage>) -> LanguageFallback {
+        LanguageFallback { chain }
+    }
+
+    /// The position of the given language in this chain,
+    /// languages not in the chain coming last.
+    fn rank(&self, language: EuropeanLanguage) -> usize {
+        self.chain
+            .iter()
+            .position(|preferred| *preferred == language)
+            .unwrap_or(self.chain.len())
+    }
+}
+
+impl Default for LanguageFallback {
+    /// English, then Finnish, then Swedish.
+    fn default() -> Self {
+        LanguageFallback::new(vec![
+            EuropeanLanguage::English,
+            EuropeanLanguage::Finnish,
+            EuropeanLanguage::Swedish,
+        ])
+    }
 }
 
 // ------- Media Classes -------
@@ -871,9 +1987,12 @@ struct InteractiveWebResource;
 struct Phone;
 struct MailBox;
 struct Address;
+#[derive(Debug, Clone, PartialEq)]
 struct Location;
+#[derive(Debug, Clone, PartialEq)]
 struct WebDocument;
 struct MediaObject;
+#[derive(Debug, Clone, PartialEq)]
 struct ImageObject;
 
 // ------- Association Classes -------
@@ -886,6 +2005,7 @@ struct AssociationObject;
 /// It is used to identify persons and organisations.
 /// In these cases and more, the identifier itself will be some sort of
 /// alpha-numeric string but that string only has meaning if it is contextualised.
+#[derive(Debug, Clone, PartialEq)]
 struct Identifier {
     /// Content string which is the identifier.
     /// A character string used to uniquely identify
@@ -928,7 +2048,8 @@ struct LegalIdentifier {
 /// vocabularies are known to exist for a particular domain of interest.
 /// It is not the job of the JV/CV Vocabularies to mandate which controlled
 /// vocabularies are used but we offer some guidance on how to use them.
-struct Code {
+#[derive(Debug, Clone, PartialEq)]
+pub struct Code {
     /// The term bieng described.
     target_notation: String,
     /// The identification of the controlled vocabulary
@@ -944,30 +2065,195 @@ struct Code {
     uri: String,
 }
 
+impl Code {
+    /// Constructs a code from a term of a controlled vocabulary.
+    pub fn new(
+        target_notation: &str,
+        target_framework_uri: &str,
+        target_framework: &str,
+        target_name: &str,
+        target_description: &str,
+        uri: &str,
+    ) -> Code {
+        Code {
+            target_notation: target_notation.to_string(),
+            target_framework_uri: target_framework_uri.to_string(),
+            target_framework: target_framework.to_string(),
+            target_name: target_name.to_string(),
+            target_description: target_description.to_string(),
+            uri: uri.to_string(),
+        }
+    }
+
+    /// The term being described.
+    pub fn target_notation(&self) -> &str {
+        &self.target_notation
+    }
+
+    /// The identification of the controlled vocabulary (the code list).
+    pub fn target_framework_uri(&self) -> &str {
+        &self.target_framework_uri
+    }
+
+    /// The name of the controlled vocabulary (the code list).
+    pub fn target_framework(&self) -> &str {
+        &self.target_framework
+    }
+
+    /// The text equivalent of the code content component.
+    pub fn target_name(&self) -> &str {
+        &self.target_name
+    }
+
+    /// A portable identifier (i.e a URI) of the code.
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+}
+
 /// A character string (i.e. a finite set of characters)
 /// generally in the form of words of a language.
-struct Text {
-    /// The content of the string.
-    content: String,
-    /// The language of the content field.
-    language: EuropeanLanguage
+/// The same string may be given in several languages,
+/// all of which are emitted in the Europass output.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Text {
+    /// The translations of the string, the primary language first.
+    contents: Vec<(EuropeanLanguage, String)>,
+}
+
+impl Text {
+    /// Constructs a text from a string in a single language.
+    pub fn new(language: EuropeanLanguage, content: &str) -> Text {
+        Text { contents: vec![(language, content.to_string())] }
+    }
+
+    /// Constructs a text from the given translations, ordering them
+    /// so that the primary language according to `fallback` comes first.
+    /// Translations to the same language after the first one are ignored.
+    pub fn from_translations<I>(translations: I, fallback: &LanguageFallback) -> Text
+    where
+        I: IntoIterator<Item = (EuropeanLanguage, String)>,
+    {
+        let mut text = Text::default();
+        for (language, content) in translations {
+            if text.get(language).is_none() {
+                text.contents.push((language, content));
+            }
+        }
+        // A stable sort keeps the original order of languages outside the chain.
+        text.contents.sort_by_key(|(language, _)| fallback.rank(*language));
+        text
+    }
+
+    /// The content of this text in the given language, if available.
+    pub fn get(&self, language: EuropeanLanguage) -> Option<&str> {
+        self.contents
+            .iter()
+            .find(|(translated, _)| *translated == language)
+            .map(|(_, content)| content.as_str())
+    }
+
+    /// The language and content of the primary translation of this text.
+    pub fn primary(&self) -> Option<(EuropeanLanguage, &str)> {
+        self.contents
+            .first()
+            .map(|(language, content)| (*language, content.as_str()))
+    }
+
+    /// All translations of this text, the primary language first.
+    pub fn translations(&self) -> impl Iterator<Item = (EuropeanLanguage, &str)> {
+        self.contents
+            .iter()
+            .map(|(language, content)| (*language, content.as_str()))
+    }
+
+    /// Whether this text has no translations at all.
+    pub fn is_empty(&self) -> bool {
+        self.contents.is_empty()
+    }
+
+    /// The translations of this text as a JSON-LD language map
+    /// from language tags to contents.
+    pub fn to_language_map(&self) -> serde_json::Value {
+        self.translations()
+            .map(|(language, content)| (language.language_tag(), serde_json::Value::from(content)))
+            .collect::<serde_json::Map<String, serde_json::Value>>()
+            .into()
+    }
+
+    /// The translations of this text as `text` elements of the given mimetype.
+    fn localized_elements(&self, content_type: &'static str) -> Vec<LocalizedText<'_>> {
+        self.translations()
+            .map(|(language, content)| LocalizedText {
+                lang: language.language_tag(),
+                content_type,
+                content,
+            })
+            .collect()
+    }
+}
+
+impl serde::Serialize for Text {
+    /// Serializes every translation as a `text` element
+    /// with a `lang` attribute.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Text", 1)?;
+        state.serialize_field("text", &self.localized_elements("text/plain"))?;
+        state.end()
+    }
+}
+
+/// A single translation of a `Text` or `Note` in the Europass output.
+#[derive(serde::Serialize)]
+struct LocalizedText<'a> {
+    lang: String,
+    #[serde(rename = "content-type")]
+    content_type: &'static str,
+    #[serde(rename = "$value")]
+    content: &'a str,
 }
 
 /// A formatted character string (i.e. a finite set of characters)
 /// generally in the form of words of a language. The character string
 /// is passed/included in, and can be represented as, a (formatted) document
 /// fragment (formatted) according a given mimetype (e.g. "text/plain", "text/html", etc.)
-struct Note {
-    /// The free text note.
-    content: String,
-    /// The identifier of the language used in the Content attribute.
-    language: EuropeanLanguage,
+#[derive(Debug, Clone, PartialEq)]
+pub struct Note {
+    /// The free text note, possibly in several languages.
+    content: Text,
     /// The identifier of the mimetype used in the Content attribute.
-    format: String,
+    format: &'static str,
     /// The information topic this note is about.
     topic: String,
 }
 
+impl Note {
+    /// Constructs a plain text note from a possibly multilingual text.
+    pub fn new(content: Text) -> Note {
+        Note { content, format: "text/plain", topic: String::new() }
+    }
+
+    /// The content of this note.
+    pub fn content(&self) -> &Text {
+        &self.content
+    }
+}
+
+impl serde::Serialize for Note {
+    /// Serializes every translation as a `text` element
+    /// with a `lang` attribute, like a `Text`.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Note", 2)?;
+        if !self.topic.is_empty() {
+            state.serialize_field("topic", &self.topic)?;
+        }
+        state.serialize_field("text", &self.content.localized_elements(self.format))?;
+        state.end()
+    }
+}
+
 /// A notation (or code) is a character string according
 /// a given syntax encoding scheme.
 struct Notation {
@@ -990,17 +2276,18 @@ struct Score {
 /// A numeric score. Extends Score.
 struct NumericScore {
     content: f64,
-
 }
 
 /// A textual accreditation. Extends Score.
+#[derive(Debug, Clone, PartialEq)]
 struct TextualScore {
     content: String,
 }
 
 /// A standard measure with a unit from
 /// the MDR Measurement unit Named Authority List.
-struct Measure {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measure {
     /// The numeric value (i.e. measure).
     content: f64,
     /// A code indicating the type of unit measure,
@@ -1010,9 +2297,79 @@ struct Measure {
     unit: controlled_vocabularies::MDRunit,
 }
 
+impl Measure {
+    /// Constructs a measure in the given unit.
+    pub fn new(content: f64, unit: controlled_vocabularies::MDRunit) -> Measure {
+        Measure { content, unit }
+    }
+
+    /// The numeric value of this measure.
+    pub fn content(&self) -> f64 {
+        self.content
+    }
+
+    /// The unit of this measure.
+    pub fn unit(&self) -> controlled_vocabularies::MDRunit {
+        self.unit
+    }
+
+    /// This measure converted to the given unit of the same dimension,
+    /// such as minutes to hours.
+    pub fn convert_to(&self, unit: controlled_vocabularies::MDRunit) -> Result<Measure, String> {
+        Ok(Measure::new(self.unit.convert(self.content, unit)?, unit))
+    }
+
+    /// The sum of this and another measure of the same dimension
+    /// in the unit of this measure.
+    pub fn checked_add(&self, other: &Measure) -> Result<Measure, String> {
+        Ok(Measure::new(self.content + other.convert_to(self.unit)?.content, self.unit))
+    }
+
+    /// The difference of this and another measure of the same dimension
+    /// in the unit of this measure.
+    pub fn checked_sub(&self, other: &Measure) -> Result<Measure, String> {
+        Ok(Measure::new(self.content - other.convert_to(self.unit)?.content, self.unit))
+    }
+
+    /// This measure multiplied by the given factor.
+    pub fn multiplied(&self, factor: f64) -> Measure {
+        Measure::new(self.content * factor, self.unit)
+    }
+
+    /// The sum of the given measures normalised to the given unit.
+    pub fn sum<'a, I>(measures: I, unit: controlled_vocabularies::MDRunit) -> Result<Measure, String>
+    where
+        I: IntoIterator<Item = &'a Measure>,
+    {
+        measures
+            .into_iter()
+            .try_fold(Measure::new(0.0, unit), |total, measure| total.checked_add(measure))
+    }
+
+    /// This measure as a duration, for measures of time or of the work
+    /// effort of a single learner, such as labour hours.
+    pub fn to_duration(self) -> Result<Duration, String> {
+        let hours = self
+            .convert_to(controlled_vocabularies::MDRunit::Hour)
+            .or_else(|_| self.convert_to(controlled_vocabularies::MDRunit::LabourHour))
+            .map_err(|_| format!("Could not form a duration from measure {}…", self))?;
+        Ok(Duration::from_hours(hours.content))
+    }
+}
+
+impl std::fmt::Display for Measure {
+    /// Formats the measure with the common code of its unit,
+    /// as in `1.5 HUR`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} {}", self.content, self.unit.common_code())
+    }
+}
+
 /// A standard amount with a unit from
-/// the MDR Currencies Named Authority List.
-struct Amount {
+/// the MDR Currencies Named Authority List,
+/// such as a tuition fee or the amount of a grant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Amount {
     /// The numeric value (i.e. measure).
     content: f64,
     /// A code indicating the currency the content field is in.
@@ -1020,10 +2377,157 @@ struct Amount {
     unit: controlled_vocabularies::MDRcurrency,
 }
 
+impl Amount {
+    /// Constructs an amount of the given currency.
+    pub fn new(content: f64, unit: controlled_vocabularies::MDRcurrency) -> Amount {
+        Amount { content, unit }
+    }
+
+    /// Constructs an amount from a whole number of minor units,
+    /// such as cents. Currencies without minor units take the
+    /// number as is.
+    pub fn from_minor_units(minor_units: i64, unit: controlled_vocabularies::MDRcurrency) -> Amount {
+        Amount::new(minor_units as f64 / Amount::scale(unit), unit)
+    }
+
+    fn scale(unit: controlled_vocabularies::MDRcurrency) -> f64 {
+        10f64.powi(unit.minor_units().unwrap_or(0) as i32)
+    }
+
+    /// The numeric value of this amount.
+    pub fn content(&self) -> f64 {
+        self.content
+    }
+
+    /// The currency of this amount.
+    pub fn unit(&self) -> controlled_vocabularies::MDRcurrency {
+        self.unit
+    }
+
+    /// This amount as a whole number of minor units, such as cents,
+    /// rounded half away from zero.
+    pub fn minor_units(&self) -> i64 {
+        (self.content * Amount::scale(self.unit)).round() as i64
+    }
+
+    /// This amount rounded to the minor units of its currency.
+    pub fn rounded(&self) -> Amount {
+        Amount::from_minor_units(self.minor_units(), self.unit)
+    }
+
+    fn same_currency(&self, other: &Amount, operation: &str) -> Result<(), String> {
+        if self.unit == other.unit {
+            Ok(())
+        } else {
+            Err(format!(
+                "Could not {} amounts in different currencies {} and {}…",
+                operation,
+                self.unit.alphabetic_code(),
+                other.unit.alphabetic_code()
+            ))
+        }
+    }
+
+    /// The sum of this and another amount of the same currency,
+    /// rounded to minor units.
+    pub fn checked_add(&self, other: &Amount) -> Result<Amount, String> {
+        self.same_currency(other, "add")?;
+        Ok(Amount::from_minor_units(self.minor_units() + other.minor_units(), self.unit))
+    }
+
+    /// The difference of this and another amount of the same currency,
+    /// rounded to minor units.
+    pub fn checked_sub(&self, other: &Amount) -> Result<Amount, String> {
+        self.same_currency(other, "subtract")?;
+        Ok(Amount::from_minor_units(self.minor_units() - other.minor_units(), self.unit))
+    }
+
+    /// This amount multiplied by the given factor, such as the number
+    /// of semesters of a tuition fee, rounded to minor units.
+    pub fn multiplied(&self, factor: f64) -> Amount {
+        Amount::new(self.content * factor, self.unit).rounded()
+    }
+
+    /// The sum of the given amounts, which must all be of the given currency.
+    pub fn sum<'a, I>(amounts: I, unit: controlled_vocabularies::MDRcurrency) -> Result<Amount, String>
+    where
+        I: IntoIterator<Item = &'a Amount>,
+    {
+        amounts
+            .into_iter()
+            .try_fold(Amount::new(0.0, unit), |total, amount| total.checked_add(amount))
+    }
+
+    /// Formats this amount for the given language, with digits grouped
+    /// in thousands and the decimal separator used by the language,
+    /// as in `1,500.00 EUR` in English and `1 500,00 EUR` in Finnish.
+    pub fn format(&self, language: EuropeanLanguage) -> String {
+        let (group_separator, decimal_separator) = match language {
+            EuropeanLanguage::English | EuropeanLanguage::Irish | EuropeanLanguage::Maltese => (',', '.'),
+            EuropeanLanguage::Finnish
+            | EuropeanLanguage::Swedish
+            | EuropeanLanguage::French
+            | EuropeanLanguage::Czech
+            | EuropeanLanguage::Slovak
+            | EuropeanLanguage::Polish
+            | EuropeanLanguage::Estonian
+            | EuropeanLanguage::Latvian
+            | EuropeanLanguage::Lithuanian
+            | EuropeanLanguage::Hungarian
+            | EuropeanLanguage::Bulgarian
+            | EuropeanLanguage::Norwegian
+            | EuropeanLanguage::NorthernSami
+            | EuropeanLanguage::InariSami
+            | EuropeanLanguage::SkoltSami
+            | EuropeanLanguage::Karelian
+            | EuropeanLanguage::Russian
+            | EuropeanLanguage::Ukrainian => ('\u{a0}', ','),
+            _ => ('.', ','),
+        };
+        let number = self.format_number();
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", number.as_str()),
+        };
+        let (integer, fraction) = match number.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (number, None),
+        };
+        let mut grouped = String::new();
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push(group_separator);
+            }
+            grouped.push(digit);
+        }
+        match fraction {
+            Some(fraction) => format!("{}{}{}{} {}", sign, grouped, decimal_separator, fraction, self.unit.alphabetic_code()),
+            None => format!("{}{} {}", sign, grouped, self.unit.alphabetic_code()),
+        }
+    }
+
+    /// The content with as many decimals as the currency has minor units.
+    fn format_number(&self) -> String {
+        match self.unit.minor_units() {
+            Some(minor_units) => format!("{:.*}", minor_units as usize, self.content),
+            None => self.content.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Amount {
+    /// Formats the amount with its alphabetic currency code,
+    /// as in `1500.00 EUR`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} {}", self.format_number(), self.unit.alphabetic_code())
+    }
+}
+
 // ------- Primitive Types -------
 
 /// A Uniform Resource Identifier.
 /// Has a range of xsd:anyURI.
+#[derive(Debug, Clone, PartialEq)]
 struct URI(String);
 
 ///A boolean indicating true or false.
@@ -1043,8 +2547,198 @@ struct PositiveInteger(u64);
 struct Numeric(f64);
 
 /// A time duration.
-/// Has a range of xsd:duration.
-struct Duration(u64);
+/// Has a range of xsd:duration, so it is parsed from and formatted as
+/// an ISO 8601 duration such as `P1Y2M10DT2H30M`.
+/// Durations are equal if they are equally long, as in xsd:duration:
+/// `PT60M` equals `PT1H` and `P12M` equals `P1Y`. Days are not compared
+/// with hours, as a day is not always 24 hours long.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Duration {
+    years: u64,
+    months: u64,
+    days: u64,
+    hours: u64,
+    minutes: u64,
+    seconds: u64,
+    /// The fraction of a second, which only the seconds of an `xsd:duration` may have.
+    nanoseconds: u32,
+}
+
+impl PartialEq for Duration {
+    fn eq(&self, other: &Duration) -> bool {
+        self.total_months() == other.total_months()
+            && self.days == other.days
+            && self.time_in_nanoseconds() == other.time_in_nanoseconds()
+    }
+}
+
+impl Eq for Duration {}
+
+impl Duration {
+    /// The years and months of this duration in months.
+    fn total_months(&self) -> u128 {
+        self.years as u128 * 12 + self.months as u128
+    }
+
+    /// The time part of this duration in nanoseconds.
+    fn time_in_nanoseconds(&self) -> u128 {
+        ((self.hours as u128 * 60 + self.minutes as u128) * 60 + self.seconds as u128) * 1_000_000_000
+            + self.nanoseconds as u128
+    }
+
+    /// Constructs a duration of the given number of hours.
+    /// Fractions of an hour are carried over to minutes and seconds,
+    /// rounded to the nearest second.
+    pub fn from_hours(hours: f64) -> Duration {
+        let total_seconds = (hours.max(0.0) * 3600.0).round() as u64;
+        Duration {
+            hours: total_seconds / 3600,
+            minutes: total_seconds % 3600 / 60,
+            seconds: total_seconds % 60,
+            ..Duration::default()
+        }
+    }
+
+    /// The time part of this duration in hours.
+    /// Years, months and days are not included, as their length in hours
+    /// depends on the calendar.
+    pub fn time_in_hours(&self) -> f64 {
+        self.hours as f64
+            + self.minutes as f64 / 60.0
+            + (self.seconds as f64 + self.nanoseconds as f64 / 1e9) / 3600.0
+    }
+}
+
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if *self == Duration::default() {
+            return write!(f, "PT0S");
+        }
+        write!(f, "P")?;
+        for (value, designator) in &[(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+            if *value > 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+        if self.hours > 0 || self.minutes > 0 || self.seconds > 0 || self.nanoseconds > 0 {
+            write!(f, "T")?;
+            for (value, designator) in &[(self.hours, 'H'), (self.minutes, 'M')] {
+                if *value > 0 {
+                    write!(f, "{}{}", value, designator)?;
+                }
+            }
+            if self.nanoseconds > 0 {
+                let fraction = format!("{:09}", self.nanoseconds);
+                write!(f, "{}.{}S", self.seconds, fraction.trim_end_matches('0'))?;
+            } else if self.seconds > 0 {
+                write!(f, "{}S", self.seconds)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Duration {
+    type Err = String;
+
+    /// Parses an ISO 8601 duration of the form `PnYnMnDTnHnMnS`, as restricted
+    /// by `xsd:duration`: each component may be given once and in this order,
+    /// and only the seconds may have a fraction, of at most nine digits.
+    /// Weeks are not supported.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || format!("Could not form an ISO 8601 duration from string {}…", value);
+        let body = value.strip_prefix('P').ok_or_else(error)?;
+        if body.is_empty() || body.ends_with('T') {
+            return Err(error());
+        }
+        let mut duration = Duration::default();
+        let mut in_time_part = false;
+        // The components in the order they have to be given in.
+        let order = [(false, 'Y'), (false, 'M'), (false, 'D'), (true, 'H'), (true, 'M'), (true, 'S')];
+        let mut next_component = 0;
+        let mut number = String::new();
+        for c in body.chars() {
+            if c.is_ascii_digit() || c == '.' {
+                number.push(c);
+                continue;
+            }
+            if c == 'T' && !in_time_part && number.is_empty() {
+                in_time_part = true;
+                next_component = 3;
+                continue;
+            }
+            let component = order[next_component..]
+                .iter()
+                .position(|component| *component == (in_time_part, c))
+                .map(|position| next_component + position)
+                .ok_or_else(error)?;
+            next_component = component + 1;
+            let (whole, fraction) = match number.split_once('.') {
+                Some((whole, fraction)) if c == 'S' && !fraction.is_empty() && fraction.len() <= 9 => {
+                    (whole, fraction)
+                }
+                Some(_) => return Err(error()),
+                None => (number.as_str(), ""),
+            };
+            if whole.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(error());
+            }
+            let parsed: u64 = whole.parse().map_err(|_| error())?;
+            match component {
+                0 => duration.years = parsed,
+                1 => duration.months = parsed,
+                2 => duration.days = parsed,
+                3 => duration.hours = parsed,
+                4 => duration.minutes = parsed,
+                _ => {
+                    duration.seconds = parsed;
+                    duration.nanoseconds = format!("{:0<9}", fraction).parse().map_err(|_| error())?;
+                }
+            }
+            number.clear();
+        }
+        if number.is_empty() {
+            Ok(duration)
+        } else {
+            Err(error())
+        }
+    }
+}
+
+/// The rule used to derive the expected workload of learning from
+/// the number of ECTS credits it is worth. The ECTS users' guide allows
+/// 25–30 hours of work per credit, and Finnish universities use 27 hours.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CreditWorkloadRule {
+    /// The number of hours of work a single ECTS credit corresponds to.
+    hours_per_credit: f64,
+}
+
+impl CreditWorkloadRule {
+    /// Constructs a rule where one ECTS credit corresponds
+    /// to the given positive number of hours.
+    pub fn new(hours_per_credit: f64) -> Result<CreditWorkloadRule, String> {
+        if !(hours_per_credit.is_finite() && hours_per_credit > 0.0) {
+            return Err(format!(
+                "Could not form a workload rule from {} hours per credit: the hours have to be a positive number…",
+                hours_per_credit
+            ));
+        }
+        Ok(CreditWorkloadRule { hours_per_credit })
+    }
+
+    /// The expected workload of learning worth the given number of credits.
+    pub fn workload(&self, credits: f64) -> Duration {
+        Duration::from_hours(credits * self.hours_per_credit)
+    }
+}
+
+impl Default for CreditWorkloadRule {
+    /// 1 ECTS credit = 27 hours, as at Finnish universities.
+    fn default() -> Self {
+        CreditWorkloadRule { hours_per_credit: 27.0 }
+    }
+}
 
 // ------- Additional Types Not In Spec -------
 
@@ -1061,3 +2755,320 @@ struct EscoOccupationAssociation;
 
 /// Unknown.
 struct OccupationAssociation;
+
+#[cfg(test)]
+mod tests {
+    use crate::europass_learning_model::{
+        Amount, EuropeanLanguage, LanguageFallback, Measure, Note, Text, ValidityPeriod,
+    };
+    use crate::controlled_vocabularies::{MDRcurrency, MDRunit};
+
+    #[test]
+    fn duration_from_iso_8601 () {
+        let duration: crate::europass_learning_model::Duration = match "P1Y2M10DT2H30M15S".parse() {
+            Ok(duration) => duration,
+            Err(e) => panic!("{}", e)
+        };
+        // Formatting the parsed duration gives back the original string.
+        assert_eq!(duration.to_string(), "P1Y2M10DT2H30M15S");
+        assert_eq!(duration.time_in_hours(), 2.5 + 15.0 / 3600.0);
+    }
+    #[test]
+    fn duration_from_invalid_iso_8601 () {
+        for value in &["", "P", "PT", "1Y", "P1H", "PT1D", "P1DT", "P1.5Y", "P1Y2"] {
+            assert!(value.parse::<crate::europass_learning_model::Duration>().is_err(), "{}", value);
+        }
+        // Components may not be repeated or given out of order.
+        for value in &["P1Y1Y", "P1D1Y", "P1M1Y", "PT1S1M", "PT1H1H", "P1DT1H1DT"] {
+            assert!(value.parse::<crate::europass_learning_model::Duration>().is_err(), "{}", value);
+        }
+        // Only seconds may have a fraction.
+        for value in &["PT1.5M", "PT.5S", "PT1.S", "PT1..5S", "PT1.1234567891S"] {
+            assert!(value.parse::<crate::europass_learning_model::Duration>().is_err(), "{}", value);
+        }
+    }
+    #[test]
+    fn duration_with_fractional_seconds () {
+        let duration: crate::europass_learning_model::Duration = match "PT1M30.25S".parse() {
+            Ok(duration) => duration,
+            Err(e) => panic!("{}", e)
+        };
+        assert_eq!(duration.to_string(), "PT1M30.25S");
+        assert_eq!(duration.time_in_hours(), 90.25 / 3600.0);
+        assert_eq!("PT0.5S".parse::<crate::europass_learning_model::Duration>().map(|d| d.to_string()), Ok(String::from("PT0.5S")));
+    }
+    #[test]
+    fn duration_from_hours () {
+        let duration = crate::europass_learning_model::Duration::from_hours(67.5);
+        assert_eq!(duration.to_string(), "PT67H30M");
+        let zero = crate::europass_learning_model::Duration::from_hours(0.0);
+        assert_eq!(zero.to_string(), "PT0S");
+    }
+    #[test]
+    fn duration_equality () {
+        let duration = |value: &str| match value.parse::<crate::europass_learning_model::Duration>() {
+            Ok(duration) => duration,
+            Err(e) => panic!("{}", e)
+        };
+        // Durations are compared by length, not by how they are written.
+        assert_eq!(duration("PT60M"), duration("PT1H"));
+        assert_eq!(duration("PT1H30M"), duration("PT90M"));
+        assert_eq!(duration("PT0.5S"), duration("PT0.500S"));
+        assert_eq!(duration("P12M"), duration("P1Y"));
+        assert_eq!(duration("PT0S"), duration("P0D"));
+        assert_ne!(duration("P1D"), duration("PT24H"));
+        assert_ne!(duration("PT1H"), duration("PT1H1S"));
+    }
+    #[test]
+    fn workload_from_ects_credits () {
+        // A 5 credit course at a Finnish university is worth 135 hours of work.
+        let rule = crate::europass_learning_model::CreditWorkloadRule::default();
+        assert_eq!(rule.workload(5.0).to_string(), "PT135H");
+        // Other institutions may use a different number of hours per credit.
+        let rule = match crate::europass_learning_model::CreditWorkloadRule::new(25.0) {
+            Ok(rule) => rule,
+            Err(e) => panic!("{}", e)
+        };
+        assert_eq!(rule.workload(2.5).to_string(), "PT62H30M");
+        for hours_per_credit in &[0.0, -27.0, f64::NAN, f64::INFINITY] {
+            assert!(crate::europass_learning_model::CreditWorkloadRule::new(*hours_per_credit).is_err());
+        }
+    }
+    #[test]
+    fn text_primary_language_from_fallback () {
+        let translations = vec![
+            (EuropeanLanguage::Swedish, String::from("Kurs")),
+            (EuropeanLanguage::Finnish, String::from("Kurssi")),
+            (EuropeanLanguage::German, String::from("Kurs")),
+        ];
+        // With the default chain Finnish is preferred over Swedish,
+        // and languages outside the chain come last.
+        let text = Text::from_translations(translations.clone(), &LanguageFallback::default());
+        assert_eq!(text.primary(), Some((EuropeanLanguage::Finnish, "Kurssi")));
+        let languages: Vec<EuropeanLanguage> = text.translations().map(|(language, _)| language).collect();
+        assert_eq!(languages, vec![EuropeanLanguage::Finnish, EuropeanLanguage::Swedish, EuropeanLanguage::German]);
+        // The chain is configurable.
+        let fallback = LanguageFallback::new(vec![EuropeanLanguage::German]);
+        let text = Text::from_translations(translations, &fallback);
+        assert_eq!(text.primary(), Some((EuropeanLanguage::German, "Kurs")));
+        assert_eq!(text.get(EuropeanLanguage::English), None);
+    }
+    #[test]
+    fn text_emits_every_translation () {
+        let text = Text::from_translations(
+            vec![
+                (EuropeanLanguage::English, String::from("Course")),
+                (EuropeanLanguage::Finnish, String::from("Kurssi")),
+            ],
+            &LanguageFallback::default(),
+        );
+        let xml = match quick_xml::se::to_string(&text) {
+            Ok(xml) => xml,
+            Err(e) => panic!("Could not serialize text: {}", e)
+        };
+        assert_eq!(
+            xml,
+            r#"<Text><text lang="en" content-type="text/plain">Course</text><text lang="fi" content-type="text/plain">Kurssi</text></Text>"#
+        );
+        let note = Note::new(Text::new(EuropeanLanguage::Swedish, "Anteckning & mer"));
+        let xml = match quick_xml::se::to_string(&note) {
+            Ok(xml) => xml,
+            Err(e) => panic!("Could not serialize note: {}", e)
+        };
+        assert_eq!(xml, r#"<Note><text lang="sv" content-type="text/plain">Anteckning &amp; mer</text></Note>"#);
+    }
+    #[test]
+    fn european_language_from_str () {
+        for value in &["sv", "SV", "sv-FI", "swe", "urn:code:language:sv",
+                       "http://publications.europa.eu/resource/authority/language/SWE"] {
+            assert_eq!(value.parse(), Ok(EuropeanLanguage::Swedish), "{}", value);
+        }
+        assert_eq!("smn".parse(), Ok(EuropeanLanguage::InariSami));
+        assert_eq!("se".parse(), Ok(EuropeanLanguage::NorthernSami));
+        // In the language authority table, but not a language of teaching.
+        assert!("vi".parse::<EuropeanLanguage>().is_err());
+        assert!("string".parse::<EuropeanLanguage>().is_err());
+    }
+    #[test]
+    fn european_language_codes_and_labels () {
+        assert_eq!(EuropeanLanguage::Slovene.to_string(), "sl");
+        assert_eq!(EuropeanLanguage::SkoltSami.to_string(), "sms");
+        assert_eq!(EuropeanLanguage::Russian.iso_639_1(), Some("ru"));
+        assert_eq!(EuropeanLanguage::Russian.iso_639_3(), "rus");
+        assert_eq!(
+            EuropeanLanguage::Norwegian.nal_uri(),
+            "http://publications.europa.eu/resource/authority/language/NOR"
+        );
+        assert_eq!(EuropeanLanguage::NorthernSami.label(EuropeanLanguage::Finnish), Some("pohjoissaame"));
+        assert_eq!(EuropeanLanguage::Finnish.label(EuropeanLanguage::Swedish), Some("finska"));
+        assert_eq!(EuropeanLanguage::Finnish.label(EuropeanLanguage::German), None);
+        // Every language displays as a tag it can be parsed back from.
+        for (language, _) in crate::europass_learning_model::EUROPEAN_LANGUAGES {
+            assert_eq!(language.to_string().parse(), Ok(*language));
+        }
+    }
+    #[test]
+    fn amount_formatting () {
+        let fee = Amount::new(1500.0, MDRcurrency::EUR);
+        assert_eq!(fee.to_string(), "1500.00 EUR");
+        assert_eq!(fee.format(EuropeanLanguage::English), "1,500.00 EUR");
+        assert_eq!(fee.format(EuropeanLanguage::Finnish), "1\u{a0}500,00 EUR");
+        assert_eq!(fee.format(EuropeanLanguage::German), "1.500,00 EUR");
+        assert_eq!(Amount::new(-1234567.891, MDRcurrency::EUR).format(EuropeanLanguage::English), "-1,234,567.89 EUR");
+        // Currencies without minor units have no decimals.
+        assert_eq!(Amount::new(120000.0, MDRcurrency::JPY).format(EuropeanLanguage::English), "120,000 JPY");
+        assert_eq!(Amount::new(1.5, MDRcurrency::XAU).to_string(), "1.5 XAU");
+    }
+    #[test]
+    fn amount_arithmetic () {
+        let fee = Amount::new(0.1, MDRcurrency::EUR);
+        let total = match fee.checked_add(&Amount::new(0.2, MDRcurrency::EUR)) {
+            Ok(total) => total,
+            Err(e) => panic!("{}", e)
+        };
+        assert_eq!(total.minor_units(), 30);
+        assert_eq!(total.to_string(), "0.30 EUR");
+        assert_eq!(Amount::new(1500.0, MDRcurrency::EUR).multiplied(2.0), Amount::new(3000.0, MDRcurrency::EUR));
+        assert_eq!(Amount::new(10.005, MDRcurrency::KWD).rounded().minor_units(), 10005);
+        assert_eq!(
+            Amount::new(100.0, MDRcurrency::EUR).checked_sub(&Amount::new(0.01, MDRcurrency::EUR)),
+            Ok(Amount::from_minor_units(9999, MDRcurrency::EUR))
+        );
+        // Amounts in different currencies cannot be combined.
+        assert!(fee.checked_add(&Amount::new(1.0, MDRcurrency::SEK)).is_err());
+        let grants = [Amount::new(250.0, MDRcurrency::EUR), Amount::new(250.5, MDRcurrency::EUR)];
+        assert_eq!(Amount::sum(&grants, MDRcurrency::EUR), Ok(Amount::new(500.5, MDRcurrency::EUR)));
+    }
+    #[test]
+    fn measure_conversion_and_arithmetic () {
+        let workload = match Measure::new(90.0, MDRunit::Minute).convert_to(MDRunit::Hour) {
+            Ok(workload) => workload,
+            Err(e) => panic!("{}", e)
+        };
+        assert_eq!(workload, Measure::new(1.5, MDRunit::Hour));
+        assert_eq!(workload.to_string(), "1.5 HUR");
+        let measures = [Measure::new(1.0, MDRunit::Hour), Measure::new(1800.0, MDRunit::Second)];
+        assert_eq!(Measure::sum(&measures, MDRunit::Minute), Ok(Measure::new(90.0, MDRunit::Minute)));
+        assert_eq!(
+            Measure::new(1.0, MDRunit::Manmonth).checked_sub(&Measure::new(40.0, MDRunit::LabourHour)),
+            Ok(Measure::new(0.75, MDRunit::Manmonth))
+        );
+        assert!(workload.checked_add(&Measure::new(1.0, MDRunit::Metre)).is_err());
+        // Time and effort both normalise to durations.
+        assert_eq!(workload.to_duration().map(|d| d.to_string()), Ok(String::from("PT1H30M")));
+        assert_eq!(Measure::new(27.0, MDRunit::LabourHour).to_duration().map(|d| d.to_string()), Ok(String::from("PT27H")));
+        assert!(Measure::new(1.0, MDRunit::Kilogram).to_duration().is_err());
+    }
+    #[test]
+    fn qualification_levels_must_agree () {
+        use crate::europass_learning_model::Qualification;
+        use crate::european_qualifications_framework::EQFLevel;
+        use crate::national_qualification_frameworks::{FinlandNQF, NetherlandsNQF, NQF};
+        let qualification = match Qualification::new(false, EQFLevel::Level6, NQF::Finland(FinlandNQF::Level6)) {
+            Ok(qualification) => qualification,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(qualification.eqf_level(), EQFLevel::Level6);
+        // A level 6 NQF qualification cannot be issued with EQF level 7.
+        assert!(Qualification::new(false, EQFLevel::Level7, NQF::Finland(FinlandNQF::Level6)).is_err());
+        let qualification = match Qualification::from_nqf_level(true, NQF::Finland(FinlandNQF::Level7)) {
+            Ok(qualification) => qualification,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(qualification.eqf_level(), EQFLevel::Level7);
+        // Levels below the first EQF level have no EQF level to take.
+        assert!(Qualification::from_nqf_level(false, NQF::Netherlands(NetherlandsNQF::EntryLevel)).is_err());
+    }
+    #[test]
+    fn validity_periods () {
+        use crate::controlled_vocabularies::VerificationStatus;
+        let date = |y, m, d| chrono::naive::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let time = |y, m, d| date(y, m, d).and_hms_opt(12, 0, 0).unwrap();
+        let validity = match ValidityPeriod::from_dates(Some(date(2024, 5, 31)), Some(date(2026, 5, 31))) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        };
+        assert!(!validity.is_valid_at(time(2024, 5, 30)));
+        assert!(validity.is_valid_at(time(2025, 1, 1)));
+        assert!(!validity.is_valid_at(time(2026, 5, 31)));
+        assert_eq!(
+            validity.status_at(time(2025, 1, 1)),
+            (VerificationStatus::Green, String::from("The credential is valid until 2026-05-31 00:00:00."))
+        );
+        assert_eq!(
+            validity.status_at(time(2024, 1, 1)),
+            (VerificationStatus::Red, String::from("The credential is not valid before 2024-05-31 00:00:00…"))
+        );
+        assert_eq!(validity.status_at(time(2027, 1, 1)).0, VerificationStatus::Red);
+        assert_eq!(ValidityPeriod::default().status_at(time(2027, 1, 1)).0, VerificationStatus::Green);
+        assert!(ValidityPeriod::from_dates(Some(date(2024, 5, 31)), Some(date(2024, 5, 30))).is_err());
+        let last_day = match ValidityPeriod::from_dates(Some(date(2024, 5, 31)), Some(chrono::naive::NaiveDate::MAX)) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        };
+        assert!(last_day.is_valid_at(time(9999, 12, 31)));
+    }
+    #[test]
+    fn verifiable_credential_envelope () {
+        use crate::controlled_vocabularies::{CredentialType, VerificationStatus};
+        use crate::europass_learning_model::{EuropassCredential, Organisation, Person, VerifiableCredential};
+        use crate::owner::{IdentityAssertion, OwnerVerifier};
+        use crate::status_list::{BitstringStatusList, StatusPurpose, MINIMUM_LENGTH};
+        use crate::verification::{ValidityVerifier, VerificationPipeline};
+        use chrono::TimeZone;
+        let date = |y, m, d| chrono::naive::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let mut credential = EuropassCredential::new(
+            CredentialType::LearningActivity,
+            Text::new(EuropeanLanguage::English, "Course & exam"),
+            Organisation::new("https://example.org", Text::new(EuropeanLanguage::English, "Example University")),
+            Person::new(
+                "urn:person:1",
+                Text::new(EuropeanLanguage::Finnish, "Matti"),
+                Text::new(EuropeanLanguage::Finnish, "Meikäläinen"),
+                Some(date(1990, 12, 31)),
+            ),
+        );
+        credential.set_description(Note::new(Text::new(EuropeanLanguage::English, "Completed a course.")));
+        let validity = match ValidityPeriod::from_dates(Some(date(2024, 5, 31)), None) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        };
+        let mut vc = VerifiableCredential::new(credential, "attainment-1", date(2024, 6, 1).and_hms_opt(12, 0, 0).unwrap(), validity);
+        assert!(vc.id().starts_with("urn:uuid:"));
+        assert_eq!(vc.id().len(), "urn:uuid:".len() + 36);
+        assert_eq!(&vc.id()["urn:uuid:".len() + 14..][..1], "8");
+        let mut list = BitstringStatusList::new("https://example.org/status/1", StatusPurpose::Revocation, MINIMUM_LENGTH).unwrap();
+        let index = match vc.allocate_status(&mut list) {
+            Ok(entry) => entry.index(),
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(list.entry(vc.id()).map(|entry| entry.index()), Some(index));
+
+        let xml = match vc.to_xml() {
+            Ok(xml) => xml,
+            Err(e) => panic!("{}", e),
+        };
+        assert!(xml.contains("<title><text lang=\"en\" content-type=\"text/plain\">Course &amp; exam</text></title>"));
+        assert!(xml.contains("<validFrom>2024-05-31T00:00:00Z</validFrom>"));
+        let mut pipeline = VerificationPipeline::new();
+        pipeline.register(ValidityVerifier);
+        pipeline.register(OwnerVerifier::new(IdentityAssertion::new("Matti", "Meikalainen", date(1990, 12, 31))));
+        let checks = pipeline.run(&xml, chrono::Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+        let statuses: Vec<VerificationStatus> = checks.iter().map(|check| check.status()).collect();
+        assert_eq!(statuses[0], VerificationStatus::Green);
+        assert_eq!(statuses[2], VerificationStatus::Green);
+        assert_eq!(statuses[5], VerificationStatus::Green);
+        assert!(checks.iter().all(|check| check.subject() == vc.id()));
+
+        let json = vc.to_vc_json_ld();
+        assert_eq!(json["id"], vc.id());
+        assert_eq!(json["type"][0], "VerifiableCredential");
+        assert_eq!(json["validFrom"], "2024-05-31T00:00:00Z");
+        assert!(json.get("validUntil").is_none());
+        assert!(json.get("issuanceDate").is_none() && json.get("issued").is_none());
+        assert_eq!(json["credentialSubject"]["familyName"]["fi"], "Meikäläinen");
+        assert_eq!(json["credentialSubject"]["dateOfBirth"], "1990-12-31");
+        assert_eq!(json["credentialStatus"]["statusListIndex"], index.to_string());
+        assert_eq!(json["title"]["en"], "Course & exam");
+    }
+}
@@ -4,10 +4,24 @@ This submodule provides a Rust implementation of the European Qualifications Fra
 The implementation is an enumeration of the 8 different levels of international qualification,
 as recognized by the European Union and its partners.
 
+The descriptors of the levels are also available as data, in the English
+of the Council Recommendation of 22 May 2017 on the European Qualifications
+Framework and in Finnish and Swedish translations, so that they can be
+printed in diploma supplements.
+
+The official translations of the descriptors into the other EU languages,
+published with the Recommendation in the Official Journal (OJ C 189, 15.6.2017),
+are not included. The returned texts have no translation in those languages,
+only the English primary translation, so a diploma supplement in another
+language must bring its own translation.
+
 See https://europa.eu/europass/en/european-qualifications-framework-eqf
 for relevant information and links.
 */
 
+use crate::europass_learning_model::{EuropeanLanguage, LanguageFallback, Text};
+use std::convert::TryFrom;
+
 /// An enumeration of the standard Europass EQF qualification levels.
 /// Each of the 8 levels is defined by a set of descriptors indicating
 /// the learning outcomes relevant to qualifications at that level
@@ -32,6 +46,7 @@ for relevant information and links.
 ///
 /// See https://europa.eu/europass/en/description-eight-eqf-levels
 /// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum EQFLevel {
     /// Knowledge
     ///     Basic general knowledge
@@ -112,8 +127,9 @@ pub enum EQFLevel {
     ///
     /// # Skills
     ///
-    /// A comprehensive range of cognitive and practical skills required to
-    /// develop creative solutions to abstract problems
+    /// Advanced skills, demonstrating mastery and innovation, required to
+    /// solve complex and unpredictable problems in a specialised field of
+    /// work or study
     ///
     /// # Responsibility and autonomy
     ///
@@ -159,3 +175,323 @@ pub enum EQFLevel {
     /// including research
     Level8,
 }
+
+/// The URI of the EQF levels concept scheme. The levels are identified
+/// by their number within it, such as `http://data.europa.eu/snb/eqf/6`.
+pub const EQF_SCHEME_URI: &str = "http://data.europa.eu/snb/eqf";
+
+/// The descriptors of an EQF level in a single language.
+struct Descriptors {
+    level: EQFLevel,
+    language: EuropeanLanguage,
+    knowledge: &'static str,
+    skills: &'static str,
+    responsibility_and_autonomy: &'static str,
+}
+
+/// The descriptors of each level in English, Finnish and Swedish.
+const DESCRIPTORS: &[Descriptors] = &[
+    Descriptors {
+        level: EQFLevel::Level1,
+        language: EuropeanLanguage::English,
+        knowledge: "Basic general knowledge",
+        skills: "Basic skills required to carry out simple tasks",
+        responsibility_and_autonomy: "Work or study under direct supervision in a structured context",
+    },
+    Descriptors {
+        level: EQFLevel::Level2,
+        language: EuropeanLanguage::English,
+        knowledge: "Basic factual knowledge of a field of work or study",
+        skills: "Basic cognitive and practical skills required to use relevant information in order to carry out tasks and to solve routine problems using simple rules and tools",
+        responsibility_and_autonomy: "Work or study under supervision with some autonomy",
+    },
+    Descriptors {
+        level: EQFLevel::Level3,
+        language: EuropeanLanguage::English,
+        knowledge: "Knowledge of facts, principles, processes and general concepts, in a field of work or study",
+        skills: "A range of cognitive and practical skills required to accomplish tasks and solve problems by selecting and applying basic methods, tools, materials and information",
+        responsibility_and_autonomy: "Take responsibility for completion of tasks in work or study; adapt own behaviour to circumstances in solving problems",
+    },
+    Descriptors {
+        level: EQFLevel::Level4,
+        language: EuropeanLanguage::English,
+        knowledge: "Factual and theoretical knowledge in broad contexts within a field of work or study",
+        skills: "A range of cognitive and practical skills required to generate solutions to specific problems in a field of work or study",
+        responsibility_and_autonomy: "Exercise self-management within the guidelines of work or study contexts that are usually predictable, but are subject to change; supervise the routine work of others, taking some responsibility for the evaluation and improvement of work or study activities",
+    },
+    Descriptors {
+        level: EQFLevel::Level5,
+        language: EuropeanLanguage::English,
+        knowledge: "Comprehensive, specialised, factual and theoretical knowledge within a field of work or study and an awareness of the boundaries of that knowledge",
+        skills: "A comprehensive range of cognitive and practical skills required to develop creative solutions to abstract problems",
+        responsibility_and_autonomy: "Exercise management and supervision in contexts of work or study activities where there is unpredictable change; review and develop performance of self and others",
+    },
+    Descriptors {
+        level: EQFLevel::Level6,
+        language: EuropeanLanguage::English,
+        knowledge: "Advanced knowledge of a field of work or study, involving a critical understanding of theories and principles",
+        skills: "Advanced skills, demonstrating mastery and innovation, required to solve complex and unpredictable problems in a specialised field of work or study",
+        responsibility_and_autonomy: "Manage complex technical or professional activities or projects, taking responsibility for decision-making in unpredictable work or study contexts; take responsibility for managing professional development of individuals and groups",
+    },
+    Descriptors {
+        level: EQFLevel::Level7,
+        language: EuropeanLanguage::English,
+        knowledge: "Highly specialised knowledge, some of which is at the forefront of knowledge in a field of work or study, as the basis for original thinking and/or research; critical awareness of knowledge issues in a field and at the interface between different fields",
+        skills: "Specialised problem-solving skills required in research and/or innovation in order to develop new knowledge and procedures and to integrate knowledge from different fields",
+        responsibility_and_autonomy: "Manage and transform work or study contexts that are complex, unpredictable and require new strategic approaches; take responsibility for contributing to professional knowledge and practice and/or for reviewing the strategic performance of teams",
+    },
+    Descriptors {
+        level: EQFLevel::Level8,
+        language: EuropeanLanguage::English,
+        knowledge: "Knowledge at the most advanced frontier of a field of work or study and at the interface between fields",
+        skills: "The most advanced and specialised skills and techniques, including synthesis and evaluation, required to solve critical problems in research and/or innovation and to extend and redefine existing knowledge or professional practice",
+        responsibility_and_autonomy: "Demonstrate substantial authority, innovation, autonomy, scholarly and professional integrity and sustained commitment to the development of new ideas or processes at the forefront of work or study contexts including research",
+    },
+    Descriptors {
+        level: EQFLevel::Level1,
+        language: EuropeanLanguage::Finnish,
+        knowledge: "Perustason yleistiedot",
+        skills: "Perustaidot, joita tarvitaan yksinkertaisten tehtävien suorittamiseen",
+        responsibility_and_autonomy: "Työskentely tai opiskelu suorassa ohjauksessa jäsennellyssä ympäristössä",
+    },
+    Descriptors {
+        level: EQFLevel::Level2,
+        language: EuropeanLanguage::Finnish,
+        knowledge: "Työ- tai opiskelualan perustason tosiasiatiedot",
+        skills: "Kognitiiviset ja käytännön perustaidot, joita tarvitaan olennaisten tietojen käyttämiseen tehtävien suorittamisessa ja rutiiniongelmien ratkaisemisessa yksinkertaisia sääntöjä ja välineitä käyttäen",
+        responsibility_and_autonomy: "Työskentely tai opiskelu ohjauksessa ja jossakin määrin itsenäisesti",
+    },
+    Descriptors {
+        level: EQFLevel::Level3,
+        language: EuropeanLanguage::Finnish,
+        knowledge: "Työ- tai opiskelualan tosiasioiden, periaatteiden, prosessien ja yleisten käsitteiden tuntemus",
+        skills: "Monenlaiset kognitiiviset ja käytännön taidot, joita tarvitaan tehtävien suorittamisessa ja ongelmien ratkaisemisessa valitsemalla ja soveltamalla perusmenetelmiä, -välineitä, -materiaaleja ja -tietoja",
+        responsibility_and_autonomy: "Vastuun ottaminen tehtävien suorittamisesta työssä tai opiskelussa; oman toiminnan mukauttaminen olosuhteisiin ongelmia ratkaistaessa",
+    },
+    Descriptors {
+        level: EQFLevel::Level4,
+        language: EuropeanLanguage::Finnish,
+        knowledge: "Laaja-alaiset tosiasia- ja teoriatiedot työ- tai opiskelualalla",
+        skills: "Monenlaiset kognitiiviset ja käytännön taidot, joita tarvitaan työ- tai opiskelualan erityisongelmien ratkaisujen keksimiseen",
+        responsibility_and_autonomy: "Itsenäinen toiminta sellaisten työ- tai opiskeluympäristöjen ohjeiden puitteissa, jotka ovat yleensä ennakoitavissa mutta voivat muuttua; muiden rutiininomaisen työn valvominen ja jonkinasteinen vastuu työ- tai opiskelutoiminnan arvioinnista ja kehittämisestä",
+    },
+    Descriptors {
+        level: EQFLevel::Level5,
+        language: EuropeanLanguage::Finnish,
+        knowledge: "Kattavat, erikoistuneet tosiasia- ja teoriatiedot työ- tai opiskelualalla sekä tietoisuus näiden tietojen rajoista",
+        skills: "Kattavat kognitiiviset ja käytännön taidot, joita tarvitaan luovien ratkaisujen kehittämiseen abstrakteihin ongelmiin",
+        responsibility_and_autonomy: "Johtaminen ja valvonta työ- tai opiskelutoiminnassa, jossa tapahtuu ennakoimattomia muutoksia; oman ja muiden suoritusten arvioiminen ja kehittäminen",
+    },
+    Descriptors {
+        level: EQFLevel::Level6,
+        language: EuropeanLanguage::Finnish,
+        knowledge: "Työ- tai opiskelualan syvälliset tiedot, joihin liittyy teorioiden ja periaatteiden kriittinen ymmärtäminen",
+        skills: "Edistyneet taidot, jotka osoittavat asioiden hallintaa ja innovatiivisuutta ja joita tarvitaan erikoistuneen työ- tai opiskelualan monimutkaisten ja ennakoimattomien ongelmien ratkaisemiseen",
+        responsibility_and_autonomy: "Monimutkaisten teknisten tai ammatillisten toimintojen tai hankkeiden johtaminen ja päätöksentekovastuun ottaminen ennakoimattomissa työ- tai opiskeluympäristöissä; vastuun ottaminen yksilöiden ja ryhmien ammatillisen kehityksen johtamisesta",
+    },
+    Descriptors {
+        level: EQFLevel::Level7,
+        language: EuropeanLanguage::Finnish,
+        knowledge: "Pitkälle erikoistuneet tiedot, joista osa on työ- tai opiskelualan huippuosaamista ja jotka ovat perustana omaperäiselle ajattelulle ja/tai tutkimukselle; alan ja eri alojen rajapintojen tietoon liittyvien kysymysten kriittinen tiedostaminen",
+        skills: "Erikoistuneet ongelmanratkaisutaidot, joita tarvitaan tutkimus- ja/tai innovaatiotoiminnassa uuden tiedon ja uusien menettelyjen kehittämiseen sekä eri alojen tietojen yhdistämiseen",
+        responsibility_and_autonomy: "Monimutkaisten, ennakoimattomien ja uusia strategisia lähestymistapoja vaativien työ- tai opiskeluympäristöjen johtaminen ja muuttaminen; vastuun ottaminen ammatillisen tiedon ja käytäntöjen kehittämisestä ja/tai ryhmien strategisen toiminnan arvioinnista",
+    },
+    Descriptors {
+        level: EQFLevel::Level8,
+        language: EuropeanLanguage::Finnish,
+        knowledge: "Työ- tai opiskelualan sekä eri alojen rajapintojen kaikkein edistyneimmät tiedot",
+        skills: "Kaikkein edistyneimmät ja erikoistuneimmat taidot ja tekniikat, synteesi ja arviointi mukaan lukien, joita tarvitaan tutkimus- ja/tai innovaatiotoiminnan kriittisten ongelmien ratkaisemiseen sekä olemassa olevan tiedon tai ammattikäytännön laajentamiseen ja uudelleen määrittelyyn",
+        responsibility_and_autonomy: "Huomattavan arvovallan, innovatiivisuuden, itsenäisyyden, tieteellisen ja ammatillisen integriteetin sekä jatkuvan sitoutumisen osoittaminen uusien ajatusten tai prosessien kehittämiseen työ- tai opiskeluympäristöjen kärjessä, tutkimus mukaan lukien",
+    },
+    Descriptors {
+        level: EQFLevel::Level1,
+        language: EuropeanLanguage::Swedish,
+        knowledge: "Grundläggande allmänna kunskaper",
+        skills: "Grundläggande färdigheter som krävs för att utföra enkla uppgifter",
+        responsibility_and_autonomy: "Arbeta eller studera under direkt handledning i ett strukturerat sammanhang",
+    },
+    Descriptors {
+        level: EQFLevel::Level2,
+        language: EuropeanLanguage::Swedish,
+        knowledge: "Grundläggande faktakunskaper inom ett arbets- eller studieområde",
+        skills: "Grundläggande kognitiva och praktiska färdigheter som krävs för att använda relevant information för att utföra uppgifter och lösa rutinproblem med hjälp av enkla regler och verktyg",
+        responsibility_and_autonomy: "Arbeta eller studera under handledning med viss självständighet",
+    },
+    Descriptors {
+        level: EQFLevel::Level3,
+        language: EuropeanLanguage::Swedish,
+        knowledge: "Kunskaper om fakta, principer, processer och allmänna begrepp inom ett arbets- eller studieområde",
+        skills: "En rad kognitiva och praktiska färdigheter som krävs för att utföra uppgifter och lösa problem genom att välja och tillämpa grundläggande metoder, verktyg, material och information",
+        responsibility_and_autonomy: "Ta ansvar för att utföra uppgifter i arbete eller studier; anpassa det egna beteendet efter omständigheterna vid problemlösning",
+    },
+    Descriptors {
+        level: EQFLevel::Level4,
+        language: EuropeanLanguage::Swedish,
+        knowledge: "Fakta- och teorikunskaper i breda sammanhang inom ett arbets- eller studieområde",
+        skills: "En rad kognitiva och praktiska färdigheter som krävs för att finna lösningar på specifika problem inom ett arbets- eller studieområde",
+        responsibility_and_autonomy: "Arbeta självständigt inom ramen för riktlinjer i arbets- eller studiesammanhang som vanligtvis är förutsägbara men kan förändras; övervaka andras rutinarbete och ta ett visst ansvar för utvärdering och förbättring av arbets- eller studieaktiviteter",
+    },
+    Descriptors {
+        level: EQFLevel::Level5,
+        language: EuropeanLanguage::Swedish,
+        knowledge: "Omfattande, specialiserade fakta- och teorikunskaper inom ett arbets- eller studieområde samt medvetenhet om gränserna för dessa kunskaper",
+        skills: "Ett omfattande urval av kognitiva och praktiska färdigheter som krävs för att utveckla kreativa lösningar på abstrakta problem",
+        responsibility_and_autonomy: "Leda och övervaka i arbets- eller studiesammanhang där oförutsägbara förändringar sker; granska och utveckla egna och andras prestationer",
+    },
+    Descriptors {
+        level: EQFLevel::Level6,
+        language: EuropeanLanguage::Swedish,
+        knowledge: "Avancerade kunskaper inom ett arbets- eller studieområde, vilket inbegriper en kritisk förståelse av teorier och principer",
+        skills: "Avancerade färdigheter som visar på behärskning och innovation och som krävs för att lösa komplexa och oförutsägbara problem inom ett specialiserat arbets- eller studieområde",
+        responsibility_and_autonomy: "Leda komplexa tekniska eller yrkesmässiga aktiviteter eller projekt och ta ansvar för beslutsfattande i oförutsägbara arbets- eller studiesammanhang; ta ansvar för att leda den yrkesmässiga utvecklingen för individer och grupper",
+    },
+    Descriptors {
+        level: EQFLevel::Level7,
+        language: EuropeanLanguage::Swedish,
+        knowledge: "Högt specialiserade kunskaper, varav vissa ligger i framkant inom ett arbets- eller studieområde, som grund för originellt tänkande och/eller forskning; kritisk medvetenhet om kunskapsfrågor inom ett område och i gränssnittet mellan olika områden",
+        skills: "Specialiserade problemlösningsfärdigheter som krävs inom forskning och/eller innovation för att utveckla nya kunskaper och förfaranden och integrera kunskaper från olika områden",
+        responsibility_and_autonomy: "Leda och förändra arbets- eller studiesammanhang som är komplexa, oförutsägbara och kräver nya strategiska angreppssätt; ta ansvar för att bidra till yrkesmässig kunskap och praxis och/eller för att granska gruppers strategiska prestationer",
+    },
+    Descriptors {
+        level: EQFLevel::Level8,
+        language: EuropeanLanguage::Swedish,
+        knowledge: "Kunskaper i den yttersta framkanten inom ett arbets- eller studieområde och i gränssnittet mellan områden",
+        skills: "De mest avancerade och specialiserade färdigheterna och teknikerna, inklusive syntes och utvärdering, som krävs för att lösa kritiska problem inom forskning och/eller innovation och för att utvidga och omdefiniera befintliga kunskaper eller yrkesmässig praxis",
+        responsibility_and_autonomy: "Visa betydande auktoritet, innovationsförmåga, självständighet, vetenskaplig och yrkesmässig integritet och varaktigt engagemang för utveckling av nya idéer eller processer i framkant av arbets- eller studiesammanhang, inklusive forskning",
+    },
+];
+
+/// The label of an EQF level in each language, followed by its number.
+const LABELS: &[(EuropeanLanguage, &str)] = &[
+    (EuropeanLanguage::English, "EQF level"),
+    (EuropeanLanguage::Finnish, "EQF-taso"),
+    (EuropeanLanguage::Swedish, "EQF-nivå"),
+];
+
+impl EQFLevel {
+    /// The levels in ascending order.
+    pub const LEVELS: [EQFLevel; 8] = [
+        EQFLevel::Level1,
+        EQFLevel::Level2,
+        EQFLevel::Level3,
+        EQFLevel::Level4,
+        EQFLevel::Level5,
+        EQFLevel::Level6,
+        EQFLevel::Level7,
+        EQFLevel::Level8,
+    ];
+
+    /// The number of this level, from 1 to 8.
+    pub fn number(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    /// The URI of this level in the EQF levels concept scheme.
+    pub fn uri(&self) -> String {
+        format!("{}/{}", EQF_SCHEME_URI, self.number())
+    }
+
+    /// The level with the given URI.
+    pub fn from_uri(uri: &str) -> Option<EQFLevel> {
+        uri.strip_prefix(EQF_SCHEME_URI)
+            .and_then(|number| number.strip_prefix('/'))
+            .and_then(|number| number.parse::<u8>().ok())
+            .and_then(|number| EQFLevel::try_from(number).ok())
+    }
+
+    /// The label of this level, such as “EQF level 6”, in every available language.
+    pub fn label(&self) -> Text {
+        Text::from_translations(
+            LABELS
+                .iter()
+                .map(|(language, label)| (*language, format!("{} {}", label, self.number()))),
+            &LanguageFallback::default(),
+        )
+    }
+
+    fn descriptors<F>(&self, descriptor: F) -> Text
+    where
+        F: Fn(&Descriptors) -> &'static str,
+    {
+        Text::from_translations(
+            DESCRIPTORS
+                .iter()
+                .filter(|descriptors| descriptors.level == *self)
+                .map(|descriptors| (descriptors.language, descriptor(descriptors).to_string())),
+            &LanguageFallback::default(),
+        )
+    }
+
+    /// The knowledge descriptor of this level in every available language.
+    pub fn knowledge(&self) -> Text {
+        self.descriptors(|descriptors| descriptors.knowledge)
+    }
+
+    /// The skills descriptor of this level in every available language.
+    pub fn skills(&self) -> Text {
+        self.descriptors(|descriptors| descriptors.skills)
+    }
+
+    /// The responsibility and autonomy descriptor of this level
+    /// in every available language.
+    pub fn responsibility_and_autonomy(&self) -> Text {
+        self.descriptors(|descriptors| descriptors.responsibility_and_autonomy)
+    }
+}
+
+impl TryFrom<u8> for EQFLevel {
+    type Error = String;
+
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        match number {
+            1..=8 => Ok(EQFLevel::LEVELS[number as usize - 1]),
+            _ => Err(format!("Could not form an EQF level from number {}…", number)),
+        }
+    }
+}
+
+impl std::fmt::Display for EQFLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "EQF level {}", self.number())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use crate::european_qualifications_framework::EQFLevel;
+    use crate::europass_learning_model::EuropeanLanguage;
+
+    #[test]
+    fn eqf_level_numbers_and_uris () {
+        assert_eq!(EQFLevel::try_from(6), Ok(EQFLevel::Level6));
+        assert!(EQFLevel::try_from(0).is_err());
+        assert!(EQFLevel::try_from(9).is_err());
+        assert_eq!(EQFLevel::Level6.uri(), "http://data.europa.eu/snb/eqf/6");
+        assert_eq!(EQFLevel::from_uri("http://data.europa.eu/snb/eqf/8"), Some(EQFLevel::Level8));
+        assert_eq!(EQFLevel::from_uri("http://data.europa.eu/snb/eqf/9"), None);
+        assert!(EQFLevel::Level6 < EQFLevel::Level7);
+        assert_eq!(EQFLevel::LEVELS.iter().max(), Some(&EQFLevel::Level8));
+        for level in &EQFLevel::LEVELS {
+            assert_eq!(EQFLevel::try_from(level.number()), Ok(*level));
+        }
+    }
+    #[test]
+    fn eqf_level_descriptors () {
+        let level = EQFLevel::Level6;
+        assert_eq!(
+            level.knowledge().primary(),
+            Some((EuropeanLanguage::English, "Advanced knowledge of a field of work or study, involving a critical understanding of theories and principles"))
+        );
+        assert_eq!(level.label().get(EuropeanLanguage::Finnish), Some("EQF-taso 6"));
+        assert_eq!(level.to_string(), "EQF level 6");
+        // Every level has every descriptor in every language.
+        for level in &EQFLevel::LEVELS {
+            for language in &[EuropeanLanguage::English, EuropeanLanguage::Finnish, EuropeanLanguage::Swedish] {
+                assert!(level.knowledge().get(*language).is_some());
+                assert!(level.skills().get(*language).is_some());
+                assert!(level.responsibility_and_autonomy().get(*language).is_some());
+            }
+        }
+    }
+}
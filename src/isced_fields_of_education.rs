@@ -0,0 +1,751 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+/*!
+This submodule provides a Rust implementation of the broad, narrow and detailed
+fields of the International Standard Classification of Education:
+Fields of Education and Training 2013 (ISCED-F 2013), as published in the
+ISCED-F Named Authority List of Europass. It also maps the study fields used
+in Finnish higher education onto ISCED-F fields.
+
+The education classification of Statistics Finland, whose codes SISU gives
+as `urn:code:education-classification:*` URNs, is not mapped: its six digit
+codes do not encode a field of education, and its correspondence to ISCED-F
+is a table of its codes that is not bundled with the crate.
+Only the twelve `urn:code:study-field:*` URNs are mapped.
+The broad and narrow fields are named in English, Finnish and Swedish,
+the detailed fields in English only.
+
+See http://uis.unesco.org/sites/default/files/documents/international-standard-classification-of-education-fields-of-education-and-training-2013-detailed-field-descriptions-2015-en.pdf
+and https://op.europa.eu/en/web/eu-vocabularies/concept-scheme/-/resource?uri=http://data.europa.eu/snb/isced-f/25831c2
+for details.
+*/
+
+use crate::europass_learning_model::Code;
+
+/// The URI of the ISCED-F concept scheme in the Europass Named Authority Lists.
+pub const ISCED_F_SCHEME_URI: &str = "http://data.europa.eu/snb/isced-f/25831c2";
+
+/// The prefix of the URIs of the individual ISCED-F concepts.
+const ISCED_F_CONCEPT_URI_PREFIX: &str = "http://data.europa.eu/snb/isced-f/";
+
+/// A field of education and training in ISCED-F 2013.
+/// Broad fields have a two digit notation, narrow fields a three digit one
+/// and detailed fields a four digit one.
+pub struct ISCEDFField {
+    /// The notation of the field, e.g. `061`.
+    notation: &'static str,
+    /// The name of the field, keyed by ISO 639-1 language code.
+    labels: &'static [(&'static str, &'static str)],
+}
+
+impl ISCEDFField {
+    /// Looks up a broad, narrow or detailed field by its notation.
+    pub fn from_notation(notation: &str) -> Option<&'static ISCEDFField> {
+        ISCED_F_FIELDS.iter().find(|field| field.notation == notation)
+    }
+
+    /// The field this field belongs to: the narrow field of a detailed field
+    /// and the broad field of a narrow field. Broad fields belong to none.
+    pub fn broader(&self) -> Option<&'static ISCEDFField> {
+        ISCEDFField::from_notation(self.notation.get(..self.notation.len().checked_sub(1)?)?)
+    }
+
+    /// Looks up the field a Finnish study field URN, as used in SISU,
+    /// corresponds to.
+    pub fn from_study_field_urn(urn: &str) -> Option<&'static ISCEDFField> {
+        STUDY_FIELD_URN_TO_ISCED_F
+            .iter()
+            .find(|(study_field_urn, _)| *study_field_urn == urn)
+            .and_then(|(_, notation)| ISCEDFField::from_notation(notation))
+    }
+
+    /// The notation of this field.
+    pub fn notation(&self) -> &'static str {
+        self.notation
+    }
+
+    /// The URI of this field in the ISCED-F Named Authority List.
+    pub fn uri(&self) -> String {
+        format!("{}{}", ISCED_F_CONCEPT_URI_PREFIX, self.notation)
+    }
+
+    /// The name of this field in the language with the given ISO 639-1 code,
+    /// if a translation exists.
+    pub fn label(&self, language: &str) -> Option<&'static str> {
+        self.labels
+            .iter()
+            .find(|(code, _)| *code == language)
+            .map(|(_, label)| *label)
+    }
+
+    /// All available translations of the name of this field,
+    /// keyed by ISO 639-1 language code.
+    pub fn labels(&self) -> &'static [(&'static str, &'static str)] {
+        self.labels
+    }
+
+    /// Converts this field into a Europass `Code`, named in English.
+    pub fn to_code(&self) -> Code {
+        Code::new(
+            self.notation,
+            ISCED_F_SCHEME_URI,
+            "ISCED-F 2013",
+            self.label("en").unwrap_or(self.notation),
+            "",
+            &self.uri(),
+        )
+    }
+}
+
+/// Maps the Finnish Ministry of Education and Culture fields of education
+/// (ohjauksen alat, 2016), used as study fields in SISU, to ISCED-F fields.
+/// Where a Finnish field spans several narrow fields, it is mapped to
+/// the common broad field.
+const STUDY_FIELD_URN_TO_ISCED_F: &[(&str, &str)] = &[
+    // Kasvatusalat
+    ("urn:code:study-field:1", "011"),
+    // Taiteet ja kulttuurialat
+    ("urn:code:study-field:2", "021"),
+    // Humanistiset alat, which include languages
+    ("urn:code:study-field:3", "02"),
+    // Yhteiskunnalliset alat
+    ("urn:code:study-field:4", "03"),
+    // Kauppa, hallinto ja oikeustieteet
+    ("urn:code:study-field:5", "04"),
+    // Luonnontieteet
+    ("urn:code:study-field:6", "05"),
+    // Tietojenkäsittely ja tietoliikenne (ICT)
+    ("urn:code:study-field:7", "061"),
+    // Tekniikan alat
+    ("urn:code:study-field:8", "07"),
+    // Maa- ja metsätalousalat
+    ("urn:code:study-field:9", "08"),
+    // Lääketieteet
+    ("urn:code:study-field:10", "091"),
+    // Terveys- ja hyvinvointialat
+    ("urn:code:study-field:11", "09"),
+    // Palvelualat
+    ("urn:code:study-field:12", "10"),
+];
+
+/// The broad and narrow fields of ISCED-F 2013.
+const ISCED_F_FIELDS: &[ISCEDFField] = &[
+    ISCEDFField { notation: "00", labels: &[
+        ("en", "Generic programmes and qualifications"),
+        ("fi", "Yleissivistävä koulutus"),
+        ("sv", "Allmänbildande utbildning"),
+    ]},
+    ISCEDFField { notation: "001", labels: &[
+        ("en", "Basic programmes and qualifications"),
+        ("fi", "Perusohjelmat ja -tutkinnot"),
+        ("sv", "Grundläggande program och examina"),
+    ]},
+    ISCEDFField { notation: "0011", labels: &[
+        ("en", "Basic programmes and qualifications"),
+    ]},
+    ISCEDFField { notation: "002", labels: &[
+        ("en", "Literacy and numeracy"),
+        ("fi", "Luku- ja laskutaito"),
+        ("sv", "Läs- och skrivkunnighet samt räknefärdighet"),
+    ]},
+    ISCEDFField { notation: "0021", labels: &[
+        ("en", "Literacy and numeracy"),
+    ]},
+    ISCEDFField { notation: "003", labels: &[
+        ("en", "Personal skills and development"),
+        ("fi", "Henkilökohtaiset taidot ja kehittyminen"),
+        ("sv", "Personliga färdigheter och personlig utveckling"),
+    ]},
+    ISCEDFField { notation: "0031", labels: &[
+        ("en", "Personal skills and development"),
+    ]},
+    ISCEDFField { notation: "01", labels: &[
+        ("en", "Education"),
+        ("fi", "Kasvatusalat"),
+        ("sv", "Pedagogik"),
+    ]},
+    ISCEDFField { notation: "011", labels: &[
+        ("en", "Education"),
+        ("fi", "Kasvatusalat"),
+        ("sv", "Pedagogik"),
+    ]},
+    ISCEDFField { notation: "0111", labels: &[
+        ("en", "Education science"),
+    ]},
+    ISCEDFField { notation: "0112", labels: &[
+        ("en", "Training for pre-school teachers"),
+    ]},
+    ISCEDFField { notation: "0113", labels: &[
+        ("en", "Teacher training without subject specialisation"),
+    ]},
+    ISCEDFField { notation: "0114", labels: &[
+        ("en", "Teacher training with subject specialisation"),
+    ]},
+    ISCEDFField { notation: "0119", labels: &[
+        ("en", "Education not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "018", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving education"),
+    ]},
+    ISCEDFField { notation: "0188", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving education"),
+    ]},
+    ISCEDFField { notation: "02", labels: &[
+        ("en", "Arts and humanities"),
+        ("fi", "Humanistiset ja taidealat"),
+        ("sv", "Humaniora och konst"),
+    ]},
+    ISCEDFField { notation: "021", labels: &[
+        ("en", "Arts"),
+        ("fi", "Taiteet"),
+        ("sv", "Konst"),
+    ]},
+    ISCEDFField { notation: "0211", labels: &[
+        ("en", "Audio-visual techniques and media production"),
+    ]},
+    ISCEDFField { notation: "0212", labels: &[
+        ("en", "Fashion, interior and industrial design"),
+    ]},
+    ISCEDFField { notation: "0213", labels: &[
+        ("en", "Fine arts"),
+    ]},
+    ISCEDFField { notation: "0214", labels: &[
+        ("en", "Handicrafts"),
+    ]},
+    ISCEDFField { notation: "0215", labels: &[
+        ("en", "Music and performing arts"),
+    ]},
+    ISCEDFField { notation: "0219", labels: &[
+        ("en", "Arts not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "022", labels: &[
+        ("en", "Humanities (except languages)"),
+        ("fi", "Humanistiset alat (pl. kielet)"),
+        ("sv", "Humaniora (utom språk)"),
+    ]},
+    ISCEDFField { notation: "0221", labels: &[
+        ("en", "Religion and theology"),
+    ]},
+    ISCEDFField { notation: "0222", labels: &[
+        ("en", "History and archaeology"),
+    ]},
+    ISCEDFField { notation: "0223", labels: &[
+        ("en", "Philosophy and ethics"),
+    ]},
+    ISCEDFField { notation: "0229", labels: &[
+        ("en", "Humanities (except languages) not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "023", labels: &[
+        ("en", "Languages"),
+        ("fi", "Kielet"),
+        ("sv", "Språk"),
+    ]},
+    ISCEDFField { notation: "0231", labels: &[
+        ("en", "Language acquisition"),
+    ]},
+    ISCEDFField { notation: "0232", labels: &[
+        ("en", "Literature and linguistics"),
+    ]},
+    ISCEDFField { notation: "0239", labels: &[
+        ("en", "Languages not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "028", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving arts and humanities"),
+    ]},
+    ISCEDFField { notation: "0288", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving arts and humanities"),
+    ]},
+    ISCEDFField { notation: "03", labels: &[
+        ("en", "Social sciences, journalism and information"),
+        ("fi", "Yhteiskunnalliset alat"),
+        ("sv", "Samhällsvetenskap, journalistik och information"),
+    ]},
+    ISCEDFField { notation: "031", labels: &[
+        ("en", "Social and behavioural sciences"),
+        ("fi", "Yhteiskunta- ja käyttäytymistieteet"),
+        ("sv", "Samhälls- och beteendevetenskap"),
+    ]},
+    ISCEDFField { notation: "0311", labels: &[
+        ("en", "Economics"),
+    ]},
+    ISCEDFField { notation: "0312", labels: &[
+        ("en", "Political sciences and civics"),
+    ]},
+    ISCEDFField { notation: "0313", labels: &[
+        ("en", "Psychology"),
+    ]},
+    ISCEDFField { notation: "0314", labels: &[
+        ("en", "Sociology and cultural studies"),
+    ]},
+    ISCEDFField { notation: "0319", labels: &[
+        ("en", "Social and behavioural sciences not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "032", labels: &[
+        ("en", "Journalism and information"),
+        ("fi", "Journalistiikka ja informaatio"),
+        ("sv", "Journalistik och information"),
+    ]},
+    ISCEDFField { notation: "0321", labels: &[
+        ("en", "Journalism and reporting"),
+    ]},
+    ISCEDFField { notation: "0322", labels: &[
+        ("en", "Library, information and archival studies"),
+    ]},
+    ISCEDFField { notation: "0329", labels: &[
+        ("en", "Journalism and information not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "038", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving social sciences, journalism and information"),
+    ]},
+    ISCEDFField { notation: "0388", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving social sciences, journalism and information"),
+    ]},
+    ISCEDFField { notation: "04", labels: &[
+        ("en", "Business, administration and law"),
+        ("fi", "Kauppa, hallinto ja oikeustieteet"),
+        ("sv", "Handel, administration och juridik"),
+    ]},
+    ISCEDFField { notation: "041", labels: &[
+        ("en", "Business and administration"),
+        ("fi", "Liiketalous ja hallinto"),
+        ("sv", "Handel och administration"),
+    ]},
+    ISCEDFField { notation: "0411", labels: &[
+        ("en", "Accounting and taxation"),
+    ]},
+    ISCEDFField { notation: "0412", labels: &[
+        ("en", "Finance, banking and insurance"),
+    ]},
+    ISCEDFField { notation: "0413", labels: &[
+        ("en", "Management and administration"),
+    ]},
+    ISCEDFField { notation: "0414", labels: &[
+        ("en", "Marketing and advertising"),
+    ]},
+    ISCEDFField { notation: "0415", labels: &[
+        ("en", "Secretarial and office work"),
+    ]},
+    ISCEDFField { notation: "0416", labels: &[
+        ("en", "Wholesale and retail sales"),
+    ]},
+    ISCEDFField { notation: "0417", labels: &[
+        ("en", "Work skills"),
+    ]},
+    ISCEDFField { notation: "0419", labels: &[
+        ("en", "Business and administration not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "042", labels: &[
+        ("en", "Law"),
+        ("fi", "Oikeustiede"),
+        ("sv", "Juridik"),
+    ]},
+    ISCEDFField { notation: "0421", labels: &[
+        ("en", "Law"),
+    ]},
+    ISCEDFField { notation: "048", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving business, administration and law"),
+    ]},
+    ISCEDFField { notation: "0488", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving business, administration and law"),
+    ]},
+    ISCEDFField { notation: "05", labels: &[
+        ("en", "Natural sciences, mathematics and statistics"),
+        ("fi", "Luonnontieteet"),
+        ("sv", "Naturvetenskap, matematik och statistik"),
+    ]},
+    ISCEDFField { notation: "051", labels: &[
+        ("en", "Biological and related sciences"),
+        ("fi", "Biologia ja siihen liittyvät tieteet"),
+        ("sv", "Biologi och närliggande vetenskaper"),
+    ]},
+    ISCEDFField { notation: "0511", labels: &[
+        ("en", "Biology"),
+    ]},
+    ISCEDFField { notation: "0512", labels: &[
+        ("en", "Biochemistry"),
+    ]},
+    ISCEDFField { notation: "0519", labels: &[
+        ("en", "Biological and related sciences not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "052", labels: &[
+        ("en", "Environment"),
+        ("fi", "Ympäristötieteet"),
+        ("sv", "Miljövetenskap"),
+    ]},
+    ISCEDFField { notation: "0521", labels: &[
+        ("en", "Environmental sciences"),
+    ]},
+    ISCEDFField { notation: "0522", labels: &[
+        ("en", "Natural environments and wildlife"),
+    ]},
+    ISCEDFField { notation: "0529", labels: &[
+        ("en", "Environment not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "053", labels: &[
+        ("en", "Physical sciences"),
+        ("fi", "Fysikaaliset tieteet"),
+        ("sv", "Fysik, kemi och geovetenskap"),
+    ]},
+    ISCEDFField { notation: "0531", labels: &[
+        ("en", "Chemistry"),
+    ]},
+    ISCEDFField { notation: "0532", labels: &[
+        ("en", "Earth sciences"),
+    ]},
+    ISCEDFField { notation: "0533", labels: &[
+        ("en", "Physics"),
+    ]},
+    ISCEDFField { notation: "0539", labels: &[
+        ("en", "Physical sciences not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "054", labels: &[
+        ("en", "Mathematics and statistics"),
+        ("fi", "Matematiikka ja tilastotiede"),
+        ("sv", "Matematik och statistik"),
+    ]},
+    ISCEDFField { notation: "0541", labels: &[
+        ("en", "Mathematics"),
+    ]},
+    ISCEDFField { notation: "0542", labels: &[
+        ("en", "Statistics"),
+    ]},
+    ISCEDFField { notation: "0549", labels: &[
+        ("en", "Mathematics and statistics not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "058", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving natural sciences, mathematics and statistics"),
+    ]},
+    ISCEDFField { notation: "0588", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving natural sciences, mathematics and statistics"),
+    ]},
+    ISCEDFField { notation: "06", labels: &[
+        ("en", "Information and Communication Technologies (ICTs)"),
+        ("fi", "Tietojenkäsittely ja tietoliikenne (ICT)"),
+        ("sv", "Informations- och kommunikationsteknik (IKT)"),
+    ]},
+    ISCEDFField { notation: "061", labels: &[
+        ("en", "Information and Communication Technologies (ICTs)"),
+        ("fi", "Tietojenkäsittely ja tietoliikenne (ICT)"),
+        ("sv", "Informations- och kommunikationsteknik (IKT)"),
+    ]},
+    ISCEDFField { notation: "0611", labels: &[
+        ("en", "Computer use"),
+    ]},
+    ISCEDFField { notation: "0612", labels: &[
+        ("en", "Database and network design and administration"),
+    ]},
+    ISCEDFField { notation: "0613", labels: &[
+        ("en", "Software and applications development and analysis"),
+    ]},
+    ISCEDFField { notation: "0619", labels: &[
+        ("en", "Information and Communication Technologies not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "068", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving Information and Communication Technologies"),
+    ]},
+    ISCEDFField { notation: "0688", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving Information and Communication Technologies"),
+    ]},
+    ISCEDFField { notation: "07", labels: &[
+        ("en", "Engineering, manufacturing and construction"),
+        ("fi", "Tekniikan alat"),
+        ("sv", "Teknik, tillverkning och byggnadsteknik"),
+    ]},
+    ISCEDFField { notation: "071", labels: &[
+        ("en", "Engineering and engineering trades"),
+        ("fi", "Tekniikka ja tekniset ammatit"),
+        ("sv", "Teknik och tekniska yrken"),
+    ]},
+    ISCEDFField { notation: "0711", labels: &[
+        ("en", "Chemical engineering and processes"),
+    ]},
+    ISCEDFField { notation: "0712", labels: &[
+        ("en", "Environmental protection technology"),
+    ]},
+    ISCEDFField { notation: "0713", labels: &[
+        ("en", "Electricity and energy"),
+    ]},
+    ISCEDFField { notation: "0714", labels: &[
+        ("en", "Electronics and automation"),
+    ]},
+    ISCEDFField { notation: "0715", labels: &[
+        ("en", "Mechanics and metal trades"),
+    ]},
+    ISCEDFField { notation: "0716", labels: &[
+        ("en", "Motor vehicles, ships and aircraft"),
+    ]},
+    ISCEDFField { notation: "0719", labels: &[
+        ("en", "Engineering and engineering trades not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "072", labels: &[
+        ("en", "Manufacturing and processing"),
+        ("fi", "Valmistus ja tuotanto"),
+        ("sv", "Tillverkning och bearbetning"),
+    ]},
+    ISCEDFField { notation: "0721", labels: &[
+        ("en", "Food processing"),
+    ]},
+    ISCEDFField { notation: "0722", labels: &[
+        ("en", "Materials (glass, paper, plastic and wood)"),
+    ]},
+    ISCEDFField { notation: "0723", labels: &[
+        ("en", "Textiles (clothes, footwear and leather)"),
+    ]},
+    ISCEDFField { notation: "0724", labels: &[
+        ("en", "Mining and extraction"),
+    ]},
+    ISCEDFField { notation: "0729", labels: &[
+        ("en", "Manufacturing and processing not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "073", labels: &[
+        ("en", "Architecture and construction"),
+        ("fi", "Arkkitehtuuri ja rakentaminen"),
+        ("sv", "Arkitektur och byggnadsteknik"),
+    ]},
+    ISCEDFField { notation: "0731", labels: &[
+        ("en", "Architecture and town planning"),
+    ]},
+    ISCEDFField { notation: "0732", labels: &[
+        ("en", "Building and civil engineering"),
+    ]},
+    ISCEDFField { notation: "078", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving engineering, manufacturing and construction"),
+    ]},
+    ISCEDFField { notation: "0788", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving engineering, manufacturing and construction"),
+    ]},
+    ISCEDFField { notation: "08", labels: &[
+        ("en", "Agriculture, forestry, fisheries and veterinary"),
+        ("fi", "Maa- ja metsätalousalat"),
+        ("sv", "Lant- och skogsbruk, fiske och djursjukvård"),
+    ]},
+    ISCEDFField { notation: "081", labels: &[
+        ("en", "Agriculture"),
+        ("fi", "Maatalous"),
+        ("sv", "Lantbruk"),
+    ]},
+    ISCEDFField { notation: "0811", labels: &[
+        ("en", "Crop and livestock production"),
+    ]},
+    ISCEDFField { notation: "0812", labels: &[
+        ("en", "Horticulture"),
+    ]},
+    ISCEDFField { notation: "0819", labels: &[
+        ("en", "Agriculture not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "082", labels: &[
+        ("en", "Forestry"),
+        ("fi", "Metsätalous"),
+        ("sv", "Skogsbruk"),
+    ]},
+    ISCEDFField { notation: "0821", labels: &[
+        ("en", "Forestry"),
+    ]},
+    ISCEDFField { notation: "083", labels: &[
+        ("en", "Fisheries"),
+        ("fi", "Kalatalous"),
+        ("sv", "Fiske"),
+    ]},
+    ISCEDFField { notation: "0831", labels: &[
+        ("en", "Fisheries"),
+    ]},
+    ISCEDFField { notation: "084", labels: &[
+        ("en", "Veterinary"),
+        ("fi", "Eläinlääketiede"),
+        ("sv", "Veterinärmedicin"),
+    ]},
+    ISCEDFField { notation: "0841", labels: &[
+        ("en", "Veterinary"),
+    ]},
+    ISCEDFField { notation: "088", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving agriculture, forestry, fisheries and veterinary"),
+    ]},
+    ISCEDFField { notation: "0888", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving agriculture, forestry, fisheries and veterinary"),
+    ]},
+    ISCEDFField { notation: "09", labels: &[
+        ("en", "Health and welfare"),
+        ("fi", "Terveys- ja hyvinvointialat"),
+        ("sv", "Hälsa och välfärd"),
+    ]},
+    ISCEDFField { notation: "091", labels: &[
+        ("en", "Health"),
+        ("fi", "Terveysalat"),
+        ("sv", "Hälsa"),
+    ]},
+    ISCEDFField { notation: "0911", labels: &[
+        ("en", "Dental studies"),
+    ]},
+    ISCEDFField { notation: "0912", labels: &[
+        ("en", "Medicine"),
+    ]},
+    ISCEDFField { notation: "0913", labels: &[
+        ("en", "Nursing and midwifery"),
+    ]},
+    ISCEDFField { notation: "0914", labels: &[
+        ("en", "Medical diagnostic and treatment technology"),
+    ]},
+    ISCEDFField { notation: "0915", labels: &[
+        ("en", "Therapy and rehabilitation"),
+    ]},
+    ISCEDFField { notation: "0916", labels: &[
+        ("en", "Pharmacy"),
+    ]},
+    ISCEDFField { notation: "0917", labels: &[
+        ("en", "Traditional and complementary medicine and therapy"),
+    ]},
+    ISCEDFField { notation: "0919", labels: &[
+        ("en", "Health not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "092", labels: &[
+        ("en", "Welfare"),
+        ("fi", "Sosiaaliala"),
+        ("sv", "Socialt arbete och omsorg"),
+    ]},
+    ISCEDFField { notation: "0921", labels: &[
+        ("en", "Care of the elderly and of disabled adults"),
+    ]},
+    ISCEDFField { notation: "0922", labels: &[
+        ("en", "Child care and youth services"),
+    ]},
+    ISCEDFField { notation: "0923", labels: &[
+        ("en", "Social work and counselling"),
+    ]},
+    ISCEDFField { notation: "0929", labels: &[
+        ("en", "Welfare not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "098", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving health and welfare"),
+    ]},
+    ISCEDFField { notation: "0988", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving health and welfare"),
+    ]},
+    ISCEDFField { notation: "10", labels: &[
+        ("en", "Services"),
+        ("fi", "Palvelualat"),
+        ("sv", "Tjänster"),
+    ]},
+    ISCEDFField { notation: "101", labels: &[
+        ("en", "Personal services"),
+        ("fi", "Henkilökohtaiset palvelut"),
+        ("sv", "Personliga tjänster"),
+    ]},
+    ISCEDFField { notation: "1011", labels: &[
+        ("en", "Domestic services"),
+    ]},
+    ISCEDFField { notation: "1012", labels: &[
+        ("en", "Hair and beauty services"),
+    ]},
+    ISCEDFField { notation: "1013", labels: &[
+        ("en", "Hotel, restaurants and catering"),
+    ]},
+    ISCEDFField { notation: "1014", labels: &[
+        ("en", "Sports"),
+    ]},
+    ISCEDFField { notation: "1015", labels: &[
+        ("en", "Travel, tourism and leisure"),
+    ]},
+    ISCEDFField { notation: "1019", labels: &[
+        ("en", "Personal services not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "102", labels: &[
+        ("en", "Hygiene and occupational health services"),
+        ("fi", "Hygienia- ja työterveyspalvelut"),
+        ("sv", "Hygien och hälsoskydd i arbetslivet"),
+    ]},
+    ISCEDFField { notation: "1021", labels: &[
+        ("en", "Community sanitation"),
+    ]},
+    ISCEDFField { notation: "1022", labels: &[
+        ("en", "Occupational health and safety"),
+    ]},
+    ISCEDFField { notation: "1029", labels: &[
+        ("en", "Hygiene and occupational health services not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "103", labels: &[
+        ("en", "Security services"),
+        ("fi", "Turvallisuuspalvelut"),
+        ("sv", "Säkerhetstjänster"),
+    ]},
+    ISCEDFField { notation: "1031", labels: &[
+        ("en", "Military and defence"),
+    ]},
+    ISCEDFField { notation: "1032", labels: &[
+        ("en", "Protection of persons and property"),
+    ]},
+    ISCEDFField { notation: "1039", labels: &[
+        ("en", "Security services not elsewhere classified"),
+    ]},
+    ISCEDFField { notation: "104", labels: &[
+        ("en", "Transport services"),
+        ("fi", "Kuljetuspalvelut"),
+        ("sv", "Transporttjänster"),
+    ]},
+    ISCEDFField { notation: "1041", labels: &[
+        ("en", "Transport services"),
+    ]},
+    ISCEDFField { notation: "108", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving services"),
+    ]},
+    ISCEDFField { notation: "1088", labels: &[
+        ("en", "Inter-disciplinary programmes and qualifications involving services"),
+    ]},
+    ISCEDFField { notation: "99", labels: &[
+        ("en", "Field unknown"),
+        ("fi", "Tuntematon koulutusala"),
+        ("sv", "Okänd utbildningsinriktning"),
+    ]},
+    ISCEDFField { notation: "999", labels: &[
+        ("en", "Field unknown"),
+    ]},
+    ISCEDFField { notation: "9999", labels: &[
+        ("en", "Field unknown"),
+    ]},
+];
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn isced_f_field_from_notation () {
+        let field = match crate::isced_fields_of_education::ISCEDFField::from_notation("061") {
+            Some(field) => field,
+            None => panic!("No ISCED-F field with notation 061!")
+        };
+        assert_eq!(field.uri(), "http://data.europa.eu/snb/isced-f/061");
+        assert_eq!(field.label("fi"), Some("Tietojenkäsittely ja tietoliikenne (ICT)"));
+        assert_eq!(field.label("de"), None);
+        // Detailed fields are kept, and belong to their narrow and broad fields.
+        let detailed = match crate::isced_fields_of_education::ISCEDFField::from_notation("0613") {
+            Some(field) => field,
+            None => panic!("No ISCED-F field for notation 0613!")
+        };
+        assert_eq!(detailed.label("en"), Some("Software and applications development and analysis"));
+        assert_eq!(detailed.broader().map(|field| field.notation()), Some("061"));
+        assert_eq!(detailed.broader().and_then(|field| field.broader()).map(|field| field.notation()), Some("06"));
+        assert!(field.broader().and_then(|field| field.broader()).is_none());
+        for notation in &["11", "0610", "a€", "€", ""] {
+            assert!(crate::isced_fields_of_education::ISCEDFField::from_notation(notation).is_none(), "{}", notation);
+        }
+    }
+    #[test]
+    fn isced_f_field_from_study_field_urn () {
+        match crate::isced_fields_of_education::ISCEDFField::from_study_field_urn("urn:code:study-field:8") {
+            Some(field) => assert_eq!(field.label("en"), Some("Engineering, manufacturing and construction")),
+            None => panic!("No ISCED-F field for study field 8!")
+        }
+        // Humanities include languages, so they are mapped to the broad field.
+        match crate::isced_fields_of_education::ISCEDFField::from_study_field_urn("urn:code:study-field:3") {
+            Some(field) => assert_eq!(field.notation(), "02"),
+            None => panic!("No ISCED-F field for study field 3!")
+        }
+        assert!(crate::isced_fields_of_education::ISCEDFField::from_study_field_urn("string").is_none());
+    }
+    #[test]
+    fn every_study_field_maps_to_an_isced_f_field () {
+        for (urn, notation) in crate::isced_fields_of_education::STUDY_FIELD_URN_TO_ISCED_F {
+            assert!(
+                crate::isced_fields_of_education::ISCEDFField::from_notation(notation).is_some(),
+                "{} maps to an unknown ISCED-F field", urn
+            );
+        }
+    }
+}
@@ -0,0 +1,534 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+/*!
+This submodule serializes the model of the
+[Europass Learning Model 3](https://europa.eu/europass/elm-browser/index.html)
+as JSON-LD, as newer European Digital Credentials are issued in JSON-LD
+rather than in the XML of the Europass Learning Model 2.
+
+The documents use the official `@context` of the European Digital Credentials
+application profile, which maps the compact terms used here, such as `Organisation`
+and `legalName`, onto the classes and properties of the ELM ontology and the
+vocabularies it reuses. Texts are language maps from language tags to contents,
+and the terms of controlled vocabularies are `Concept`s identified by their URIs,
+with their concept schemes, notations and preferred labels. Only official URIs
+are emitted: concepts that have a stand-in URI of this crate, such as those of
+the Europass Standard Lists and the levels of national qualification frameworks,
+are blank nodes identified by their concept scheme and notation.
+
+A [`VerifiableCredential`] is serialized as a `EuropeanDigitalCredential`
+on the W3C Verifiable Credentials Data Model 1.1 the profile is built on.
+Which format a credential is issued in is selected with
+[`CredentialFormat`](crate::europass_learning_model::CredentialFormat).
+*/
+
+use crate::controlled_vocabularies::{self, ControlledVocabulary};
+use crate::europass_learning_model::{
+    json_date_time, Accreditation, Code, EuropassCredential, LearningSpecification, Note, Organisation, Person,
+    Qualification, Text, VerifiableCredential, VerificationCheck,
+};
+use crate::european_qualifications_framework::EQFLevel;
+use crate::national_qualification_frameworks::NQF;
+use serde_json::{json, Value};
+
+/// The base context of the W3C Verifiable Credentials Data Model 1.1.
+pub const VC_V1_CONTEXT: &str = "https://www.w3.org/2018/credentials/v1";
+
+/// The base context of the W3C Verifiable Credentials Data Model 2.0.
+pub const VC_V2_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+
+/// The context of the European Digital Credentials application profile.
+/// It defines the terms of the Europass Learning Model and leaves the terms
+/// of the credential envelope, such as `validFrom`, to the base context of
+/// the data model placed before it. The same context is therefore used
+/// after the base context of either version of the data model: the EDC form
+/// of a credential follows version 1.1 and its wallet form version 2.0.
+pub const EDC_CONTEXT: &str = "http://data.europa.eu/snb/model/context/edc-ap";
+
+/// The SHACL shapes of generic European Digital Credentials.
+pub const EDC_GENERIC_SHAPES: &str = "http://data.europa.eu/snb/model/ap/edc-generic-full";
+
+/// The URI of the concept scheme of the EQF levels.
+const EQF_CONCEPT_SCHEME: &str = "http://data.europa.eu/snb/eqf/25831c2";
+
+/// Types that are serialized as JSON-LD nodes of the Europass Learning Model 3.
+pub trait ToJsonLd {
+    /// The JSON-LD node of this value, to be used in a document
+    /// with the context of the European Digital Credentials application profile.
+    fn to_json_ld(&self) -> Value;
+}
+
+/// Identifies a node by its URI if the URI is official. Concepts and concept
+/// schemes with a stand-in URI of this crate are left as blank nodes
+/// identified by their concept scheme and notation instead.
+fn identify(mut node: Value, uri: &str) -> Value {
+    if controlled_vocabularies::is_official_uri(uri) {
+        insert(&mut node, "id", Some(Value::from(uri)));
+    }
+    node
+}
+
+/// A concept scheme.
+fn concept_scheme(uri: &str) -> Value {
+    identify(json!({ "type": "ConceptScheme" }), uri)
+}
+
+/// A concept of a controlled vocabulary.
+pub fn concept<T: ControlledVocabulary>(value: T) -> Value {
+    identify(json!({
+        "type": "Concept",
+        "inScheme": concept_scheme(T::SCHEME_URI),
+        "notation": value.notation(),
+        "prefLabel": value
+            .concept()
+            .pref_labels()
+            .iter()
+            .map(|(language, label)| (language.to_string(), Value::from(*label)))
+            .collect::<serde_json::Map<String, Value>>(),
+    }), value.uri())
+}
+
+/// A term of a controlled vocabulary given as a `Code`.
+fn code_concept(code: &Code) -> Value {
+    identify(json!({
+        "type": "Concept",
+        "inScheme": concept_scheme(code.target_framework_uri()),
+        "notation": code.target_notation(),
+        "prefLabel": { "en": code.target_name() },
+    }), code.uri())
+}
+
+/// A reference to a node described elsewhere.
+fn reference(id: &str, node_type: &str) -> Value {
+    json!({ "id": id, "type": node_type })
+}
+
+/// Adds a property to a node unless the value is missing or empty.
+fn insert(node: &mut Value, property: &str, value: Option<Value>) {
+    let empty = match &value {
+        None | Some(Value::Null) => true,
+        Some(Value::Array(values)) => values.is_empty(),
+        Some(Value::Object(values)) => values.is_empty(),
+        _ => false,
+    };
+    if let (false, Some(value), Value::Object(node)) = (empty, value, node) {
+        node.insert(property.to_string(), value);
+    }
+}
+
+impl ToJsonLd for Text {
+    fn to_json_ld(&self) -> Value {
+        self.to_language_map()
+    }
+}
+
+impl ToJsonLd for Note {
+    fn to_json_ld(&self) -> Value {
+        self.content().to_language_map()
+    }
+}
+
+impl ToJsonLd for EQFLevel {
+    fn to_json_ld(&self) -> Value {
+        json!({
+            "id": self.uri(),
+            "type": "Concept",
+            "inScheme": { "id": EQF_CONCEPT_SCHEME, "type": "ConceptScheme" },
+            "notation": self.number().to_string(),
+            "prefLabel": self.label().to_language_map(),
+        })
+    }
+}
+
+impl ToJsonLd for NQF {
+    /// The level within the framework of its country. The frameworks have
+    /// no official URIs, so their concept schemes are identified by
+    /// the country they are the framework of.
+    fn to_json_ld(&self) -> Value {
+        let mut scheme = concept_scheme(self.level().scheme_uri());
+        insert(&mut scheme, "spatial", Some(json!({ "id": self.country_uri(), "type": "Concept" })));
+        identify(json!({
+            "type": "Concept",
+            "inScheme": scheme,
+            "notation": self.notation(),
+        }), self.uri())
+    }
+}
+
+impl ToJsonLd for Qualification {
+    fn to_json_ld(&self) -> Value {
+        let mut node = json!({
+            "type": "Qualification",
+            "eqfLevel": self.eqf_level().to_json_ld(),
+            "nqfLevel": [self.nqf_level().to_json_ld()],
+            "isPartialQualification": self.is_partial_qualification(),
+        });
+        insert(&mut node, "accreditation", self.accreditation().map(|accreditation| json!([accreditation.to_json_ld()])));
+        node
+    }
+}
+
+impl ToJsonLd for Accreditation {
+    fn to_json_ld(&self) -> Value {
+        let mut node = json!({
+            "id": self.id(),
+            "type": "Accreditation",
+            "accreditationType": concept(self.accreditation_type()),
+            "organisation": reference(self.organisation(), "Organisation"),
+            "dateIssued": json_date_time(self.issue_date()),
+        });
+        insert(&mut node, "title", Some(self.title().to_json_ld()));
+        insert(&mut node, "limitQualification", self.limit_qualification().map(|id| reference(id, "Qualification")));
+        insert(&mut node, "limitEQFLevel", self.limit_eqf_level().map(|level| json!([level.to_json_ld()])));
+        insert(
+            &mut node,
+            "limitJurisdiction",
+            self.limit_jurisdiction().map(|code| json!([code_concept(code)])),
+        );
+        insert(&mut node, "accreditingAgent", self.accrediting_agent().map(|id| reference(id, "Organisation")));
+        insert(&mut node, "expiryDate", self.expiry_date().map(|date| json!(json_date_time(date))));
+        node
+    }
+}
+
+impl ToJsonLd for Organisation {
+    fn to_json_ld(&self) -> Value {
+        let mut node = json!({
+            "id": self.id(),
+            "type": "Organisation",
+            "legalName": self.preferred_name().to_json_ld(),
+        });
+        insert(
+            &mut node,
+            "accreditation",
+            Some(self.accreditations().iter().map(ToJsonLd::to_json_ld).collect()),
+        );
+        node
+    }
+}
+
+impl ToJsonLd for Person {
+    fn to_json_ld(&self) -> Value {
+        let mut node = json!({
+            "id": self.id(),
+            "type": "Person",
+        });
+        insert(&mut node, "fullName", Some(self.full_name().to_json_ld()));
+        insert(&mut node, "givenName", Some(self.given_names().to_json_ld()));
+        insert(&mut node, "familyName", Some(self.family_name().to_json_ld()));
+        insert(
+            &mut node,
+            "dateOfBirth",
+            self.date_of_birth().map(|date| json!(date.format("%Y-%m-%d").to_string())),
+        );
+        node
+    }
+}
+
+impl ToJsonLd for LearningSpecification {
+    fn to_json_ld(&self) -> Value {
+        let mut node = json!({
+            "id": self.id(),
+            "type": "LearningSpecification",
+            "title": self.title().to_json_ld(),
+        });
+        insert(&mut node, "volumeOfLearning", self.volume_of_learning().map(|duration| json!(duration.to_string())));
+        insert(
+            &mut node,
+            "creditPoint",
+            self.ects_credit_points().map(|points| {
+                json!([{
+                    "type": "CreditPoint",
+                    "framework": concept(controlled_vocabularies::EducationalCreditSystems::CreditTransferSystem),
+                    "point": points.to_string(),
+                }])
+            }),
+        );
+        insert(&mut node, "ISCEDFCode", self.iscedf_code().map(|code| json!([code_concept(code)])));
+        insert(&mut node, "language", self.language().map(|language| json!([concept(language)])));
+        // A specification of a qualification is typed and described as one.
+        if let (Some(qualification), Value::Object(fields)) = (self.qualification(), &mut node) {
+            if let Value::Object(qualification) = qualification.to_json_ld() {
+                fields.extend(qualification);
+            }
+        }
+        node
+    }
+}
+
+/// The subject of a credential with the learning it has achieved as its claims.
+pub(crate) fn credential_subject(credential: &VerifiableCredential) -> Value {
+    let mut node = credential.europass_credential().credential_subject().to_json_ld();
+    let claims: Vec<Value> = credential
+        .europass_credential()
+        .achievements()
+        .iter()
+        .enumerate()
+        .map(|(index, specification)| {
+            let mut awarded_by = json!({
+                "id": credential.awarding_process_id(index),
+                "type": "AwardingProcess",
+                "awardingBody": [reference(credential.europass_credential().issuer().id(), "Organisation")],
+            });
+            insert(&mut awarded_by, "awardingDate", credential.awarding_date().map(|date| json!(json_date_time(date))));
+            insert(
+                &mut awarded_by,
+                "location",
+                credential
+                    .europass_credential()
+                    .awarding_location()
+                    .map(|location| json!({ "type": "Location", "spatialCode": [code_concept(location)] })),
+            );
+            json!({
+                "id": credential.achievement_id(index),
+                "type": "LearningAchievement",
+                "title": specification.title().to_json_ld(),
+                "specifiedBy": specification.to_json_ld(),
+                "awardedBy": awarded_by,
+            })
+        })
+        .collect();
+    insert(&mut node, "hasClaim", Some(Value::from(claims)));
+    node
+}
+
+impl ToJsonLd for EuropassCredential {
+    /// The properties of the credential itself, without the envelope
+    /// of the verifiable credential.
+    fn to_json_ld(&self) -> Value {
+        let languages: Vec<controlled_vocabularies::Language> =
+            self.title().translations().map(|(language, _)| language.into()).collect();
+        let mut display = json!({
+            "type": "DisplayParameter",
+            "title": self.title().to_json_ld(),
+            "language": languages.iter().map(|language| concept(*language)).collect::<Vec<_>>(),
+        });
+        insert(&mut display, "primaryLanguage", languages.first().map(|language| concept(*language)));
+        insert(&mut display, "description", self.description().map(ToJsonLd::to_json_ld));
+        json!({
+            "credentialProfiles": [concept(self.credential_type())],
+            "issuer": self.issuer().to_json_ld(),
+            "credentialSubject": self.credential_subject().to_json_ld(),
+            "displayParameter": display,
+        })
+    }
+}
+
+impl ToJsonLd for VerifiableCredential {
+    /// The credential as a `EuropeanDigitalCredential` document.
+    fn to_json_ld(&self) -> Value {
+        let mut node = json!({
+            "@context": [VC_V1_CONTEXT, EDC_CONTEXT],
+            "id": self.id(),
+            "type": ["VerifiableCredential", "EuropeanDigitalCredential"],
+            "credentialSchema": [{ "id": EDC_GENERIC_SHAPES, "type": "ShaclValidator2017" }],
+            "issuanceDate": json_date_time(self.issuance_date()),
+            "issued": json_date_time(self.issued()),
+        });
+        if let (Value::Object(node), Value::Object(credential)) = (&mut node, self.europass_credential().to_json_ld()) {
+            node.extend(credential);
+        }
+        node["credentialSubject"] = credential_subject(self);
+        let validity = self.validity();
+        insert(&mut node, "validFrom", validity.valid_from().map(|time| json!(json_date_time(time))));
+        insert(&mut node, "expirationDate", validity.expiration_date().map(|time| json!(json_date_time(time))));
+        insert(&mut node, "credentialStatus", self.credential_status().map(|entry| entry.to_json()));
+        node
+    }
+}
+
+impl ToJsonLd for VerificationCheck {
+    fn to_json_ld(&self) -> Value {
+        json!({
+            "id": self.id(),
+            "type": "VerificationCheck",
+            "verificationCheck": concept(self.check_type()),
+            "verificationStatus": concept(self.status()),
+            "subject": { "id": self.subject() },
+            "description": self.description().to_json_ld(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::controlled_vocabularies::{AccreditationType, CredentialType, VerificationStatus, VerificationType};
+    use crate::europass_learning_model::{CredentialFormat, EuropeanLanguage, ValidityPeriod};
+    use crate::national_qualification_frameworks::FinlandNQF;
+    use std::convert::TryFrom;
+
+    fn date_time (y: i32, m: u32, d: u32) -> chrono::naive::NaiveDateTime {
+        chrono::naive::NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn model_nodes () {
+        let level = match EQFLevel::try_from(7) {
+            Ok(level) => level,
+            Err(e) => panic!("{}", e),
+        };
+        let node = level.to_json_ld();
+        assert_eq!(node["id"], "http://data.europa.eu/snb/eqf/7");
+        assert_eq!(node["inScheme"]["id"], EQF_CONCEPT_SCHEME);
+        assert_eq!(node["notation"], "7");
+        let status = concept(VerificationStatus::Green);
+        assert_eq!(status["inScheme"]["id"], VerificationStatus::SCHEME_URI);
+        assert_eq!(status["prefLabel"]["fi"], "Vihreä");
+
+        let mut accreditation = match Accreditation::new(
+            "https://example.org/accreditations/1",
+            AccreditationType::ProgramLicense,
+            Text::new(EuropeanLanguage::English, "Licence"),
+            "https://example.org",
+            date_time(2020, 1, 1),
+            None,
+        ) {
+            Ok(accreditation) => accreditation,
+            Err(e) => panic!("{}", e),
+        };
+        accreditation.limit(Some("https://example.org/qualifications/msc"), Some(level), None);
+        let node = accreditation.to_json_ld();
+        assert!(node["accreditationType"].get("id").is_none());
+        assert_eq!(node["accreditationType"]["notation"], AccreditationType::ProgramLicense.notation());
+        assert_eq!(node["accreditationType"]["inScheme"]["id"], AccreditationType::SCHEME_URI);
+        assert_eq!(node["limitEQFLevel"][0]["id"], level.uri());
+        assert_eq!(node["dateIssued"], "2020-01-01T00:00:00Z");
+        assert!(node.get("expiryDate").is_none() && node.get("limitJurisdiction").is_none());
+        let mut organisation = Organisation::new("https://example.org", Text::new(EuropeanLanguage::English, "Example"));
+        assert!(organisation.to_json_ld().get("accreditation").is_none());
+        organisation.add_accreditation(accreditation).unwrap();
+        assert_eq!(organisation.to_json_ld()["accreditation"][0]["id"], "https://example.org/accreditations/1");
+
+        let qualification = match Qualification::from_nqf_level(false, NQF::Finland(FinlandNQF::Level7)) {
+            Ok(qualification) => qualification,
+            Err(e) => panic!("{}", e),
+        };
+        let node = qualification.to_json_ld();
+        assert_eq!(node["type"], "Qualification");
+        assert_eq!(node["eqfLevel"]["id"], level.uri());
+        // The stand-in URIs of the crate are not emitted.
+        assert!(!node.to_string().contains(controlled_vocabularies::STAND_IN_CONCEPT_NAMESPACE));
+        assert_eq!(node["nqfLevel"][0]["notation"], "7");
+        assert_eq!(
+            node["nqfLevel"][0]["inScheme"],
+            json!({
+                "type": "ConceptScheme",
+                "spatial": { "id": "http://publications.europa.eu/resource/authority/country/FIN", "type": "Concept" },
+            })
+        );
+        let mut specification = LearningSpecification::new("urn:degree:1", Text::new(EuropeanLanguage::English, "MSc"));
+        specification.set_qualification(qualification);
+        if let Some(field) = crate::isced_fields_of_education::ISCEDFField::from_notation("061") {
+            specification.set_iscedf_code(field.to_code());
+        }
+        let node = specification.to_json_ld();
+        assert_eq!(node["ISCEDFCode"][0]["id"], "http://data.europa.eu/snb/isced-f/061");
+        assert_eq!(node["id"], "urn:degree:1");
+        assert_eq!(node["type"], "Qualification");
+        assert_eq!(node["eqfLevel"]["id"], level.uri());
+
+        let check = VerificationCheck::new(
+            VerificationType::Owner,
+            "urn:credential:1",
+            VerificationStatus::Red,
+            Note::new(Text::new(EuropeanLanguage::English, "Not the owner.")),
+        );
+        let node = check.to_json_ld();
+        assert_eq!(node["verificationCheck"]["notation"], VerificationType::Owner.notation());
+        assert_eq!(node["description"]["en"], "Not the owner.");
+    }
+
+    /// The terms of the credential envelope that the base contexts define.
+    /// Version 1.1 defines both the issuance and the validity terms,
+    /// version 2.0 only the validity terms.
+    const VC_V1_TERMS: [&str; 8] = [
+        "credentialSchema", "credentialStatus", "credentialSubject", "expirationDate",
+        "issuanceDate", "issued", "issuer", "validFrom",
+    ];
+    const VC_V2_TERMS: [&str; 7] = [
+        "credentialSchema", "credentialStatus", "credentialSubject", "description", "issuer", "validFrom", "validUntil",
+    ];
+    /// The terms of the credential itself that the EDC context defines.
+    const EDC_TERMS: [&str; 4] = ["credentialProfiles", "description", "displayParameter", "title"];
+
+    #[test]
+    fn json_ld_contexts () {
+        let mut credential = EuropassCredential::new(
+            CredentialType::Generic,
+            Text::new(EuropeanLanguage::Finnish, "Kurssi"),
+            Organisation::new("https://example.org", Text::new(EuropeanLanguage::Finnish, "Esimerkkiyliopisto")),
+            Person::new("urn:person:1", Text::default(), Text::default(), None),
+        );
+        credential.set_description(Note::new(Text::new(EuropeanLanguage::Finnish, "Kurssi suoritettu.")));
+        let validity = ValidityPeriod::new(Some(date_time(2024, 5, 31)), Some(date_time(2030, 5, 31))).unwrap();
+        let vc = VerifiableCredential::new(credential, "attainment-1", date_time(2024, 6, 1), validity);
+        // Every property of the envelope is defined by one of the contexts of the document.
+        let check = |node: Value, base: &str, base_terms: &[&str]| {
+            assert_eq!(node["@context"], json!([base, EDC_CONTEXT]));
+            let properties: Vec<&String> = match node.as_object() {
+                Some(node) => node.keys().filter(|key| !["@context", "id", "type"].contains(&key.as_str())).collect(),
+                None => panic!("{}", node),
+            };
+            for property in properties {
+                assert!(base_terms.contains(&property.as_str()) || EDC_TERMS.contains(&property.as_str()), "{}", property);
+            }
+        };
+        let edc = ToJsonLd::to_json_ld(&vc);
+        assert!(edc.get("validFrom").is_some() && edc.get("expirationDate").is_some());
+        check(edc, VC_V1_CONTEXT, &VC_V1_TERMS);
+        let wallet = vc.to_vc_json_ld();
+        assert!(wallet.get("validFrom").is_some() && wallet.get("validUntil").is_some());
+        check(wallet, VC_V2_CONTEXT, &VC_V2_TERMS);
+    }
+
+    #[test]
+    fn european_digital_credential () {
+        let person = Person::new(
+            "urn:person:1",
+            Text::new(EuropeanLanguage::Finnish, "Matti"),
+            Text::new(EuropeanLanguage::Finnish, "Meikäläinen"),
+            None,
+        );
+        let mut credential = EuropassCredential::new(
+            CredentialType::Generic,
+            Text::new(EuropeanLanguage::Finnish, "Kurssi"),
+            Organisation::new("https://example.org", Text::new(EuropeanLanguage::Finnish, "Esimerkkiyliopisto")),
+            person,
+        );
+        let mut specification = LearningSpecification::new("urn:course-unit:1", Text::new(EuropeanLanguage::Finnish, "Kurssi"));
+        specification.set_credit_points(5.0, crate::europass_learning_model::CreditWorkloadRule::default().workload(5.0));
+        credential.add_achievement(specification);
+        let validity = ValidityPeriod::new(Some(date_time(2024, 5, 31)), Some(date_time(2030, 5, 31))).unwrap();
+        let vc = VerifiableCredential::new(credential, "attainment-1", date_time(2024, 6, 1), validity);
+        let node = ToJsonLd::to_json_ld(&vc);
+        assert_eq!(node["@context"], json!([VC_V1_CONTEXT, EDC_CONTEXT]));
+        assert_eq!(node["type"][1], "EuropeanDigitalCredential");
+        assert_eq!(node["credentialProfiles"][0]["notation"], CredentialType::Generic.notation());
+        assert!(!node.to_string().contains(controlled_vocabularies::STAND_IN_CONCEPT_NAMESPACE));
+        assert_eq!(node["issuer"]["legalName"]["fi"], "Esimerkkiyliopisto");
+        assert_eq!(node["credentialSubject"]["fullName"]["fi"], "Matti Meikäläinen");
+        assert!(node["credentialSubject"].get("dateOfBirth").is_none());
+        let claim = &node["credentialSubject"]["hasClaim"][0];
+        assert_eq!(claim["id"], format!("{}#achievement-1", vc.id()));
+        assert_eq!(claim["specifiedBy"]["volumeOfLearning"], "PT135H");
+        assert_eq!(claim["specifiedBy"]["creditPoint"][0]["point"], "5");
+        assert_eq!(claim["awardedBy"]["awardingBody"][0]["id"], "https://example.org");
+        assert_eq!(claim["awardedBy"]["awardingDate"], "2024-05-31T00:00:00Z");
+        assert_eq!(node["displayParameter"]["primaryLanguage"]["id"], "http://publications.europa.eu/resource/authority/language/FIN");
+        assert_eq!(node["validFrom"], "2024-05-31T00:00:00Z");
+        assert_eq!(node["expirationDate"], "2030-05-31T00:00:00Z");
+
+        let serialized = |format| match vc.serialize(format) {
+            Ok(serialized) => serialized,
+            Err(e) => panic!("{}", e),
+        };
+        assert!(serialized(CredentialFormat::EuropassXml).contains("<europassCredential"));
+        let edc: Value = serde_json::from_str(&serialized(CredentialFormat::EdcJsonLd)).unwrap();
+        assert_eq!(edc, node);
+        let wallet: Value = serde_json::from_str(&serialized(CredentialFormat::VerifiableCredentialJsonLd)).unwrap();
+        assert_eq!(wallet["@context"][0], VC_V2_CONTEXT);
+        assert_eq!("JSON-LD".parse::<CredentialFormat>(), Ok(CredentialFormat::EdcJsonLd));
+        assert!("pdf".parse::<CredentialFormat>().is_err());
+    }
+}
@@ -1,3 +1,5 @@
+// The type names follow the acronyms used in the Europass specification.
+#![allow(clippy::upper_case_acronyms)]
 /*!
 # ↦ Europass Credential
 
@@ -27,6 +29,17 @@ mod europass_learning_model;
 mod european_qualifications_framework;
 mod national_qualification_frameworks;
 mod controlled_vocabularies;
+mod isced_fields_of_education;
+mod skos;
+mod vocabulary_registry;
+mod xml_canonicalization;
+mod seal;
+mod verification;
+mod revocation;
+mod status_list;
+mod accreditation;
+mod owner;
+mod json_ld;
 
 use europass_learning_model::EuropassCredential;
 
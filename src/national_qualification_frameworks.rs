@@ -5,13 +5,31 @@ that list the different national qualification levels acknowledged by
 the European Union. They should be accessed through the top level
 enum `NQF`, at least when used as struct members.
 
+Each level is documented with the qualifications placed on it and carries
+the EQF level it was referenced to in the referencing report of its country.
+Several levels or sublevels may be referenced to the same EQF level, and
+the framework macro also allows a level to span several EQF levels.
+Levels below the first EQF level, such as the entry level of the Netherlands,
+have no EQF level.
+
+The countries are identified by the URIs of the MDR Countries and territories
+Named Authority List. The frameworks are not published as a Named Authority
+List, so the levels are identified by their notation within a namespace of
+their country owned by this crate, such as
+`https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/fin/6`,
+under [`STAND_IN_CONCEPT_NAMESPACE`](crate::controlled_vocabularies::STAND_IN_CONCEPT_NAMESPACE).
+These URIs are not published by the EU and do not resolve.
+
 See https://www.oph.fi/en/education-and-qualifications/qualifications-frameworks
 for a list of links to relevant documents and details.
 */
 
+use crate::european_qualifications_framework::EQFLevel;
+
 /// The root of the shallow national qualification framework tree.
 /// Lists the nations that have provided their NQFs for review,
 /// and provides access to them, when used as a member of a struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NQF {
     Austria(AustriaNQF),
     BosniaAndHerzegovina(BosniaAndHerzegovinaNQF),
@@ -49,78 +67,1176 @@ pub enum NQF {
     Turkey(TurkeyNQF),
 }
 
-pub enum AustriaNQF {}
-pub enum BosniaAndHerzegovinaNQF {}
-pub enum BulgariaNQF {}
-pub enum CroatiaNQF {}
-pub enum CyprusNQF {}
-pub enum CzechRepublicNQF {}
-pub enum DenmarkNQF {}
-pub enum GermanyNQF {}
-pub enum EstoniaNQF {}
-
-/// The national qualification framework levels of Finland.
-/// See https://www.oph.fi/en/education-and-qualifications/qualifications-frameworks
-pub enum FinlandNQF {
-    /// Basic education syllabus and Preparatory education
-    /// for working life and independent living (TELMA).
-    Level2,
-    /// Preparatory studies for general upper secondary school (LUVA),
-    /// Preparatory education for vocational training (VALMA) and
-    /// an Advanced syllabus for basic education in the arts.
-    Level3,
-    /// General upper secondary school syllabus and the Matriculation Examination,
-    /// Upper secondary vocational qualifications and further vocational qualifications,
-    /// Basic Examination in Prison Services, Fire Fighter Qualification,
-    /// and Emergency Response Centre Operator Qualification, a Basic course for
-    /// Border Guards and a Basic study module for non-commissioned officers.
-    Level4,
-    /// Specialist vocational qualifications, the Sub-Officer Qualification
-    /// (Fire and Rescue Services), the Vocational Qualification in Air Traffic Control,
-    /// a General level (1 and 2) study module for non-commissioned officers and
-    /// a Master level study module for non-commissioned officers.
-    Level5,
-    /// Bachelor’s degrees at universities of applied sciences and Bachelor’s degrees
-    /// at universities, Professional specialisation programmes provided by universities
-    /// and universities of applied sciences intended for holders of a Bachelor's degrees
-    /// or a UAS Bachelor’s degree, and Specialised training and Pastoral qualification
-    /// provided by the church.
-    Level6,
-    /// Master’s degrees at universities of applied sciences and Master’s degrees at
-    /// universities, Professional specialisation programmes provided by universities
-    /// and universities of applied sciences intended for holders of a Master’s degree
-    /// or a UAS Master’s degree, Advanced pastoral qualification, Senior staff officer
-    /// course, Further studies in war economy and technology and Specific qualification
-    /// on prescribing medicines.
-    Level7,
-    /// Universities’ and National Defence University scientific and artistic postgraduate
-    /// degrees (licentiate and doctor degrees), the General Staff Officer’s Degree,
-    /// the Specialist Degree in Veterinary Medicine, and Specialist training in medicine
-    /// and Specialist training in dentistry.
-    Level8,
-}
-
-pub enum FranceNQF {}
-pub enum GreeceNQF {}
-pub enum HungaryNQF {}
-pub enum IcelandNQF {}
-pub enum IrelandNQF {}
-pub enum ItalyNQF {}
-pub enum LatviaNQF {}
-pub enum LiechtensteinNQF {}
-pub enum LithuaniaNQF {}
-pub enum LuxembourgNQF {}
-pub enum NorthMacedoniaNQF {}
-pub enum MaltaNQF {}
-pub enum MontenegroNQF {}
-pub enum NetherlandsNQF {}
-pub enum NorwayNQF {}
-pub enum PolandNQF {}
-pub enum PortugalNQF {}
-pub enum RomaniaNQF {}
-pub enum SerbiaNQF {}
-pub enum SlovakiaNQF {}
-pub enum SloveniaNQF {}
-pub enum SwedenNQF {}
-pub enum SwitzerlandNQF {}
-pub enum TurkeyNQF {}
+impl NQF {
+    /// The level of the national framework this value wraps.
+    pub fn level(&self) -> &dyn NationalQualificationLevel {
+        match self {
+            NQF::Austria(level) => level,
+            NQF::BosniaAndHerzegovina(level) => level,
+            NQF::Bulgaria(level) => level,
+            NQF::Croatia(level) => level,
+            NQF::Cyprus(level) => level,
+            NQF::CzechRepublic(level) => level,
+            NQF::Denmark(level) => level,
+            NQF::Germany(level) => level,
+            NQF::Estonia(level) => level,
+            NQF::Finland(level) => level,
+            NQF::France(level) => level,
+            NQF::Greece(level) => level,
+            NQF::Hungary(level) => level,
+            NQF::Iceland(level) => level,
+            NQF::Ireland(level) => level,
+            NQF::Italy(level) => level,
+            NQF::Latvia(level) => level,
+            NQF::Liechtenstein(level) => level,
+            NQF::Lithuania(level) => level,
+            NQF::Luxembourg(level) => level,
+            NQF::NorthMacedonia(level) => level,
+            NQF::Malta(level) => level,
+            NQF::Montenegro(level) => level,
+            NQF::Netherlands(level) => level,
+            NQF::Norway(level) => level,
+            NQF::Poland(level) => level,
+            NQF::Portugal(level) => level,
+            NQF::Romania(level) => level,
+            NQF::Serbia(level) => level,
+            NQF::Slovakia(level) => level,
+            NQF::Slovenia(level) => level,
+            NQF::Sweden(level) => level,
+            NQF::Switzerland(level) => level,
+            NQF::Turkey(level) => level,
+        }
+    }
+
+    /// The URI of the country of the framework in the
+    /// MDR Countries and territories Named Authority List.
+    pub fn country_uri(&self) -> &'static str {
+        self.level().country_uri()
+    }
+
+    /// The notation of the level within its framework, such as `6` or `4.1`.
+    pub fn notation(&self) -> &'static str {
+        self.level().notation()
+    }
+
+    /// The URI of the level.
+    pub fn uri(&self) -> &'static str {
+        self.level().uri()
+    }
+
+    /// A description of the qualifications placed on the level.
+    pub fn description(&self) -> String {
+        self.level().description()
+    }
+
+    /// The EQF level of the level, or the lowest one if the level spans
+    /// several EQF levels. Levels below the first EQF level have none.
+    pub fn eqf_level(&self) -> Option<EQFLevel> {
+        self.eqf_levels().first().copied()
+    }
+
+    /// All EQF levels the level was referenced to in ascending order.
+    pub fn eqf_levels(&self) -> &'static [EQFLevel] {
+        self.level().referenced_eqf_levels()
+    }
+
+    /// Checks that the level was referenced to the given EQF level,
+    /// so that a qualification does not claim inconsistent levels.
+    pub fn check_eqf_level(&self, eqf_level: EQFLevel) -> Result<(), String> {
+        if self.eqf_levels().contains(&eqf_level) {
+            Ok(())
+        } else {
+            Err(format!(
+                "NQF level {} of {} is not referenced to {}…",
+                self.notation(),
+                self.country_uri(),
+                eqf_level
+            ))
+        }
+    }
+
+    /// The levels of every national framework.
+    pub fn levels() -> Vec<NQF> {
+        let mut levels = Vec::new();
+        levels.extend(AustriaNQF::LEVELS.iter().map(|level| NQF::Austria(*level)));
+        levels.extend(BosniaAndHerzegovinaNQF::LEVELS.iter().map(|level| NQF::BosniaAndHerzegovina(*level)));
+        levels.extend(BulgariaNQF::LEVELS.iter().map(|level| NQF::Bulgaria(*level)));
+        levels.extend(CroatiaNQF::LEVELS.iter().map(|level| NQF::Croatia(*level)));
+        levels.extend(CyprusNQF::LEVELS.iter().map(|level| NQF::Cyprus(*level)));
+        levels.extend(CzechRepublicNQF::LEVELS.iter().map(|level| NQF::CzechRepublic(*level)));
+        levels.extend(DenmarkNQF::LEVELS.iter().map(|level| NQF::Denmark(*level)));
+        levels.extend(GermanyNQF::LEVELS.iter().map(|level| NQF::Germany(*level)));
+        levels.extend(EstoniaNQF::LEVELS.iter().map(|level| NQF::Estonia(*level)));
+        levels.extend(FinlandNQF::LEVELS.iter().map(|level| NQF::Finland(*level)));
+        levels.extend(FranceNQF::LEVELS.iter().map(|level| NQF::France(*level)));
+        levels.extend(GreeceNQF::LEVELS.iter().map(|level| NQF::Greece(*level)));
+        levels.extend(HungaryNQF::LEVELS.iter().map(|level| NQF::Hungary(*level)));
+        levels.extend(IcelandNQF::LEVELS.iter().map(|level| NQF::Iceland(*level)));
+        levels.extend(IrelandNQF::LEVELS.iter().map(|level| NQF::Ireland(*level)));
+        levels.extend(ItalyNQF::LEVELS.iter().map(|level| NQF::Italy(*level)));
+        levels.extend(LatviaNQF::LEVELS.iter().map(|level| NQF::Latvia(*level)));
+        levels.extend(LiechtensteinNQF::LEVELS.iter().map(|level| NQF::Liechtenstein(*level)));
+        levels.extend(LithuaniaNQF::LEVELS.iter().map(|level| NQF::Lithuania(*level)));
+        levels.extend(LuxembourgNQF::LEVELS.iter().map(|level| NQF::Luxembourg(*level)));
+        levels.extend(NorthMacedoniaNQF::LEVELS.iter().map(|level| NQF::NorthMacedonia(*level)));
+        levels.extend(MaltaNQF::LEVELS.iter().map(|level| NQF::Malta(*level)));
+        levels.extend(MontenegroNQF::LEVELS.iter().map(|level| NQF::Montenegro(*level)));
+        levels.extend(NetherlandsNQF::LEVELS.iter().map(|level| NQF::Netherlands(*level)));
+        levels.extend(NorwayNQF::LEVELS.iter().map(|level| NQF::Norway(*level)));
+        levels.extend(PolandNQF::LEVELS.iter().map(|level| NQF::Poland(*level)));
+        levels.extend(PortugalNQF::LEVELS.iter().map(|level| NQF::Portugal(*level)));
+        levels.extend(RomaniaNQF::LEVELS.iter().map(|level| NQF::Romania(*level)));
+        levels.extend(SerbiaNQF::LEVELS.iter().map(|level| NQF::Serbia(*level)));
+        levels.extend(SlovakiaNQF::LEVELS.iter().map(|level| NQF::Slovakia(*level)));
+        levels.extend(SloveniaNQF::LEVELS.iter().map(|level| NQF::Slovenia(*level)));
+        levels.extend(SwedenNQF::LEVELS.iter().map(|level| NQF::Sweden(*level)));
+        levels.extend(SwitzerlandNQF::LEVELS.iter().map(|level| NQF::Switzerland(*level)));
+        levels.extend(TurkeyNQF::LEVELS.iter().map(|level| NQF::Turkey(*level)));
+        levels
+    }
+
+    /// The levels of the framework of the given country referenced to
+    /// the given EQF level in ascending order. The country is given by its
+    /// code or URI in the MDR Countries and territories Named Authority List,
+    /// such as `FIN`.
+    pub fn from_eqf_level(country: &str, eqf_level: EQFLevel) -> Vec<NQF> {
+        NQF::levels()
+            .into_iter()
+            .filter(|level| {
+                let country_uri = level.country_uri();
+                country_uri == country || country_uri.rsplit('/').next() == Some(country)
+            })
+            .filter(|level| level.eqf_levels().contains(&eqf_level))
+            .collect()
+    }
+}
+
+/// A level of a national qualification framework.
+pub trait NationalQualificationLevel {
+    /// The URI of the country of the framework in the
+    /// MDR Countries and territories Named Authority List.
+    fn country_uri(&self) -> &'static str;
+    /// The URI of the framework.
+    fn scheme_uri(&self) -> &'static str;
+    /// The notation of the level within its framework, such as `6` or `4.1`.
+    fn notation(&self) -> &'static str;
+    /// The URI of the level.
+    fn uri(&self) -> &'static str;
+    /// A description of the qualifications placed on the level,
+    /// taken from its documentation.
+    fn description(&self) -> String;
+    /// The EQF levels this level was referenced to in ascending order.
+    /// Most levels are referenced to a single EQF level, but a level may
+    /// span several EQF levels, and levels below the first EQF level
+    /// are referenced to none.
+    fn referenced_eqf_levels(&self) -> &'static [EQFLevel];
+}
+
+/// Defines the levels of the national qualification framework of a country
+/// from their documentation, notations and referenced EQF levels.
+/// A level spanning several EQF levels lists them as `=> Level5 | Level6`.
+/// The documentation of each level doubles as its description.
+macro_rules! national_framework {
+    (
+        $(#[doc = $doc:literal])*
+        $name:ident, $country:literal, $scheme:literal {
+            $(
+                $(#[doc = $level_doc:literal])*
+                $level:ident = $notation:literal $(=> $($eqf:ident)|+)?,
+            )*
+        }
+    ) => {
+        $(#[doc = $doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum $name {
+            $(
+                $(#[doc = $level_doc])*
+                $level,
+            )*
+        }
+
+        impl $name {
+            /// The levels of the framework in ascending order.
+            pub const LEVELS: &'static [$name] = &[$($name::$level),*];
+
+            /// The URI of the framework.
+            pub const SCHEME_URI: &'static str = $scheme;
+
+            /// The level with the given notation.
+            pub fn from_notation(notation: &str) -> Option<$name> {
+                $name::LEVELS.iter().find(|level| level.notation() == notation).copied()
+            }
+
+            /// The levels referenced to the given EQF level in ascending order.
+            pub fn from_eqf_level(eqf_level: EQFLevel) -> Vec<$name> {
+                $name::LEVELS
+                    .iter()
+                    .filter(|level| level.referenced_eqf_levels().contains(&eqf_level))
+                    .copied()
+                    .collect()
+            }
+        }
+
+        impl NationalQualificationLevel for $name {
+            fn country_uri(&self) -> &'static str {
+                concat!("http://publications.europa.eu/resource/authority/country/", $country)
+            }
+
+            fn scheme_uri(&self) -> &'static str {
+                $scheme
+            }
+
+            fn notation(&self) -> &'static str {
+                match self {
+                    $($name::$level => $notation,)*
+                }
+            }
+
+            fn uri(&self) -> &'static str {
+                match self {
+                    $($name::$level => concat!($scheme, "/", $notation),)*
+                }
+            }
+
+            fn description(&self) -> String {
+                let lines: &[&str] = match self {
+                    $($name::$level => &[$($level_doc),*],)*
+                };
+                lines.iter().map(|line| line.trim()).collect::<Vec<_>>().join(" ")
+            }
+
+            fn referenced_eqf_levels(&self) -> &'static [EQFLevel] {
+                match self {
+                    $($name::$level => &[$($(EQFLevel::$eqf),+)?],)*
+                }
+            }
+        }
+    };
+}
+
+national_framework! {
+    /// The national qualification framework levels of Austria (NQR).
+    AustriaNQF, "AUT", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/aut" {
+        /// Basic qualifications below compulsory schooling.
+        Level1 = "1" => Level1,
+        /// The compulsory school leaving qualification (Pflichtschulabschluss).
+        Level2 = "2" => Level2,
+        /// Qualifications of shorter intermediate vocational schools
+        /// and pre-vocational training.
+        Level3 = "3" => Level3,
+        /// Apprenticeship diplomas (Lehrabschluss) and diplomas of
+        /// intermediate technical and vocational schools (BMS).
+        Level4 = "4" => Level4,
+        /// The upper secondary school leaving and diploma examination of
+        /// colleges for higher vocational education (BHS).
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees and the master craftsperson qualification (Meisterprüfung).
+        Level6 = "6" => Level6,
+        /// Master’s and diploma degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Bosnia and Herzegovina.
+    BosniaAndHerzegovinaNQF, "BIH", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/bih" {
+        /// Qualifications of the first cycle of basic education.
+        Level1 = "1" => Level1,
+        /// Completed basic education.
+        Level2 = "2" => Level2,
+        /// Qualifications of three-year secondary vocational education.
+        Level3 = "3" => Level3,
+        /// Qualifications of four-year secondary general and vocational education.
+        Level4 = "4" => Level4,
+        /// Post-secondary specialist vocational qualifications.
+        Level5 = "5" => Level5,
+        /// First cycle degrees of higher education.
+        Level6 = "6" => Level6,
+        /// Second cycle degrees of higher education.
+        Level7 = "7" => Level7,
+        /// Third cycle degrees of higher education.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Bulgaria.
+    BulgariaNQF, "BGR", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/bgr" {
+        /// Preparatory pre-school education.
+        Level0 = "0",
+        /// Primary education.
+        Level1 = "1" => Level1,
+        /// Basic education and the first level of professional qualification.
+        Level2 = "2" => Level2,
+        /// The first stage of secondary education and
+        /// the second level of professional qualification.
+        Level3 = "3" => Level3,
+        /// Secondary education and the third level of professional qualification.
+        Level4 = "4" => Level4,
+        /// The fourth level of professional qualification
+        /// and professional bachelor’s degrees in college.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Croatia (CROQF).
+    /// Levels 4 and 8 are divided into sublevels.
+    CroatiaNQF, "HRV", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/hrv" {
+        /// Qualifications of primary education for adults and short vocational training.
+        Level1 = "1" => Level1,
+        /// Primary education and vocational training of up to one year.
+        Level2 = "2" => Level2,
+        /// Vocational secondary education of up to three years.
+        Level3 = "3" => Level3,
+        /// Three-year vocational secondary education.
+        Level4_1 = "4.1" => Level4,
+        /// Four-year vocational and general secondary education
+        /// and the state matura.
+        Level4_2 = "4.2" => Level4,
+        /// Professional higher education short-cycle programmes
+        /// and post-secondary vocational qualifications.
+        Level5 = "5" => Level5,
+        /// Undergraduate university and professional studies.
+        Level6 = "6" => Level6,
+        /// Graduate university and specialist professional studies.
+        Level7 = "7" => Level7,
+        /// Postgraduate specialist studies.
+        Level8_1 = "8.1" => Level8,
+        /// Postgraduate university studies leading to a doctoral degree.
+        Level8_2 = "8.2" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Cyprus (CyQF).
+    CyprusNQF, "CYP", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/cyp" {
+        /// Primary education.
+        Level1 = "1" => Level1,
+        /// Lower secondary education (gymnasium).
+        Level2 = "2" => Level2,
+        /// Vocational qualifications of the system of vocational qualifications.
+        Level3 = "3" => Level3,
+        /// The upper secondary school leaving certificate (apolytirio)
+        /// and the apprenticeship certificate.
+        Level4 = "4" => Level4,
+        /// Diplomas of post-secondary institutes of vocational
+        /// education and training and short-cycle higher education.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of the Czech Republic (NSK).
+    CzechRepublicNQF, "CZE", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/cze" {
+        /// Elementary education.
+        Level1 = "1" => Level1,
+        /// Basic education and partial vocational qualifications.
+        Level2 = "2" => Level2,
+        /// Secondary education with a vocational certificate (výuční list).
+        Level3 = "3" => Level3,
+        /// Secondary education with the school leaving examination (maturita).
+        Level4 = "4" => Level4,
+        /// Professional qualifications of the National Register of Qualifications.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees and higher vocational education (DiS.).
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Denmark.
+    DenmarkNQF, "DNK", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/dnk" {
+        /// The leaving examination of primary and lower secondary school (folkeskole).
+        Level1 = "1" => Level1,
+        /// The 10th form examination and preparatory adult education.
+        Level2 = "2" => Level2,
+        /// Basic vocational education and training.
+        Level3 = "3" => Level3,
+        /// General upper secondary examinations (STX, HHX, HTX, HF) and
+        /// vocational education and training.
+        Level4 = "4" => Level4,
+        /// Academy profession degrees and vocational academy education.
+        Level5 = "5" => Level5,
+        /// Professional bachelor’s degrees, bachelor’s degrees and diploma programmes.
+        Level6 = "6" => Level6,
+        /// Master’s degrees (kandidat) and master programmes of adult education.
+        Level7 = "7" => Level7,
+        /// PhD degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Germany (DQR).
+    GermanyNQF, "DEU", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/deu" {
+        /// Vocational training preparation measures of the
+        /// Federal Employment Agency and the prevocational training year.
+        Level1 = "1" => Level1,
+        /// Vocational training preparation and the basic vocational training year.
+        Level2 = "2" => Level2,
+        /// Dual vocational education and training of two years.
+        Level3 = "3" => Level3,
+        /// Dual vocational education and training of three to three and a half years
+        /// and full-time vocational school education.
+        Level4 = "4" => Level4,
+        /// Certified IT specialists and certified service technicians.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees, master craftsperson qualifications (Meister),
+        /// business specialists (Fachwirt) and trade and technical schools (Fachschule).
+        Level6 = "6" => Level6,
+        /// Master’s degrees and strategic IT professionals.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Estonia (EKR).
+    EstoniaNQF, "EST", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/est" {
+        /// Basic education for students with special educational needs.
+        Level1 = "1" => Level1,
+        /// Basic education and vocational education without basic education requirements.
+        Level2 = "2" => Level2,
+        /// Vocational education of level 3.
+        Level3 = "3" => Level3,
+        /// General secondary education, vocational secondary education
+        /// and vocational education of level 4.
+        Level4 = "4" => Level4,
+        /// Vocational education of level 5.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees and professional higher education diplomas.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Finland.
+    /// See https://www.oph.fi/en/education-and-qualifications/qualifications-frameworks
+    FinlandNQF, "FIN", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/fin" {
+        /// Basic education syllabus and Preparatory education
+        /// for working life and independent living (TELMA).
+        Level2 = "2" => Level2,
+        /// Preparatory studies for general upper secondary school (LUVA),
+        /// Preparatory education for vocational training (VALMA) and
+        /// an Advanced syllabus for basic education in the arts.
+        Level3 = "3" => Level3,
+        /// General upper secondary school syllabus and the Matriculation Examination,
+        /// Upper secondary vocational qualifications and further vocational qualifications,
+        /// Basic Examination in Prison Services, Fire Fighter Qualification,
+        /// and Emergency Response Centre Operator Qualification, a Basic course for
+        /// Border Guards and a Basic study module for non-commissioned officers.
+        Level4 = "4" => Level4,
+        /// Specialist vocational qualifications, the Sub-Officer Qualification
+        /// (Fire and Rescue Services), the Vocational Qualification in Air Traffic Control,
+        /// a General level (1 and 2) study module for non-commissioned officers and
+        /// a Master level study module for non-commissioned officers.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees at universities of applied sciences and Bachelor’s degrees
+        /// at universities, Professional specialisation programmes provided by universities
+        /// and universities of applied sciences intended for holders of a Bachelor's degrees
+        /// or a UAS Bachelor’s degree, and Specialised training and Pastoral qualification
+        /// provided by the church.
+        Level6 = "6" => Level6,
+        /// Master’s degrees at universities of applied sciences and Master’s degrees at
+        /// universities, Professional specialisation programmes provided by universities
+        /// and universities of applied sciences intended for holders of a Master’s degree
+        /// or a UAS Master’s degree, Advanced pastoral qualification, Senior staff officer
+        /// course, Further studies in war economy and technology and Specific qualification
+        /// on prescribing medicines.
+        Level7 = "7" => Level7,
+        /// Universities’ and National Defence University scientific and artistic postgraduate
+        /// degrees (licentiate and doctor degrees), the General Staff Officer’s Degree,
+        /// the Specialist Degree in Veterinary Medicine, and Specialist training in medicine
+        /// and Specialist training in dentistry.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of France
+    /// (cadre national des certifications professionnelles).
+    FranceNQF, "FRA", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/fra" {
+        /// Mastery of basic knowledge.
+        Level1 = "1" => Level1,
+        /// The certificate of basic professional knowledge and skills (CléA).
+        Level2 = "2" => Level2,
+        /// The certificate of professional competence (CAP) and
+        /// the diploma of vocational studies (BEP).
+        Level3 = "3" => Level3,
+        /// The general, technological and vocational baccalaureates
+        /// and the professional certificate (BP).
+        Level4 = "4" => Level4,
+        /// The advanced technician’s certificate (BTS) and the
+        /// university diploma of technology (DUT).
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees (licence, licence professionnelle, BUT).
+        Level6 = "6" => Level6,
+        /// Master’s degrees and engineering diplomas.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Greece (HQF).
+    GreeceNQF, "GRC", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/grc" {
+        /// The primary school leaving certificate.
+        Level1 = "1" => Level1,
+        /// The lower secondary school leaving certificate (gymnasio).
+        Level2 = "2" => Level2,
+        /// Certificates of vocational training schools (SEK)
+        /// and vocational lower secondary schools.
+        Level3 = "3" => Level3,
+        /// General and vocational upper secondary school leaving certificates (lykeio).
+        Level4 = "4" => Level4,
+        /// Diplomas of post-secondary vocational training institutes (IEK)
+        /// after certification.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Hungary (MKKR).
+    HungaryNQF, "HUN", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/hun" {
+        /// The completion of the first four grades of primary school.
+        Level1 = "1" => Level1,
+        /// The completion of the eight grades of primary school.
+        Level2 = "2" => Level2,
+        /// Partial vocational qualifications and bridging programmes.
+        Level3 = "3" => Level3,
+        /// The secondary school leaving examination (érettségi)
+        /// and vocational qualifications.
+        Level4 = "4" => Level4,
+        /// Higher vocational qualifications and short-cycle higher education.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees and postgraduate specialist training.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Iceland (ISQF).
+    /// Level 7 is divided into sublevels.
+    IcelandNQF, "ISL", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/isl" {
+        /// Compulsory school and short courses of adult education.
+        Level1 = "1" => Level1,
+        /// Short vocational programmes and introductory upper secondary studies.
+        Level2 = "2" => Level2,
+        /// Vocational programmes leading to certified occupations.
+        Level3 = "3" => Level3,
+        /// The matriculation examination and journeyman’s examinations.
+        Level4 = "4" => Level4,
+        /// Master craftsperson qualifications and diplomas of short-cycle
+        /// higher education.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7_1 = "7.1" => Level7,
+        /// Doctoral degrees.
+        Level7_2 = "7.2" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national framework of qualifications levels of Ireland (NFQ).
+    /// The framework has ten levels.
+    IrelandNQF, "IRL", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/irl" {
+        /// Level 1 certificates.
+        Level1 = "1" => Level1,
+        /// Level 2 certificates.
+        Level2 = "2" => Level1,
+        /// Level 3 certificates and the Junior Certificate.
+        Level3 = "3" => Level2,
+        /// Level 4 certificates and the Leaving Certificate.
+        Level4 = "4" => Level3,
+        /// Level 5 certificates and the Leaving Certificate.
+        Level5 = "5" => Level4,
+        /// Advanced certificates and higher certificates.
+        Level6 = "6" => Level5,
+        /// Ordinary bachelor’s degrees.
+        Level7 = "7" => Level6,
+        /// Honours bachelor’s degrees and higher diplomas.
+        Level8 = "8" => Level6,
+        /// Master’s degrees and postgraduate diplomas.
+        Level9 = "9" => Level7,
+        /// Doctoral degrees and higher doctorates.
+        Level10 = "10" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Italy (QNQ).
+    ItalyNQF, "ITA", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/ita" {
+        /// The lower secondary school leaving diploma.
+        Level1 = "1" => Level1,
+        /// The certificate of the completion of compulsory education.
+        Level2 = "2" => Level2,
+        /// Vocational qualifications of three-year regional programmes (operatore).
+        Level3 = "3" => Level3,
+        /// Upper secondary school leaving diplomas and
+        /// four-year regional vocational diplomas (tecnico).
+        Level4 = "4" => Level4,
+        /// Diplomas of higher technical institutes (ITS).
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees (laurea) and first level diplomas of higher
+        /// education in the arts and music (AFAM).
+        Level6 = "6" => Level6,
+        /// Master’s degrees (laurea magistrale) and first level university masters.
+        Level7 = "7" => Level7,
+        /// Research doctorates and second level specialisation diplomas.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Latvia (LKI).
+    LatviaNQF, "LVA", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/lva" {
+        /// Special basic education programmes.
+        Level1 = "1" => Level1,
+        /// Basic education and the first level of professional qualification.
+        Level2 = "2" => Level2,
+        /// Vocational education and the second level of professional qualification.
+        Level3 = "3" => Level3,
+        /// General secondary education, vocational secondary education and
+        /// the third level of professional qualification.
+        Level4 = "4" => Level4,
+        /// First level professional higher education (college) and
+        /// the fourth level of professional qualification.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees, professional bachelor’s degrees and
+        /// second level professional higher education.
+        Level6 = "6" => Level6,
+        /// Master’s degrees, professional master’s degrees and second level
+        /// professional higher education of at least five years.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Liechtenstein (NQFL).
+    LiechtensteinNQF, "LIE", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/lie" {
+        /// Compulsory schooling in primary school.
+        Level1 = "1" => Level1,
+        /// Compulsory schooling in lower secondary school.
+        Level2 = "2" => Level2,
+        /// Federal vocational education and training certificates (EBA).
+        Level3 = "3" => Level3,
+        /// Federal vocational education and training diplomas (EFZ)
+        /// and the baccalaureate (Matura).
+        Level4 = "4" => Level4,
+        /// Federal diplomas of professional education.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees and advanced federal diplomas of professional education.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Lithuania (LTKS).
+    LithuaniaNQF, "LTU", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/ltu" {
+        /// Primary education and vocational training of level 1.
+        Level1 = "1" => Level1,
+        /// Lower secondary education and vocational training of level 2.
+        Level2 = "2" => Level2,
+        /// Lower secondary education with vocational training of level 3.
+        Level3 = "3" => Level3,
+        /// The matura examination and vocational training of level 4.
+        Level4 = "4" => Level4,
+        /// Vocational training of level 5.
+        Level5 = "5" => Level5,
+        /// Bachelor’s and professional bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Luxembourg (CLQ).
+    LuxembourgNQF, "LUX", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/lux" {
+        /// The certificate of basic education.
+        Level1 = "1" => Level1,
+        /// The certificate of professional capacity (CCP).
+        Level2 = "2" => Level2,
+        /// The diploma of professional aptitude (DAP).
+        Level3 = "3" => Level3,
+        /// Diplomas of general and technical secondary education.
+        Level4 = "4" => Level4,
+        /// The advanced technician’s certificate (BTS) and the master craftsperson’s diploma.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of North Macedonia (MQF).
+    /// Levels 5 to 7 are divided into sublevels.
+    NorthMacedoniaNQF, "MKD", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/mkd" {
+        /// Primary education.
+        Level1 = "I" => Level1,
+        /// Vocational training of up to two years.
+        Level2 = "II" => Level2,
+        /// Three-year vocational education.
+        Level3 = "III" => Level3,
+        /// Four-year secondary general and vocational education.
+        Level4 = "IV" => Level4,
+        /// Post-secondary vocational education and specialist education.
+        Level5A = "V-A" => Level5,
+        /// Short-cycle higher education.
+        Level5B = "V-B" => Level5,
+        /// Bachelor’s degrees of 180 ECTS credits.
+        Level6A = "VI-A" => Level6,
+        /// Bachelor’s degrees of 240 ECTS credits and specialist
+        /// higher vocational studies.
+        Level6B = "VI-B" => Level6,
+        /// Master’s degrees of 60 ECTS credits and specialist studies.
+        Level7A = "VII-A" => Level7,
+        /// Master’s degrees of 120 ECTS credits.
+        Level7B = "VII-B" => Level7,
+        /// Doctoral degrees.
+        Level8 = "VIII" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Malta (MQF).
+    /// The introductory levels are below the first EQF level.
+    MaltaNQF, "MLT", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/mlt" {
+        /// Introductory level A qualifications.
+        IntroductoryLevelA = "A",
+        /// Introductory level B qualifications.
+        IntroductoryLevelB = "B",
+        /// The school leaving certificate and level 1 certificates.
+        Level1 = "1" => Level1,
+        /// Basic vocational education and training and the secondary
+        /// education certificate at grades 6–7.
+        Level2 = "2" => Level2,
+        /// Vocational education and training diplomas and the secondary
+        /// education certificate at grades 1–5.
+        Level3 = "3" => Level3,
+        /// The matriculation certificate, advanced level examinations
+        /// and vocational diplomas.
+        Level4 = "4" => Level4,
+        /// Undergraduate certificates and diplomas and vocational
+        /// education and training higher diplomas.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees and postgraduate diplomas.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Montenegro (NQF).
+    /// Level 7 is divided into sublevels.
+    MontenegroNQF, "MNE", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/mne" {
+        /// Primary education for adults and basic vocational training.
+        Level1 = "I" => Level1,
+        /// Lower vocational education.
+        Level2 = "II" => Level2,
+        /// Three-year secondary vocational education.
+        Level3 = "III" => Level3,
+        /// Four-year secondary general and vocational education.
+        Level4 = "IV" => Level4,
+        /// Post-secondary vocational education.
+        Level5 = "V" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "VI" => Level6,
+        /// Specialist studies after a bachelor’s degree.
+        Level7_1 = "VII1" => Level7,
+        /// Master’s degrees.
+        Level7_2 = "VII2" => Level7,
+        /// Doctoral degrees.
+        Level8 = "VIII" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of the Netherlands (NLQF).
+    /// The entry level is below the first EQF level.
+    NetherlandsNQF, "NLD", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/nld" {
+        /// The entry level (instapniveau), such as the entry level
+        /// vocational qualification (MBO entree).
+        EntryLevel = "entry",
+        /// Primary education and practical training.
+        Level1 = "1" => Level1,
+        /// Basic vocational education (MBO 2) and
+        /// pre-vocational secondary education (VMBO).
+        Level2 = "2" => Level2,
+        /// Vocational education of level MBO 3.
+        Level3 = "3" => Level3,
+        /// Vocational education of level MBO 4 and senior general
+        /// secondary education (HAVO) and pre-university education (VWO).
+        Level4 = "4" => Level4,
+        /// Associate degrees.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Norway (NKR).
+    /// Level 5 is divided into sublevels.
+    NorwayNQF, "NOR", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/nor" {
+        /// Primary school.
+        Level1 = "1" => Level1,
+        /// Lower secondary school.
+        Level2 = "2" => Level2,
+        /// Upper secondary vocational training of level 3.
+        Level3 = "3" => Level3,
+        /// The general university admissions certification, trade and
+        /// journeyman’s certificates and other upper secondary qualifications.
+        Level4 = "4" => Level4,
+        /// Tertiary vocational education of 30 to 90 credits (fagskole).
+        Level5_1 = "5.1" => Level5,
+        /// Tertiary vocational education of 90 to 120 credits (fagskole).
+        Level5_2 = "5.2" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// PhD degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Poland (PQF).
+    PolandNQF, "POL", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/pol" {
+        /// Primary school leaving certificates of special schools.
+        Level1 = "1" => Level1,
+        /// Primary school leaving certificates.
+        Level2 = "2" => Level2,
+        /// Vocational qualifications of stage I sectoral vocational schools.
+        Level3 = "3" => Level3,
+        /// The matura certificate and vocational diplomas.
+        Level4 = "4" => Level4,
+        /// Short-cycle higher education and master craftsperson’s diplomas.
+        Level5 = "5" => Level5,
+        /// Bachelor’s and engineer’s degrees (licencjat, inżynier).
+        Level6 = "6" => Level6,
+        /// Master’s degrees (magister).
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Portugal (QNQ).
+    PortugalNQF, "PRT", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/prt" {
+        /// The second cycle of basic education.
+        Level1 = "1" => Level1,
+        /// The third cycle of basic education.
+        Level2 = "2" => Level2,
+        /// Secondary education.
+        Level3 = "3" => Level3,
+        /// Secondary education with professional certification.
+        Level4 = "4" => Level4,
+        /// Technological specialisation courses and
+        /// higher professional technical courses (CTeSP).
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees (licenciatura).
+        Level6 = "6" => Level6,
+        /// Master’s degrees (mestrado).
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Romania (CNC).
+    RomaniaNQF, "ROU", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/rou" {
+        /// Primary education and vocational training of level 1.
+        Level1 = "1" => Level1,
+        /// Lower secondary education and vocational training of level 2.
+        Level2 = "2" => Level2,
+        /// Professional schools and vocational training of level 3.
+        Level3 = "3" => Level3,
+        /// The baccalaureate and technological upper secondary education.
+        Level4 = "4" => Level4,
+        /// Post-secondary non-tertiary education.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Serbia (NQFS).
+    /// Levels 6 and 7 are divided into sublevels.
+    SerbiaNQF, "SRB", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/srb" {
+        /// Primary education and vocational training.
+        Level1 = "1" => Level1,
+        /// Vocational training and education of up to two years.
+        Level2 = "2" => Level2,
+        /// Three-year secondary vocational education.
+        Level3 = "3" => Level3,
+        /// Four-year secondary general, art and vocational education.
+        Level4 = "4" => Level4,
+        /// Master craftsperson and specialist education.
+        Level5 = "5" => Level5,
+        /// Basic academic and vocational studies of 180 ECTS credits.
+        Level6_1 = "6.1" => Level6,
+        /// Basic academic and vocational studies of 240 ECTS credits
+        /// and specialist vocational studies.
+        Level6_2 = "6.2" => Level6,
+        /// Integrated academic studies, master academic studies
+        /// and master vocational studies.
+        Level7_1 = "7.1" => Level7,
+        /// Specialist academic studies.
+        Level7_2 = "7.2" => Level7,
+        /// Doctoral studies.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Slovakia (SKKR).
+    SlovakiaNQF, "SVK", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/svk" {
+        /// Primary education.
+        Level1 = "1" => Level1,
+        /// Lower secondary education.
+        Level2 = "2" => Level2,
+        /// Lower secondary vocational education.
+        Level3 = "3" => Level3,
+        /// Upper secondary education with the school leaving examination (maturita).
+        Level4 = "4" => Level4,
+        /// Higher professional education.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s and engineer’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Slovenia (SOK).
+    /// The framework has ten levels.
+    SloveniaNQF, "SVN", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/svn" {
+        /// Completion of the first six years of basic school.
+        Level1 = "1" => Level1,
+        /// Basic school.
+        Level2 = "2" => Level2,
+        /// Lower vocational education.
+        Level3 = "3" => Level3,
+        /// Secondary vocational education.
+        Level4 = "4" => Level4,
+        /// Secondary technical and general education with the matura.
+        Level5 = "5" => Level4,
+        /// Short-cycle higher vocational education.
+        Level6 = "6" => Level5,
+        /// Professional and academic bachelor’s degrees.
+        Level7 = "7" => Level6,
+        /// Master’s degrees.
+        Level8 = "8" => Level7,
+        /// Specialisations after a master’s degree and former
+        /// master’s degrees of science.
+        Level9 = "9" => Level8,
+        /// Doctoral degrees.
+        Level10 = "10" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Sweden (SeQF).
+    SwedenNQF, "SWE", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/swe" {
+        /// Compulsory school for pupils with learning disabilities.
+        Level1 = "1" => Level1,
+        /// Compulsory school.
+        Level2 = "2" => Level2,
+        /// Upper secondary school for pupils with learning disabilities.
+        Level3 = "3" => Level3,
+        /// The upper secondary school diploma (gymnasieexamen).
+        Level4 = "4" => Level4,
+        /// Higher vocational education diplomas (yrkeshögskoleexamen)
+        /// and university diplomas of at least 120 credits.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees and advanced higher vocational education diplomas.
+        Level6 = "6" => Level6,
+        /// Master’s degrees (magisterexamen, masterexamen).
+        Level7 = "7" => Level7,
+        /// Licentiate and doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Switzerland
+    /// for vocational and professional education and training (NQF VPET).
+    SwitzerlandNQF, "CHE", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/che" {
+        /// Basic vocational training below the federal certificate.
+        Level1 = "1" => Level1,
+        /// Vocational training preparation.
+        Level2 = "2" => Level2,
+        /// Federal vocational education and training certificates (EBA).
+        Level3 = "3" => Level3,
+        /// Federal vocational education and training diplomas (EFZ).
+        Level4 = "4" => Level4,
+        /// Federal diplomas of professional education.
+        Level5 = "5" => Level5,
+        /// Advanced federal diplomas of professional education and
+        /// diplomas of colleges of higher education.
+        Level6 = "6" => Level6,
+        /// Advanced federal diplomas of professional education of level 7.
+        Level7 = "7" => Level7,
+        /// Advanced federal diplomas of professional education of level 8.
+        Level8 = "8" => Level8,
+    }
+}
+
+national_framework! {
+    /// The national qualification framework levels of Turkey (TQF).
+    TurkeyNQF, "TUR", "https://github.com/SeSodesa/map-to-europass-credential/vocabularies/nqf/tur" {
+        /// Primary education.
+        Level1 = "1" => Level1,
+        /// Lower secondary education and vocational qualifications of level 2.
+        Level2 = "2" => Level2,
+        /// Vocational qualifications of level 3.
+        Level3 = "3" => Level3,
+        /// General and vocational upper secondary education.
+        Level4 = "4" => Level4,
+        /// Associate degrees.
+        Level5 = "5" => Level5,
+        /// Bachelor’s degrees.
+        Level6 = "6" => Level6,
+        /// Master’s degrees.
+        Level7 = "7" => Level7,
+        /// Doctoral degrees.
+        Level8 = "8" => Level8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::national_qualification_frameworks::*;
+    use crate::european_qualifications_framework::EQFLevel;
+    use crate::controlled_vocabularies::STAND_IN_CONCEPT_NAMESPACE;
+
+    #[test]
+    fn nqf_level_identifiers () {
+        let level = NQF::Finland(FinlandNQF::Level6);
+        assert_eq!(level.notation(), "6");
+        assert_eq!(level.uri(), format!("{}nqf/fin/6", STAND_IN_CONCEPT_NAMESPACE));
+        assert_eq!(level.country_uri(), "http://publications.europa.eu/resource/authority/country/FIN");
+        assert!(level.description().starts_with("Bachelor’s degrees at universities of applied sciences"));
+        assert_eq!(CroatiaNQF::from_notation("4.2"), Some(CroatiaNQF::Level4_2));
+        assert_eq!(NQF::Croatia(CroatiaNQF::Level4_2).uri(), format!("{}nqf/hrv/4.2", STAND_IN_CONCEPT_NAMESPACE));
+        assert!(NationalQualificationLevel::scheme_uri(&FinlandNQF::Level6).starts_with(STAND_IN_CONCEPT_NAMESPACE));
+    }
+    #[test]
+    fn nqf_levels_reference_eqf_levels () {
+        assert_eq!(NQF::Sweden(SwedenNQF::Level7).eqf_level(), Some(EQFLevel::Level7));
+        assert_eq!(NQF::Ireland(IrelandNQF::Level8).eqf_level(), Some(EQFLevel::Level6));
+        assert_eq!(NQF::Slovenia(SloveniaNQF::Level10).eqf_level(), Some(EQFLevel::Level8));
+        // Levels below the first EQF level are not referenced.
+        assert_eq!(NQF::Netherlands(NetherlandsNQF::EntryLevel).eqf_level(), None);
+        // Every level has a description and a unique notation.
+        for level in IrelandNQF::LEVELS {
+            assert!(!level.description().is_empty());
+            assert_eq!(IrelandNQF::from_notation(level.notation()), Some(*level));
+        }
+    }
+    #[test]
+    fn eqf_levels_to_nqf_levels () {
+        assert_eq!(NQF::from_eqf_level("FIN", EQFLevel::Level6), vec![NQF::Finland(FinlandNQF::Level6)]);
+        // Several levels and sublevels may be referenced to the same EQF level.
+        assert_eq!(
+            NQF::from_eqf_level("http://publications.europa.eu/resource/authority/country/IRL", EQFLevel::Level6),
+            vec![NQF::Ireland(IrelandNQF::Level7), NQF::Ireland(IrelandNQF::Level8)]
+        );
+        assert_eq!(CroatiaNQF::from_eqf_level(EQFLevel::Level4), vec![CroatiaNQF::Level4_1, CroatiaNQF::Level4_2]);
+        assert!(NQF::from_eqf_level("FIN", EQFLevel::Level1).is_empty());
+        // Every level maps back to itself through each of its EQF levels.
+        for level in NQF::levels() {
+            for eqf_level in level.eqf_levels() {
+                let country = level.country_uri();
+                assert!(NQF::from_eqf_level(country, *eqf_level).contains(&level));
+            }
+        }
+    }
+    #[test]
+    fn nqf_levels_spanning_eqf_levels () {
+        national_framework! {
+            /// A framework with a level spanning two EQF levels.
+            SpanningNQF, "XXX", "http://example.org/nqf" {
+                /// Short-cycle and first cycle degrees.
+                Level5 = "5" => Level5 | Level6,
+            }
+        }
+        assert_eq!(SpanningNQF::Level5.referenced_eqf_levels(), &[EQFLevel::Level5, EQFLevel::Level6]);
+        assert_eq!(SpanningNQF::from_eqf_level(EQFLevel::Level6), vec![SpanningNQF::Level5]);
+    }
+    #[test]
+    fn nqf_and_eqf_level_consistency () {
+        let level = NQF::Finland(FinlandNQF::Level6);
+        assert!(level.check_eqf_level(EQFLevel::Level6).is_ok());
+        assert!(level.check_eqf_level(EQFLevel::Level7).is_err());
+        assert!(NQF::Netherlands(NetherlandsNQF::EntryLevel).check_eqf_level(EQFLevel::Level1).is_err());
+    }
+}
@@ -0,0 +1,488 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+/*!
+This submodule checks that the person presenting a credential is its owner,
+by comparing the name and the date of birth of the subject of the credential
+with those of an identity established by other means, such as the
+[eIDAS minimum data set](https://ec.europa.eu/digital-building-blocks/sites/display/DIGITAL/eIDAS+SAML+Attribute+Profile)
+an admissions portal receives when an applicant authenticates with their national eID.
+
+An [`IdentityAssertion`] is read either from the eIDAS attributes of a SAML assertion
+or from a JSON object with the same attributes, by their URIs or their friendly names:
+
+```json
+{
+  "FamilyName": "Müller",
+  "FirstName": "Anna Maria",
+  "DateOfBirth": "1990-12-31",
+  "PersonIdentifier": "DE/FI/1234567"
+}
+```
+
+An attribute may have several values, such as a name in its original script
+followed by its transliteration into the latin script.
+
+Names are compared leniently, as they are spelled differently in different registers:
+they are normalized to Unicode NFKC and compared case-insensitively, transliterated
+into ASCII, with the German and Nordic letters ä, ö, ü, å, ø and æ either
+stripped of their marks or expanded into two letters, and with punctuation
+such as hyphens and apostrophes ignored. Given names match if the first
+given names are the same and one of the lists of given names contains the other,
+as registers often leave out middle names. A credential lacking either its given
+or its family names is compared by its full name, which has to contain the first
+asserted given name and the asserted family name. The dates of birth must be the same.
+*/
+
+use crate::controlled_vocabularies::{VerificationStatus, VerificationType};
+use crate::europass_learning_model::Person;
+use crate::verification::{parse_date_time, CredentialDocument, Verifier};
+use chrono::{DateTime, NaiveDate, Utc};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use unicode_normalization::UnicodeNormalization;
+
+/// The eIDAS natural person attributes used here, as their URIs and friendly names.
+const FAMILY_NAME: (&str, &str) = ("http://eidas.europa.eu/attributes/naturalperson/CurrentFamilyName", "FamilyName");
+const GIVEN_NAME: (&str, &str) = ("http://eidas.europa.eu/attributes/naturalperson/CurrentGivenName", "FirstName");
+const DATE_OF_BIRTH: (&str, &str) = ("http://eidas.europa.eu/attributes/naturalperson/DateOfBirth", "DateOfBirth");
+const PERSON_IDENTIFIER: (&str, &str) =
+    ("http://eidas.europa.eu/attributes/naturalperson/PersonIdentifier", "PersonIdentifier");
+
+/// The identity of a person as asserted by an identity provider.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentityAssertion {
+    /// The given names, possibly in several scripts.
+    given_names: Vec<String>,
+    /// The family names, possibly in several scripts.
+    family_names: Vec<String>,
+    date_of_birth: NaiveDate,
+    /// The eIDAS person identifier, if given.
+    person_identifier: Option<String>,
+}
+
+impl IdentityAssertion {
+    /// Constructs an assertion of the identity of a person with the given names.
+    pub fn new(given_names: &str, family_name: &str, date_of_birth: NaiveDate) -> IdentityAssertion {
+        IdentityAssertion {
+            given_names: vec![given_names.to_string()],
+            family_names: vec![family_name.to_string()],
+            date_of_birth,
+            person_identifier: None,
+        }
+    }
+
+    /// Reads the eIDAS attributes from a JSON object, whose values are strings
+    /// or arrays of strings.
+    pub fn from_eidas_json(json: &str) -> Result<IdentityAssertion, String> {
+        let object: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(json).map_err(|e| format!("Could not parse the eIDAS attributes: {}…", e))?;
+        let mut attributes = Vec::new();
+        for (name, value) in object {
+            match value {
+                serde_json::Value::String(value) => attributes.push((name, value)),
+                serde_json::Value::Array(values) => {
+                    for value in values {
+                        let value = value
+                            .as_str()
+                            .ok_or_else(|| format!("Could not read a value of eIDAS attribute {}…", name))?;
+                        attributes.push((name.clone(), value.to_string()));
+                    }
+                }
+                _ => return Err(format!("Could not read the value of eIDAS attribute {}…", name)),
+            }
+        }
+        IdentityAssertion::from_attributes(attributes)
+    }
+
+    /// Reads the eIDAS attributes from the `Attribute` elements of a SAML assertion
+    /// or of an attribute statement.
+    pub fn from_saml_attributes(xml: &str) -> Result<IdentityAssertion, String> {
+        let mut reader = Reader::from_str(xml);
+        reader.check_end_names(true);
+        let mut buf = Vec::new();
+        let mut attributes = Vec::new();
+        // The names of the attribute being read and the value being read.
+        let mut names: Vec<String> = Vec::new();
+        let mut value: Option<String> = None;
+        loop {
+            let event = reader
+                .read_event(&mut buf)
+                .map_err(|e| format!("Could not read the SAML attributes at {}: {}…", reader.buffer_position(), e))?;
+            match event {
+                Event::Start(ref element) if element.local_name() == b"Attribute" => {
+                    names.clear();
+                    for attribute in element.attributes() {
+                        let attribute = attribute.map_err(|e| e.to_string())?;
+                        if attribute.key == b"Name" || attribute.key == b"FriendlyName" {
+                            names.push(attribute.unescape_and_decode_value(&reader).map_err(|e| e.to_string())?);
+                        }
+                    }
+                }
+                Event::Start(ref element) if element.local_name() == b"AttributeValue" => value = Some(String::new()),
+                Event::Text(ref text) | Event::CData(ref text) => {
+                    if let Some(value) = value.as_mut() {
+                        value.push_str(&text.unescape_and_decode(&reader).map_err(|e| e.to_string())?);
+                    }
+                }
+                Event::End(ref element) if element.local_name() == b"AttributeValue" => {
+                    if let (Some(value), Some(name)) = (value.take(), names.first()) {
+                        attributes.push((name.clone(), value.trim().to_string()));
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        IdentityAssertion::from_attributes(attributes)
+    }
+
+    /// Forms an assertion from attributes named by their URIs or friendly names.
+    fn from_attributes(attributes: Vec<(String, String)>) -> Result<IdentityAssertion, String> {
+        let values = |(uri, friendly_name): (&str, &str)| -> Vec<String> {
+            attributes
+                .iter()
+                .filter(|(name, _)| name == uri || name == friendly_name)
+                .map(|(_, value)| value.clone())
+                .filter(|value| !value.trim().is_empty())
+                .collect()
+        };
+        let required = |attribute: (&str, &str)| {
+            let values = values(attribute);
+            if values.is_empty() {
+                Err(format!("Could not find eIDAS attribute {} in the identity assertion…", attribute.1))
+            } else {
+                Ok(values)
+            }
+        };
+        let date_of_birth = required(DATE_OF_BIRTH)?.remove(0);
+        Ok(IdentityAssertion {
+            given_names: required(GIVEN_NAME)?,
+            family_names: required(FAMILY_NAME)?,
+            date_of_birth: NaiveDate::parse_from_str(date_of_birth.trim(), "%Y-%m-%d")
+                .map_err(|e| format!("Could not read date of birth {}: {}…", date_of_birth, e))?,
+            person_identifier: values(PERSON_IDENTIFIER).into_iter().next(),
+        })
+    }
+
+    /// The given names of the person in the first script they were given in.
+    pub fn given_names(&self) -> &str {
+        &self.given_names[0]
+    }
+
+    /// The family name of the person in the first script it was given in.
+    pub fn family_name(&self) -> &str {
+        &self.family_names[0]
+    }
+
+    /// The date of birth of the person.
+    pub fn date_of_birth(&self) -> NaiveDate {
+        self.date_of_birth
+    }
+
+    /// The eIDAS person identifier, if one was given.
+    pub fn person_identifier(&self) -> Option<&str> {
+        self.person_identifier.as_deref()
+    }
+
+    /// Checks that a person is the asserted person.
+    pub fn check_person(&self, person: &Person) -> (VerificationStatus, String) {
+        let names = |text: &crate::europass_learning_model::Text| -> Vec<String> {
+            text.translations().map(|(_, name)| name.to_string()).collect()
+        };
+        self.status(
+            &names(person.given_names()),
+            &names(person.family_name()),
+            &names(person.full_name()),
+            person.date_of_birth(),
+        )
+    }
+
+    /// The status of the ownership of a credential whose subject has the given names
+    /// and date of birth, with a description.
+    fn status(
+        &self,
+        given_names: &[String],
+        family_names: &[String],
+        full_names: &[String],
+        date_of_birth: Option<NaiveDate>,
+    ) -> (VerificationStatus, String) {
+        let asserted = format!("{} {}", self.given_names(), self.family_name());
+        let date_of_birth = match date_of_birth {
+            Some(date_of_birth) => date_of_birth,
+            None => {
+                return (
+                    VerificationStatus::Gray,
+                    String::from("The credential does not state the date of birth of its subject…"),
+                )
+            }
+        };
+        if date_of_birth != self.date_of_birth {
+            return (
+                VerificationStatus::Red,
+                format!("The date of birth of the subject does not match that of {}…", asserted),
+            );
+        }
+        // The full name is compared unless both the given and the family names are known.
+        let missing = |names: &[String]| names.iter().all(|name| name.trim().is_empty());
+        let name_matches = if missing(given_names) || missing(family_names) {
+            if missing(full_names) {
+                return (VerificationStatus::Gray, String::from("The credential does not name its subject…"));
+            }
+            matches_any(full_names, |full_name| {
+                let full_name = name_forms(full_name);
+                self.given_names.iter().any(|given| contains_first(&full_name, &name_forms(given)))
+                    && self.family_names.iter().any(|family| contains_all(&full_name, &name_forms(family)))
+            })
+        } else {
+            matches_any(given_names, |given| {
+                self.given_names.iter().any(|asserted| given_names_match(&name_forms(asserted), &name_forms(given)))
+            }) && matches_any(family_names, |family| {
+                self.family_names.iter().any(|asserted| same_name(&name_forms(asserted), &name_forms(family)))
+            })
+        };
+        if name_matches {
+            (VerificationStatus::Green, format!("The subject of the credential is {}.", asserted))
+        } else {
+            (VerificationStatus::Red, format!("The name of the subject does not match that of {}…", asserted))
+        }
+    }
+}
+
+fn matches_any<F: Fn(&str) -> bool>(names: &[String], matches: F) -> bool {
+    names.iter().any(|name| matches(name))
+}
+
+/// The forms a name is compared in, as lists of lowercase ASCII words:
+/// the name with the German and Nordic letters stripped of their marks
+/// and with the letters expanded into two letters.
+fn name_forms(name: &str) -> Vec<Vec<String>> {
+    let name: String = name.nfkc().collect::<String>().to_lowercase();
+    let expanded: String = name
+        .chars()
+        .map(|c| match c {
+            'ä' | 'æ' => "ae".to_string(),
+            'ö' | 'ø' => "oe".to_string(),
+            'ü' => "ue".to_string(),
+            'å' => "aa".to_string(),
+            c => c.to_string(),
+        })
+        .collect();
+    let words = |name: &str| -> Vec<String> {
+        deunicode::deunicode(name)
+            .to_lowercase()
+            .replace('\'', "")
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+    let mut forms = vec![words(&name)];
+    let expanded = words(&expanded);
+    if !forms.contains(&expanded) {
+        forms.push(expanded);
+    }
+    forms
+}
+
+/// Whether the two names are the same in some form.
+fn same_name(a: &[Vec<String>], b: &[Vec<String>]) -> bool {
+    a.iter().any(|a| !a.is_empty() && b.contains(a))
+}
+
+/// Whether the given names agree on the first given name
+/// and one of them contains the other in some form.
+fn given_names_match(a: &[Vec<String>], b: &[Vec<String>]) -> bool {
+    a.iter().any(|a| {
+        b.iter().any(|b| {
+            let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+            !shorter.is_empty() && a[0] == b[0] && shorter.iter().all(|word| longer.contains(word))
+        })
+    })
+}
+
+/// Whether a full name contains the first of the given names in some form.
+fn contains_first(full_name: &[Vec<String>], given_names: &[Vec<String>]) -> bool {
+    full_name
+        .iter()
+        .any(|full_name| given_names.iter().any(|given| given.first().is_some_and(|first| full_name.contains(first))))
+}
+
+/// Whether a full name contains every word of a name in some form.
+fn contains_all(full_name: &[Vec<String>], name: &[Vec<String>]) -> bool {
+    full_name.iter().any(|full_name| {
+        name.iter()
+            .any(|name| !name.is_empty() && name.iter().all(|word| full_name.contains(word)))
+    })
+}
+
+/// Checks that the subject of a credential is the person whose identity has been asserted.
+/// The names of the subject are read from `credentialSubject/givenNames/text`,
+/// `credentialSubject/familyName/text` and `credentialSubject/fullName/text`,
+/// and the date of birth from `credentialSubject/dateOfBirth`.
+pub struct OwnerVerifier {
+    identity: IdentityAssertion,
+}
+
+impl OwnerVerifier {
+    /// Constructs a verifier for credentials presented by the asserted person.
+    pub fn new(identity: IdentityAssertion) -> OwnerVerifier {
+        OwnerVerifier { identity }
+    }
+}
+
+impl Verifier for OwnerVerifier {
+    fn check_type(&self) -> VerificationType {
+        VerificationType::Owner
+    }
+
+    fn verify(&self, credential: &CredentialDocument, _as_of: DateTime<Utc>) -> (VerificationStatus, String) {
+        let names = |path: &str| -> Vec<String> { credential.values(path).map(str::to_string).collect() };
+        let date_of_birth = match credential.value("credentialSubject/dateOfBirth").map(parse_date_time) {
+            Some(Ok(date_of_birth)) => Some(date_of_birth.date()),
+            Some(Err(error)) => return (VerificationStatus::Red, error),
+            None => None,
+        };
+        self.identity.status(
+            &names("credentialSubject/givenNames/text"),
+            &names("credentialSubject/familyName/text"),
+            &names("credentialSubject/fullName/text"),
+            date_of_birth,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::europass_learning_model::{EuropeanLanguage, Text};
+    use chrono::TimeZone;
+
+    fn born () -> NaiveDate {
+        NaiveDate::from_ymd_opt(1990, 12, 31).unwrap()
+    }
+
+    #[test]
+    fn identity_assertions () {
+        let from_json = match IdentityAssertion::from_eidas_json(
+            r#"{
+                "FamilyName": ["Петрова", "Petrova"],
+                "http://eidas.europa.eu/attributes/naturalperson/CurrentGivenName": "Анна",
+                "DateOfBirth": "1990-12-31",
+                "PersonIdentifier": "BG/FI/1234567"
+            }"#,
+        ) {
+            Ok(identity) => identity,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(from_json.family_name(), "Петрова");
+        assert_eq!(from_json.date_of_birth(), born());
+        assert_eq!(from_json.person_identifier(), Some("BG/FI/1234567"));
+        let from_saml = match IdentityAssertion::from_saml_attributes(
+            r#"<saml2:AttributeStatement xmlns:saml2="urn:oasis:names:tc:SAML:2.0:assertion">
+                <saml2:Attribute FriendlyName="FamilyName" Name="http://eidas.europa.eu/attributes/naturalperson/CurrentFamilyName">
+                    <saml2:AttributeValue>Петрова</saml2:AttributeValue>
+                    <saml2:AttributeValue>Petrova</saml2:AttributeValue>
+                </saml2:Attribute>
+                <saml2:Attribute FriendlyName="FirstName" Name="http://eidas.europa.eu/attributes/naturalperson/CurrentGivenName">
+                    <saml2:AttributeValue>Анна</saml2:AttributeValue>
+                </saml2:Attribute>
+                <saml2:Attribute FriendlyName="DateOfBirth" Name="http://eidas.europa.eu/attributes/naturalperson/DateOfBirth">
+                    <saml2:AttributeValue>1990-12-31</saml2:AttributeValue>
+                </saml2:Attribute>
+                <saml2:Attribute FriendlyName="PersonIdentifier" Name="http://eidas.europa.eu/attributes/naturalperson/PersonIdentifier">
+                    <saml2:AttributeValue>BG/FI/1234567</saml2:AttributeValue>
+                </saml2:Attribute>
+            </saml2:AttributeStatement>"#,
+        ) {
+            Ok(identity) => identity,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(from_json, from_saml);
+        assert!(IdentityAssertion::from_eidas_json(r#"{"FamilyName": "Petrova", "FirstName": "Anna"}"#).is_err());
+
+        let person = |given: &str, family: &str| {
+            Person::new(
+                "urn:person:1",
+                Text::new(EuropeanLanguage::English, given),
+                Text::new(EuropeanLanguage::English, family),
+                Some(born()),
+            )
+        };
+        assert_eq!(from_json.check_person(&person("Anna", "Petrova")).0, VerificationStatus::Green);
+        assert_eq!(from_json.check_person(&person("Anna", "Ivanova")).0, VerificationStatus::Red);
+    }
+
+    #[test]
+    fn name_matching () {
+        let identity = IdentityAssertion::new("Anna-Maria Sofia", "Müller", born());
+        let status = |given: &str, family: &str| {
+            identity
+                .status(&[given.to_string()], &[family.to_string()], &[], Some(born()))
+                .0
+        };
+        assert_eq!(status("Anna-Maria Sofia", "Müller"), VerificationStatus::Green);
+        assert_eq!(status("ANNA MARIA", "MUELLER"), VerificationStatus::Green);
+        // The umlaut as a combining diaeresis.
+        assert_eq!(status("Anna Maria", "Mu\u{308}ller"), VerificationStatus::Green);
+        assert_eq!(status("anna", "muller"), VerificationStatus::Green);
+        assert_eq!(status("Maria", "Müller"), VerificationStatus::Red);
+        assert_eq!(status("Anna-Maria Sofia", "Miller"), VerificationStatus::Red);
+        let full_name = |name: &str| identity.status(&[], &[], &[name.to_string()], Some(born())).0;
+        assert_eq!(full_name("Anna Müller"), VerificationStatus::Green);
+        assert_eq!(full_name("Anna Smith"), VerificationStatus::Red);
+        // A missing given or family name falls back to the full name.
+        let full_name_without = |given: &str, family: &str, name: &str| {
+            let names = |name: &str| if name.is_empty() { vec![] } else { vec![name.to_string()] };
+            identity.status(&names(given), &names(family), &names(name), Some(born())).0
+        };
+        assert_eq!(full_name_without("", "Müller", "Anna Müller"), VerificationStatus::Green);
+        assert_eq!(full_name_without("Anna", "", "Anna Smith"), VerificationStatus::Red);
+        assert_eq!(full_name_without("", "Müller", ""), VerificationStatus::Gray);
+
+        let identity = IdentityAssertion::new("Søren", "O’Brien", born());
+        let status = |given: &str, family: &str, date_of_birth| {
+            identity
+                .status(&[given.to_string()], &[family.to_string()], &[], date_of_birth)
+                .0
+        };
+        assert_eq!(status("Soeren", "OBrien", Some(born())), VerificationStatus::Green);
+        assert_eq!(status("Soren", "O'Brien", Some(born())), VerificationStatus::Green);
+        assert_eq!(status("Søren", "O’Brien", born().succ_opt()), VerificationStatus::Red);
+        assert_eq!(status("Søren", "O’Brien", None), VerificationStatus::Gray);
+    }
+
+    #[test]
+    fn owner_verification () {
+        let verifier = OwnerVerifier::new(IdentityAssertion::new("Иван", "Петров", born()));
+        let credential = |subject: &str| {
+            let xml = format!(
+                "<europassCredential xmlns=\"http://data.europa.eu/snb\" id=\"urn:credential:1\">\
+                <credentialSubject id=\"urn:person:1\">{}</credentialSubject></europassCredential>",
+                subject
+            );
+            match CredentialDocument::parse(&xml) {
+                Ok(credential) => credential,
+                Err(e) => panic!("{}", e),
+            }
+        };
+        let as_of = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let status = |subject: &str| verifier.verify(&credential(subject), as_of).0;
+        assert_eq!(
+            status(
+                "<givenNames><text lang=\"en\">Ivan</text></givenNames>\
+                <familyName><text lang=\"en\">Petrov</text></familyName><dateOfBirth>1990-12-31</dateOfBirth>"
+            ),
+            VerificationStatus::Green
+        );
+        assert_eq!(
+            status("<fullName><text lang=\"en\">Ivan Petrov</text></fullName><dateOfBirth>1990-12-31</dateOfBirth>"),
+            VerificationStatus::Green
+        );
+        assert_eq!(
+            status("<fullName><text lang=\"en\">Ivan Petrov</text></fullName><dateOfBirth>1991-12-31</dateOfBirth>"),
+            VerificationStatus::Red
+        );
+        assert_eq!(status("<dateOfBirth>1990-12-31</dateOfBirth>"), VerificationStatus::Gray);
+    }
+}
@@ -0,0 +1,496 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+/*!
+This submodule keeps track of the credentials an institution has revoked.
+
+Revocations are recorded in a local [`RevocationRegistry`], which is
+persisted as a JSON file. The registry is published as a revocation list,
+an XML document sealed with the institutional key like the credentials themselves:
+
+```xml
+<revocationList id="https://example.org/revocations" issuer="https://example.org" published="2025-06-01T12:00:00Z">
+  <revokedCredential id="urn:credential:1" revoked="2025-05-31T09:00:00Z">
+    <reason>The attainment was misregistered.</reason>
+  </revokedCredential>
+  <ds:Signature>…</ds:Signature>
+</revocationList>
+```
+
+Verifiers read a published list with [`RevocationList::parse`],
+which accepts the list only if its seal is valid against a trust store,
+and check credentials against it with a [`RevocationVerifier`].
+A list speaks only for the credentials of its issuer: those naming it as
+their issuer and sealed with the same certificate subject as the list.
+Naming the issuer is not enough, as anyone can name any issuer, and
+a trust store may trust the seals of several institutions.
+*/
+
+use crate::controlled_vocabularies::{VerificationStatus, VerificationType};
+use crate::seal::{check_seal, seal, sealed_by, SealingKey, TrustStore};
+use crate::verification::{CredentialDocument, Verifier};
+use chrono::{DateTime, SecondsFormat, Utc};
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// The revocation of a single credential.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocationRecord {
+    /// The identifier of the revoked credential.
+    credential_id: String,
+    /// The time of the revocation.
+    revoked_at: DateTime<Utc>,
+    /// The reason of the revocation.
+    reason: String,
+}
+
+impl RevocationRecord {
+    /// The identifier of the revoked credential.
+    pub fn credential_id(&self) -> &str {
+        &self.credential_id
+    }
+
+    /// The time of the revocation.
+    pub fn revoked_at(&self) -> DateTime<Utc> {
+        self.revoked_at
+    }
+
+    /// The reason of the revocation.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+/// The revocations issued by an institution.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RevocationRegistry {
+    /// The revocations in the order they were issued.
+    records: Vec<RevocationRecord>,
+}
+
+impl RevocationRegistry {
+    /// Constructs an empty registry.
+    pub fn new() -> RevocationRegistry {
+        RevocationRegistry::default()
+    }
+
+    /// Reads a registry from its JSON file. A missing file is an empty registry.
+    pub fn load(path: &Path) -> Result<RevocationRegistry, String> {
+        if !path.exists() {
+            return Ok(RevocationRegistry::new());
+        }
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read revocation registry {}: {}…", path.display(), e))?;
+        serde_json::from_str(&json)
+            .map_err(|e| format!("Could not parse revocation registry {}: {}…", path.display(), e))
+    }
+
+    /// Writes the registry to its JSON file. The file is replaced only once
+    /// the new content has been written in full.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Could not serialize revocation registry: {}…", e))?;
+        let temporary = path.with_extension("tmp");
+        fs::write(&temporary, json)
+            .map_err(|e| format!("Could not write revocation registry {}: {}…", temporary.display(), e))?;
+        fs::rename(&temporary, path)
+            .map_err(|e| format!("Could not replace revocation registry {}: {}…", path.display(), e))
+    }
+
+    /// Revokes a credential at the given time. A credential can be revoked only once.
+    pub fn revoke(
+        &mut self,
+        credential_id: &str,
+        reason: &str,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<&RevocationRecord, String> {
+        if credential_id.trim().is_empty() {
+            return Err(String::from("Could not revoke a credential without an identifier…"));
+        }
+        if let Some(record) = self.revocation(credential_id) {
+            return Err(format!(
+                "Could not revoke credential {} again: it was revoked at {}…",
+                credential_id,
+                record.revoked_at.to_rfc3339_opts(SecondsFormat::Secs, true)
+            ));
+        }
+        self.records.push(RevocationRecord {
+            credential_id: credential_id.to_string(),
+            revoked_at,
+            reason: reason.to_string(),
+        });
+        Ok(self.records.last().expect("A revocation was just recorded"))
+    }
+
+    /// The revocation of a credential, if it has been revoked.
+    pub fn revocation(&self, credential_id: &str) -> Option<&RevocationRecord> {
+        self.records.iter().find(|record| record.credential_id == credential_id)
+    }
+
+    /// All revocations in the order they were issued.
+    pub fn records(&self) -> &[RevocationRecord] {
+        &self.records
+    }
+
+    /// Publishes the registry as a revocation list of the issuer sealed at `published_at`.
+    /// The list is identified by `list_id`, which is where it is published.
+    pub fn publish(
+        &self,
+        list_id: &str,
+        issuer: &str,
+        key: &SealingKey,
+        published_at: DateTime<Utc>,
+    ) -> Result<String, String> {
+        let text = |value: &str| String::from_utf8_lossy(&escape(value.as_bytes())).into_owned();
+        let time = |time: DateTime<Utc>| time.to_rfc3339_opts(SecondsFormat::Secs, true);
+        let mut xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<revocationList id=\"{}\" issuer=\"{}\" published=\"{}\">\n",
+            text(list_id),
+            text(issuer),
+            time(published_at)
+        );
+        for record in &self.records {
+            xml.push_str(&format!(
+                "<revokedCredential id=\"{}\" revoked=\"{}\"><reason>{}</reason></revokedCredential>\n",
+                text(&record.credential_id),
+                time(record.revoked_at),
+                text(&record.reason)
+            ));
+        }
+        xml.push_str("</revocationList>\n");
+        seal(&xml, key, published_at)
+    }
+}
+
+/// A published revocation list whose seal has been verified.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RevocationList {
+    id: String,
+    issuer: String,
+    published: DateTime<Utc>,
+    /// The subject of the certificate the list was sealed with.
+    sealed_by: String,
+    records: Vec<RevocationRecord>,
+}
+
+fn attribute(element: &BytesStart, name: &[u8]) -> Result<String, String> {
+    for attribute in element.attributes() {
+        let attribute = attribute.map_err(|e| e.to_string())?;
+        if attribute.key == name {
+            let value = attribute.unescaped_value().map_err(|e| e.to_string())?;
+            return String::from_utf8(value.into_owned()).map_err(|e| e.to_string());
+        }
+    }
+    Err(format!(
+        "Element {} of the revocation list has no attribute {}…",
+        String::from_utf8_lossy(element.name()),
+        String::from_utf8_lossy(name)
+    ))
+}
+
+fn parse_time(value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&Utc))
+        .map_err(|e| format!("Could not read time {} of the revocation list: {}…", value, e))
+}
+
+impl RevocationList {
+    /// Reads a published revocation list, whose seal must be valid
+    /// at `as_of` against the trust store. Only the `revokedCredential` children
+    /// of the `revocationList` document element are read, never elements
+    /// placed inside its seal.
+    pub fn parse(xml: &str, trust_store: &TrustStore, as_of: DateTime<Utc>) -> Result<RevocationList, String> {
+        let seal = check_seal(xml, trust_store, as_of).map_err(|e| format!("Could not trust the revocation list: {}", e))?;
+        let mut reader = Reader::from_str(xml);
+        reader.check_end_names(true);
+        let mut buf = Vec::new();
+        let mut ns_buf = Vec::new();
+        let mut list: Option<RevocationList> = None;
+        let mut reason: Option<String> = None;
+        // The names of the open elements that are part of the list,
+        // or `None` for the seal and any other elements.
+        let mut open: Vec<Option<&[u8]>> = Vec::new();
+        loop {
+            let (namespace, event) = reader
+                .read_namespaced_event(&mut buf, &mut ns_buf)
+                .map_err(|e| format!("Could not read the revocation list at {}: {}…", reader.buffer_position(), e))?;
+            match event {
+                Event::Start(ref element) | Event::Empty(ref element) => {
+                    let name: Option<&[u8]> = match (open.as_slice(), namespace, element.local_name()) {
+                        ([], None, b"revocationList") => {
+                            list = Some(RevocationList {
+                                id: attribute(element, b"id")?,
+                                issuer: attribute(element, b"issuer")?,
+                                published: parse_time(&attribute(element, b"published")?)?,
+                                sealed_by: seal.sealed_by.clone(),
+                                records: Vec::new(),
+                            });
+                            Some(b"revocationList")
+                        }
+                        ([], _, _) => return Err(String::from("Could not find a revocation list…")),
+                        ([Some(b"revocationList")], None, b"revokedCredential") => {
+                            if let Some(list) = list.as_mut() {
+                                list.records.push(RevocationRecord {
+                                    credential_id: attribute(element, b"id")?,
+                                    revoked_at: parse_time(&attribute(element, b"revoked")?)?,
+                                    reason: String::new(),
+                                })
+                            }
+                            Some(b"revokedCredential")
+                        }
+                        ([Some(b"revocationList"), Some(b"revokedCredential")], None, b"reason") => {
+                            reason = Some(String::new());
+                            Some(b"reason")
+                        }
+                        _ => None,
+                    };
+                    if matches!(event, Event::Start(_)) {
+                        open.push(name);
+                    } else if name == Some(b"reason") {
+                        reason = None;
+                    }
+                }
+                Event::Text(ref text) if open.last() == Some(&Some(b"reason")) => {
+                    if let Some(reason) = reason.as_mut() {
+                        reason.push_str(&text.unescape_and_decode(&reader).map_err(|e| e.to_string())?);
+                    }
+                }
+                Event::End(_) => {
+                    if let (Some(Some(b"reason")), Some(record)) =
+                        (open.pop(), list.as_mut().and_then(|list| list.records.last_mut()))
+                    {
+                        record.reason = reason.take().unwrap_or_default().trim().to_string();
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        list.ok_or_else(|| String::from("Could not find a revocation list…"))
+    }
+
+    /// The identifier of the list.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The issuer whose credentials the list revokes.
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// The time the list was published.
+    pub fn published(&self) -> DateTime<Utc> {
+        self.published
+    }
+
+    /// The subject of the certificate the list was sealed with.
+    pub fn sealed_by(&self) -> &str {
+        &self.sealed_by
+    }
+
+    /// Whether the list covers a credential, that is whether the credential names
+    /// the issuer of the list and was sealed by the sealer of the list.
+    /// The seal of the credential itself is checked by the seal verifier.
+    pub fn covers(&self, credential: &CredentialDocument) -> bool {
+        credential.value("issuer/@idref") == Some(self.issuer.as_str())
+            && sealed_by(credential.xml()).as_deref() == Some(self.sealed_by.as_str())
+    }
+
+    /// The revocation of a credential, if the list revokes it.
+    pub fn revocation(&self, credential_id: &str) -> Option<&RevocationRecord> {
+        self.records.iter().find(|record| record.credential_id == credential_id)
+    }
+}
+
+/// Checks credentials against a published revocation list.
+pub struct RevocationVerifier {
+    list: RevocationList,
+}
+
+impl RevocationVerifier {
+    /// Constructs a verifier for a revocation list that has been read.
+    pub fn new(list: RevocationList) -> RevocationVerifier {
+        RevocationVerifier { list }
+    }
+}
+
+impl Verifier for RevocationVerifier {
+    fn check_type(&self) -> VerificationType {
+        VerificationType::Revocation
+    }
+
+    fn verify(&self, credential: &CredentialDocument, as_of: DateTime<Utc>) -> (VerificationStatus, String) {
+        let id = match credential.id() {
+            Some(id) => id,
+            None => return (VerificationStatus::Gray, String::from("The credential has no identifier to look up…")),
+        };
+        if !self.list.covers(credential) {
+            return (
+                VerificationStatus::Gray,
+                format!(
+                    "The revocation list {} does not cover the credential: it was not issued by {} and sealed by {}…",
+                    self.list.id, self.list.issuer, self.list.sealed_by
+                ),
+            );
+        }
+        match self.list.revocation(id) {
+            Some(record) if record.revoked_at <= as_of => (
+                VerificationStatus::Red,
+                format!(
+                    "The credential was revoked at {}: {}…",
+                    record.revoked_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+                    record.reason.trim_end_matches('.')
+                ),
+            ),
+            _ if self.list.published > as_of => (
+                VerificationStatus::Gray,
+                String::from("The revocation list was published after the time of verification…"),
+            ),
+            _ => (
+                VerificationStatus::Green,
+                format!(
+                    "The credential is not on the revocation list {} published at {}.",
+                    self.list.id,
+                    self.list.published.to_rfc3339_opts(SecondsFormat::Secs, true)
+                ),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::verification::VerificationPipeline;
+    use chrono::TimeZone;
+
+    fn time(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, day, 12, 0, 0).unwrap()
+    }
+
+    fn key() -> SealingKey {
+        match SealingKey::from_pem(
+            include_str!("../test_data/seal/seal-rsa.key"),
+            include_str!("../test_data/seal/seal-rsa.pem"),
+        ) {
+            Ok(k) => k,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn other_key() -> SealingKey {
+        match SealingKey::from_pem(
+            include_str!("../test_data/seal/seal-ec.key"),
+            include_str!("../test_data/seal/seal-ec.pem"),
+        ) {
+            Ok(k) => k,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn trust_store() -> TrustStore {
+        let mut trust_store = TrustStore::new();
+        trust_store.add_pem(include_str!("../test_data/seal/ca.pem")).unwrap();
+        trust_store
+    }
+
+    #[test]
+    fn revocation_registry () {
+        let mut registry = RevocationRegistry::new();
+        assert!(registry.revoke("urn:credential:1", "Misregistered attainment", time(1)).is_ok());
+        assert!(registry.revoke("urn:credential:2", "Issued to the wrong person & replaced", time(2)).is_ok());
+        assert!(registry.revoke("urn:credential:1", "Again", time(3)).is_err());
+        assert!(registry.revoke(" ", "No identifier", time(3)).is_err());
+        assert_eq!(registry.revocation("urn:credential:2").map(|r| r.revoked_at()), Some(time(2)));
+        assert_eq!(registry.revocation("urn:credential:3"), None);
+
+        let path = std::env::temp_dir().join(format!("revocations-{}.json", std::process::id()));
+        assert_eq!(RevocationRegistry::load(&path), Ok(RevocationRegistry::new()));
+        if let Err(e) = registry.save(&path) {
+            panic!("{}", e);
+        }
+        let loaded = RevocationRegistry::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, Ok(registry));
+    }
+
+    #[test]
+    fn revocation_list () {
+        let mut registry = RevocationRegistry::new();
+        registry.revoke("urn:credential:1", "Misregistered attainment", time(1)).unwrap();
+        registry.revoke("urn:credential:2", "Issued to the wrong person & replaced", time(2)).unwrap();
+        let published = match registry.publish("https://example.org/revocations", "https://example.org", &key(), time(3)) {
+            Ok(p) => p,
+            Err(e) => panic!("{}", e),
+        };
+        let list = match RevocationList::parse(&published, &trust_store(), time(4)) {
+            Ok(l) => l,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(list.id(), "https://example.org/revocations");
+        assert_eq!(list.issuer(), "https://example.org");
+        assert_eq!(list.published(), time(3));
+        assert_eq!(list.revocation("urn:credential:2"), registry.revocation("urn:credential:2"));
+
+        // Lists that have been tampered with or are not trusted are not read.
+        let tampered = published.replace("urn:credential:1", "urn:credential:9");
+        assert!(RevocationList::parse(&tampered, &trust_store(), time(4)).is_err());
+        assert!(RevocationList::parse(&published, &TrustStore::new(), time(4)).is_err());
+
+        // Revocations slipped into the seal, which the enveloped signature leaves out, are not read.
+        let forged = published.replace(
+            "</ds:KeyInfo>",
+            "<revokedCredential id=\"urn:credential:3\" revoked=\"2025-06-01T12:00:00Z\"><reason>Forged</reason></revokedCredential></ds:KeyInfo>",
+        );
+        assert_ne!(forged, published);
+        match RevocationList::parse(&forged, &trust_store(), time(4)) {
+            Ok(forged) => assert_eq!(forged.revocation("urn:credential:3"), None),
+            Err(e) => panic!("{}", e),
+        }
+
+        let mut pipeline = VerificationPipeline::new();
+        pipeline.register(RevocationVerifier::new(list));
+        let credential = |id: &str, issuer: &str, key: &SealingKey| {
+            let xml = format!(
+                "<europassCredential xmlns=\"http://data.europa.eu/snb\" id=\"{}\"><issuer idref=\"{}\"/></europassCredential>",
+                id, issuer
+            );
+            match seal(&xml, key, time(1)) {
+                Ok(s) => s,
+                Err(e) => panic!("{}", e),
+            }
+        };
+        let run = |xml: &str, as_of| {
+            pipeline
+                .run(xml, as_of)
+                .into_iter()
+                .find(|c| c.check_type() == VerificationType::Revocation)
+                .unwrap()
+        };
+        let revocation_check = |id: &str, as_of| run(&credential(id, "https://example.org", &key()), as_of);
+        let check = revocation_check("urn:credential:1", time(4));
+        assert_eq!(check.status(), VerificationStatus::Red);
+        assert_eq!(
+            check.description().content().primary().map(|(_, d)| d),
+            Some("The credential was revoked at 2025-06-01T12:00:00Z: Misregistered attainment…")
+        );
+        assert_eq!(revocation_check("urn:credential:3", time(4)).status(), VerificationStatus::Green);
+        assert_eq!(revocation_check("urn:credential:3", time(2)).status(), VerificationStatus::Gray);
+
+        // The list does not speak for credentials of other issuers, even if sealed by its sealer,
+        // nor for credentials naming its issuer that were sealed by another trusted sealer or not at all.
+        let other_issuer = credential("urn:credential:1", "https://example.com", &key());
+        assert_eq!(run(&other_issuer, time(4)).status(), VerificationStatus::Gray);
+        let other_sealer = credential("urn:credential:1", "https://example.org", &other_key());
+        assert!(crate::seal::check_seal(&other_sealer, &trust_store(), time(4)).is_ok());
+        assert_eq!(run(&other_sealer, time(4)).status(), VerificationStatus::Gray);
+        let unsealed = "<europassCredential xmlns=\"http://data.europa.eu/snb\" id=\"urn:credential:1\"><issuer idref=\"https://example.org\"/></europassCredential>";
+        assert_eq!(run(unsealed, time(4)).status(), VerificationStatus::Gray);
+    }
+}
@@ -0,0 +1,1232 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+/*!
+This submodule seals serialized Europass credentials with
+the electronic seal of the issuing institution.
+
+Europass only accepts credentials that carry an
+[XAdES](https://www.etsi.org/deliver/etsi_en/319100_319199/31913201/01.01.01_60/en_31913201v010101p.pdf)
+baseline B-B signature, which is enveloped in the credential document:
+the `ds:Signature` element is appended as the last child of the document element
+and its first reference covers the whole document with the enveloped signature
+and [exclusive canonicalization](crate::xml_canonicalization) transforms.
+The second reference covers the signed XAdES properties,
+which hold the signing time and a digest of the signing certificate.
+SHA-256 is used for all digests.
+
+The institutional key and X.509 certificate are read from local files,
+either from a PEM private key (PKCS#8, PKCS#1 or SEC1) and a PEM certificate chain
+or from a password-protected PKCS#12 file. RSA and ECDSA P-256 keys are supported.
+
+Seals of credentials received from other institutions are verified offline
+against a local [`TrustStore`] of certification authority certificates.
+Verification checks the digests of the references, the signature value,
+the signing certificate referenced in the signed properties and
+its chain to a trusted certificate at the signing time.
+The seal has to be the only `ds:Signature` among the children of the document
+element, and its parts are only read from their places inside it by namespace,
+so that a signature cannot be wrapped in look-alike elements.
+Revocation of the certificates is not checked.
+*/
+
+use crate::controlled_vocabularies::{VerificationStatus, VerificationType};
+use crate::europass_learning_model::{EuropeanLanguage, Note, Text, VerificationCheck};
+use crate::verification::{CredentialDocument, Verifier};
+use crate::xml_canonicalization::{
+    canonicalize, canonicalize_element, canonicalize_enveloped, Selection, XMLDSIG_NAMESPACE,
+};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::{DateTime, SecondsFormat, TimeZone, Utc};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::signature::{SignatureEncoding, Signer, Verifier as _};
+use std::convert::TryFrom;
+use rsa::{RsaPrivateKey, RsaPublicKey};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+use x509_cert::der::{Decode, Encode};
+use x509_cert::Certificate;
+
+/// The namespace of XAdES qualifying properties.
+pub const XADES_NAMESPACE: &str = "http://uri.etsi.org/01903/v1.3.2#";
+/// The type of a reference to XAdES signed properties.
+pub const SIGNED_PROPERTIES_TYPE: &str = "http://uri.etsi.org/01903#SignedProperties";
+/// The exclusive canonicalization algorithm.
+pub const EXCLUSIVE_C14N: &str = "http://www.w3.org/2001/10/xml-exc-c14n#";
+/// The enveloped signature transform.
+pub const ENVELOPED_SIGNATURE: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+/// The SHA-256 digest algorithm.
+pub const SHA256: &str = "http://www.w3.org/2001/04/xmlenc#sha256";
+/// The RSA PKCS#1 v1.5 signature algorithm with SHA-256.
+pub const RSA_SHA256: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256";
+/// The ECDSA signature algorithm with SHA-256.
+pub const ECDSA_SHA256: &str = "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha256";
+
+/// The private key of a seal.
+enum PrivateKey {
+    Rsa(Box<rsa::pkcs1v15::SigningKey<Sha256>>),
+    EcdsaP256(p256::ecdsa::SigningKey),
+}
+
+/// The public key of a seal certificate.
+pub(crate) enum PublicKey {
+    Rsa(RsaPublicKey),
+    EcdsaP256(p256::PublicKey),
+}
+
+impl PublicKey {
+    /// Reads the subject public key of a certificate.
+    pub(crate) fn from_certificate(certificate: &Certificate) -> Result<PublicKey, String> {
+        let spki = certificate
+            .tbs_certificate
+            .subject_public_key_info
+            .to_der()
+            .map_err(|e| format!("Could not encode the public key of a certificate: {}…", e))?;
+        if let Ok(key) = RsaPublicKey::from_public_key_der(&spki) {
+            Ok(PublicKey::Rsa(key))
+        } else if let Ok(key) = p256::PublicKey::from_public_key_der(&spki) {
+            Ok(PublicKey::EcdsaP256(key))
+        } else {
+            Err(String::from("Could not read the public key of a certificate: only RSA and P-256 keys are supported…"))
+        }
+    }
+}
+
+/// An institutional key with the X.509 certificate chain used for sealing credentials.
+pub struct SealingKey {
+    key: PrivateKey,
+    /// The DER encoded certificates, starting from the certificate of the key.
+    chain: Vec<Vec<u8>>,
+}
+
+impl SealingKey {
+    /// Reads a sealing key from a PEM private key and a PEM certificate chain,
+    /// which starts with the certificate of the key.
+    pub fn from_pem(key_pem: &str, certificate_pem: &str) -> Result<SealingKey, String> {
+        let key = pem::parse(key_pem).map_err(|e| format!("Could not read a PEM private key: {}…", e))?;
+        let key = match key.tag() {
+            "PRIVATE KEY" => private_key_from_pkcs8(key.contents())?,
+            "RSA PRIVATE KEY" => PrivateKey::Rsa(Box::new(rsa::pkcs1v15::SigningKey::new(
+                RsaPrivateKey::from_pkcs1_der(key.contents())
+                    .map_err(|e| format!("Could not read a PKCS#1 private key: {}…", e))?,
+            ))),
+            "EC PRIVATE KEY" => PrivateKey::EcdsaP256(p256::ecdsa::SigningKey::from(
+                p256::SecretKey::from_sec1_der(key.contents())
+                    .map_err(|e| format!("Could not read a SEC1 private key: {}…", e))?,
+            )),
+            tag => return Err(format!("Could not read a private key from a PEM block {}…", tag)),
+        };
+        let chain = pem::parse_many(certificate_pem)
+            .map_err(|e| format!("Could not read PEM certificates: {}…", e))?
+            .into_iter()
+            .filter(|block| block.tag() == "CERTIFICATE")
+            .map(|block| block.into_contents())
+            .collect();
+        SealingKey::new(key, chain)
+    }
+
+    /// Reads a sealing key from a PEM private key file and a PEM certificate chain file.
+    pub fn from_pem_files(key_path: &Path, certificate_path: &Path) -> Result<SealingKey, String> {
+        let key_pem = fs::read_to_string(key_path)
+            .map_err(|e| format!("Could not read private key file {}: {}…", key_path.display(), e))?;
+        let certificate_pem = fs::read_to_string(certificate_path)
+            .map_err(|e| format!("Could not read certificate file {}: {}…", certificate_path.display(), e))?;
+        SealingKey::from_pem(&key_pem, &certificate_pem)
+    }
+
+    /// Reads a sealing key from a PKCS#12 file content protected with the given password.
+    /// The first private key of the file is used.
+    pub fn from_pkcs12(der: &[u8], password: &str) -> Result<SealingKey, String> {
+        let keystore = p12_keystore::KeyStore::from_pkcs12(der, password)
+            .map_err(|e| format!("Could not read PKCS#12: {}…", e))?;
+        let (_, chain) = keystore
+            .private_key_chain()
+            .ok_or_else(|| String::from("Could not find a private key in PKCS#12…"))?;
+        let key = private_key_from_pkcs8(chain.key())?;
+        let certificates = chain.chain().iter().map(|c| c.as_der().to_vec()).collect();
+        SealingKey::new(key, certificates)
+    }
+
+    /// Reads a sealing key from a PKCS#12 file protected with the given password.
+    pub fn from_pkcs12_file(path: &Path, password: &str) -> Result<SealingKey, String> {
+        let der = fs::read(path).map_err(|e| format!("Could not read PKCS#12 file {}: {}…", path.display(), e))?;
+        SealingKey::from_pkcs12(&der, password)
+    }
+
+    /// Checks that the first certificate of the chain belongs to the key.
+    fn new(key: PrivateKey, chain: Vec<Vec<u8>>) -> Result<SealingKey, String> {
+        let der = chain
+            .first()
+            .ok_or_else(|| String::from("Could not find a certificate for the sealing key…"))?;
+        let certificate =
+            Certificate::from_der(der).map_err(|e| format!("Could not read an X.509 certificate: {}…", e))?;
+        let matches = match (&key, PublicKey::from_certificate(&certificate)?) {
+            (PrivateKey::Rsa(key), PublicKey::Rsa(public)) => {
+                RsaPublicKey::from((**key).as_ref()) == public
+            }
+            (PrivateKey::EcdsaP256(key), PublicKey::EcdsaP256(public)) => {
+                p256::PublicKey::from(key.verifying_key()) == public
+            }
+            _ => false,
+        };
+        if !matches {
+            return Err(String::from("Could not use a sealing key whose certificate is for a different key…"));
+        }
+        Ok(SealingKey { key, chain })
+    }
+
+    /// The DER encoded certificate of the key.
+    pub fn certificate_der(&self) -> &[u8] {
+        &self.chain[0]
+    }
+
+    /// The algorithm of the signatures made with the key.
+    pub fn signature_method(&self) -> &'static str {
+        match self.key {
+            PrivateKey::Rsa(_) => RSA_SHA256,
+            PrivateKey::EcdsaP256(_) => ECDSA_SHA256,
+        }
+    }
+
+    /// Signs the data. ECDSA signatures are given as the concatenated
+    /// integers r and s, as XML signatures require.
+    fn sign(&self, data: &[u8]) -> Vec<u8> {
+        match &self.key {
+            PrivateKey::Rsa(key) => key.sign(data).to_vec(),
+            PrivateKey::EcdsaP256(key) => {
+                let signature: p256::ecdsa::Signature = key.sign(data);
+                signature.to_bytes().to_vec()
+            }
+        }
+    }
+}
+
+fn private_key_from_pkcs8(der: &[u8]) -> Result<PrivateKey, String> {
+    if let Ok(key) = RsaPrivateKey::from_pkcs8_der(der) {
+        Ok(PrivateKey::Rsa(Box::new(rsa::pkcs1v15::SigningKey::new(key))))
+    } else if let Ok(key) = p256::SecretKey::from_pkcs8_der(der) {
+        Ok(PrivateKey::EcdsaP256(p256::ecdsa::SigningKey::from(key)))
+    } else {
+        Err(String::from("Could not read a PKCS#8 private key: only RSA and P-256 keys are supported…"))
+    }
+}
+
+/// The base64 encoded SHA-256 digest of the data.
+pub(crate) fn digest(data: &[u8]) -> String {
+    BASE64.encode(Sha256::digest(data))
+}
+
+/// Seals a serialized credential with an enveloped XAdES B-B signature
+/// made at the given time. The sealed document is returned.
+pub fn seal(xml: &str, key: &SealingKey, signing_time: DateTime<Utc>) -> Result<String, String> {
+    let document = canonicalize(xml)?;
+    let document_digest = Sha256::digest(document.as_bytes());
+    let id = format!(
+        "seal-{}",
+        document_digest[..8].iter().map(|b| format!("{:02x}", b)).collect::<String>()
+    );
+    let end = document_element_end(xml)?;
+    let signature = |properties_digest: &str, signature_value: &str| {
+        signature_element(
+            &id,
+            key,
+            &BASE64.encode(document_digest),
+            properties_digest,
+            signature_value,
+            signing_time,
+        )
+    };
+    let with_signature = |signature: String| format!("{}{}{}", &xml[..end], signature, &xml[end..]);
+
+    let unsigned = with_signature(signature("", ""));
+    let properties = canonicalize_element(&unsigned, Selection::Id(&format!("{}-signed-properties", id)))?;
+    let properties_digest = digest(properties.as_bytes());
+
+    let unsigned = with_signature(signature(&properties_digest, ""));
+    let signed_info_path = read_signature(&unsigned)?
+        .map(|signature| signature.signed_info_path)
+        .ok_or_else(|| String::from("Could not find the seal being made…"))?;
+    let signed_info = canonicalize_element(&unsigned, Selection::Path(&signed_info_path))?;
+    let signature_value = BASE64.encode(key.sign(signed_info.as_bytes()));
+
+    Ok(with_signature(signature(&properties_digest, &signature_value)))
+}
+
+/// The byte position of the end tag of the document element.
+fn document_element_end(xml: &str) -> Result<usize, String> {
+    let mut reader = Reader::from_str(xml);
+    let mut buf = Vec::new();
+    let mut depth = 0usize;
+    loop {
+        let position = reader.buffer_position();
+        match reader.read_event(&mut buf) {
+            Ok(Event::Start(_)) => depth += 1,
+            Ok(Event::End(_)) => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(position);
+                }
+            }
+            Ok(Event::Empty(_)) if depth == 0 => {
+                return Err(String::from("Could not seal a document whose document element is empty…"))
+            }
+            Ok(Event::Eof) => return Err(String::from("Could not find the document element to seal…")),
+            Err(e) => return Err(format!("Could not read XML at {}: {}…", reader.buffer_position(), e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+fn signature_element(
+    id: &str,
+    key: &SealingKey,
+    document_digest: &str,
+    properties_digest: &str,
+    signature_value: &str,
+    signing_time: DateTime<Utc>,
+) -> String {
+    let certificates: String = key
+        .chain
+        .iter()
+        .map(|der| format!("<ds:X509Certificate>{}</ds:X509Certificate>", BASE64.encode(der)))
+        .collect();
+    format!(
+        "<ds:Signature xmlns:ds=\"{dsig}\" Id=\"{id}\">\
+        <ds:SignedInfo>\
+        <ds:CanonicalizationMethod Algorithm=\"{c14n}\"/>\
+        <ds:SignatureMethod Algorithm=\"{method}\"/>\
+        <ds:Reference Id=\"{id}-document\" URI=\"\">\
+        <ds:Transforms>\
+        <ds:Transform Algorithm=\"{enveloped}\"/>\
+        <ds:Transform Algorithm=\"{c14n}\"/>\
+        </ds:Transforms>\
+        <ds:DigestMethod Algorithm=\"{sha256}\"/>\
+        <ds:DigestValue>{document_digest}</ds:DigestValue>\
+        </ds:Reference>\
+        <ds:Reference Type=\"{properties_type}\" URI=\"#{id}-signed-properties\">\
+        <ds:Transforms><ds:Transform Algorithm=\"{c14n}\"/></ds:Transforms>\
+        <ds:DigestMethod Algorithm=\"{sha256}\"/>\
+        <ds:DigestValue>{properties_digest}</ds:DigestValue>\
+        </ds:Reference>\
+        </ds:SignedInfo>\
+        <ds:SignatureValue Id=\"{id}-value\">{signature_value}</ds:SignatureValue>\
+        <ds:KeyInfo><ds:X509Data>{certificates}</ds:X509Data></ds:KeyInfo>\
+        <ds:Object>\
+        <xades:QualifyingProperties xmlns:xades=\"{xades}\" Target=\"#{id}\">\
+        <xades:SignedProperties Id=\"{id}-signed-properties\">\
+        <xades:SignedSignatureProperties>\
+        <xades:SigningTime>{signing_time}</xades:SigningTime>\
+        <xades:SigningCertificateV2><xades:Cert><xades:CertDigest>\
+        <ds:DigestMethod Algorithm=\"{sha256}\"/>\
+        <ds:DigestValue>{certificate_digest}</ds:DigestValue>\
+        </xades:CertDigest></xades:Cert></xades:SigningCertificateV2>\
+        </xades:SignedSignatureProperties>\
+        </xades:SignedProperties>\
+        </xades:QualifyingProperties>\
+        </ds:Object>\
+        </ds:Signature>",
+        dsig = XMLDSIG_NAMESPACE,
+        id = id,
+        c14n = EXCLUSIVE_C14N,
+        method = key.signature_method(),
+        enveloped = ENVELOPED_SIGNATURE,
+        sha256 = SHA256,
+        document_digest = document_digest,
+        properties_type = SIGNED_PROPERTIES_TYPE,
+        properties_digest = properties_digest,
+        signature_value = signature_value,
+        certificates = certificates,
+        xades = XADES_NAMESPACE,
+        signing_time = signing_time.to_rfc3339_opts(SecondsFormat::Secs, true),
+        certificate_digest = digest(key.certificate_der()),
+    )
+}
+
+/// A store of the certificates of trusted certification authorities,
+/// such as the trust service providers of the EU trusted lists.
+#[derive(Default)]
+pub struct TrustStore {
+    certificates: Vec<Certificate>,
+}
+
+impl TrustStore {
+    /// Constructs an empty trust store.
+    pub fn new() -> TrustStore {
+        TrustStore::default()
+    }
+
+    /// Adds the certificates of a PEM document to the store
+    /// and returns the number of certificates added.
+    pub fn add_pem(&mut self, certificate_pem: &str) -> Result<usize, String> {
+        let blocks = pem::parse_many(certificate_pem).map_err(|e| format!("Could not read PEM certificates: {}…", e))?;
+        let mut added = 0;
+        for block in blocks.iter().filter(|block| block.tag() == "CERTIFICATE") {
+            self.certificates.push(
+                Certificate::from_der(block.contents())
+                    .map_err(|e| format!("Could not read an X.509 certificate: {}…", e))?,
+            );
+            added += 1;
+        }
+        Ok(added)
+    }
+
+    /// Adds the certificates of a PEM file to the store.
+    pub fn add_pem_file(&mut self, path: &Path) -> Result<usize, String> {
+        let certificate_pem = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read certificate file {}: {}…", path.display(), e))?;
+        self.add_pem(&certificate_pem)
+    }
+
+    /// Reads a trust store from the `.pem` and `.crt` files of a directory.
+    pub fn from_directory(path: &Path) -> Result<TrustStore, String> {
+        let mut store = TrustStore::new();
+        let entries = fs::read_dir(path).map_err(|e| format!("Could not read directory {}: {}…", path.display(), e))?;
+        for entry in entries {
+            let file = entry.map_err(|e| e.to_string())?.path();
+            if matches!(file.extension().and_then(|e| e.to_str()), Some("pem" | "crt")) {
+                store.add_pem_file(&file)?;
+            }
+        }
+        Ok(store)
+    }
+
+    /// The number of trusted certificates.
+    pub fn len(&self) -> usize {
+        self.certificates.len()
+    }
+
+    /// Whether the store has no trusted certificates.
+    pub fn is_empty(&self) -> bool {
+        self.certificates.is_empty()
+    }
+
+    fn contains(&self, certificate: &Certificate) -> bool {
+        self.certificates.iter().any(|trusted| trusted == certificate)
+    }
+}
+
+/// A reference of a signature as read from `ds:Reference`.
+#[derive(Debug, Default)]
+struct Reference {
+    uri: String,
+    reference_type: Option<String>,
+    transforms: Vec<String>,
+    digest_method: String,
+    digest_value: String,
+}
+
+/// The parts of an XAdES signature needed for verifying it.
+#[derive(Debug, Default)]
+struct SignatureElements {
+    canonicalization_method: String,
+    signature_method: String,
+    references: Vec<Reference>,
+    signature_value: String,
+    /// The base64 encoded certificates of `ds:KeyInfo`.
+    certificates: Vec<String>,
+    signing_time: Option<String>,
+    /// The digest methods and values of the signing certificate references.
+    certificate_digests: Vec<(String, String)>,
+    signed_properties_id: Option<String>,
+    /// The positions of the elements leading from the document element
+    /// to the signature, as in `Selection::Path`.
+    path: Vec<usize>,
+    /// The positions of the elements leading from the document element
+    /// to the `ds:SignedInfo` of the signature.
+    signed_info_path: Vec<usize>,
+}
+
+/// The namespaces of the elements read from a signature.
+const DS: &[u8] = XMLDSIG_NAMESPACE.as_bytes();
+const XADES: &[u8] = XADES_NAMESPACE.as_bytes();
+
+/// The elements of a signature that are read, by their namespaces and
+/// local names on the path from the `ds:Signature` element.
+const SIGNED_INFO: &[(&[u8], &[u8])] = &[(DS, b"SignedInfo")];
+const CANONICALIZATION_METHOD: &[(&[u8], &[u8])] = &[(DS, b"SignedInfo"), (DS, b"CanonicalizationMethod")];
+const SIGNATURE_METHOD: &[(&[u8], &[u8])] = &[(DS, b"SignedInfo"), (DS, b"SignatureMethod")];
+const REFERENCE: &[(&[u8], &[u8])] = &[(DS, b"SignedInfo"), (DS, b"Reference")];
+const TRANSFORM: &[(&[u8], &[u8])] =
+    &[(DS, b"SignedInfo"), (DS, b"Reference"), (DS, b"Transforms"), (DS, b"Transform")];
+const DIGEST_METHOD: &[(&[u8], &[u8])] = &[(DS, b"SignedInfo"), (DS, b"Reference"), (DS, b"DigestMethod")];
+const DIGEST_VALUE: &[(&[u8], &[u8])] = &[(DS, b"SignedInfo"), (DS, b"Reference"), (DS, b"DigestValue")];
+const SIGNATURE_VALUE: &[(&[u8], &[u8])] = &[(DS, b"SignatureValue")];
+const X509_CERTIFICATE: &[(&[u8], &[u8])] = &[(DS, b"KeyInfo"), (DS, b"X509Data"), (DS, b"X509Certificate")];
+const SIGNED_PROPERTIES: &[(&[u8], &[u8])] =
+    &[(DS, b"Object"), (XADES, b"QualifyingProperties"), (XADES, b"SignedProperties")];
+const SIGNING_TIME: &[(&[u8], &[u8])] = &[
+    (DS, b"Object"),
+    (XADES, b"QualifyingProperties"),
+    (XADES, b"SignedProperties"),
+    (XADES, b"SignedSignatureProperties"),
+    (XADES, b"SigningTime"),
+];
+const CERTIFICATE_DIGEST_METHOD: &[(&[u8], &[u8])] = &[
+    (DS, b"Object"),
+    (XADES, b"QualifyingProperties"),
+    (XADES, b"SignedProperties"),
+    (XADES, b"SignedSignatureProperties"),
+    (XADES, b"SigningCertificateV2"),
+    (XADES, b"Cert"),
+    (XADES, b"CertDigest"),
+    (DS, b"DigestMethod"),
+];
+const CERTIFICATE_DIGEST_VALUE: &[(&[u8], &[u8])] = &[
+    (DS, b"Object"),
+    (XADES, b"QualifyingProperties"),
+    (XADES, b"SignedProperties"),
+    (XADES, b"SignedSignatureProperties"),
+    (XADES, b"SigningCertificateV2"),
+    (XADES, b"Cert"),
+    (XADES, b"CertDigest"),
+    (DS, b"DigestValue"),
+];
+
+/// Whether the open elements inside a signature form the given path.
+fn is_at(open: &[(Vec<u8>, Vec<u8>)], path: &[(&[u8], &[u8])]) -> bool {
+    open.len() == path.len()
+        && open
+            .iter()
+            .zip(path)
+            .all(|((namespace, name), (expected_namespace, expected_name))| {
+                namespace == expected_namespace && name == expected_name
+            })
+}
+
+fn attribute(element: &BytesStart, name: &[u8]) -> Result<Option<String>, String> {
+    for attribute in element.attributes() {
+        let attribute = attribute.map_err(|e| e.to_string())?;
+        if attribute.key == name {
+            let value = attribute.unescaped_value().map_err(|e| e.to_string())?;
+            return String::from_utf8(value.into_owned()).map(Some).map_err(|e| e.to_string());
+        }
+    }
+    Ok(None)
+}
+
+/// Reads the seal of a document, which is the only `ds:Signature` element
+/// among the children of the document element. Only the elements of that
+/// signature are read, each at its place in the signature and by namespace,
+/// so that signatures and look-alike elements elsewhere in the document
+/// cannot stand in for its parts.
+fn read_signature(xml: &str) -> Result<Option<SignatureElements>, String> {
+    let mut reader = Reader::from_str(xml);
+    let mut buf = Vec::new();
+    let mut namespaces = Vec::new();
+    let mut signature: Option<SignatureElements> = None;
+    // The depth of the open elements, with the document element at depth 1.
+    let mut depth = 0usize;
+    // The number of child elements read so far of the document element and of the signature.
+    let mut document_children = 0usize;
+    let mut signature_children = 0usize;
+    // Whether the signature being read is still open.
+    let mut in_signature = false;
+    // The namespaces and local names of the open elements inside the signature.
+    let mut open: Vec<(Vec<u8>, Vec<u8>)> = Vec::new();
+    // The singular elements of the signature that have been read.
+    let mut read: Vec<&[(&[u8], &[u8])]> = Vec::new();
+    let mut text = String::new();
+    loop {
+        let (namespace, event) = reader
+            .read_namespaced_event(&mut buf, &mut namespaces)
+            .map_err(|e| format!("Could not read XML at {}: {}…", reader.buffer_position(), e))?;
+        let namespace = namespace.unwrap_or_default().to_vec();
+        match event {
+            Event::Start(ref element) | Event::Empty(ref element) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                let name = element.local_name().to_vec();
+                depth += 1;
+                if depth == 2 {
+                    let position = document_children;
+                    document_children += 1;
+                    if namespace == DS && name == b"Signature" {
+                        if signature.is_some() {
+                            return Err(String::from("The credential has more than one seal…"));
+                        }
+                        signature = Some(SignatureElements {
+                            path: vec![position],
+                            ..SignatureElements::default()
+                        });
+                        in_signature = !is_empty;
+                    }
+                } else if in_signature {
+                    let signature = signature.as_mut().expect("A signature is being read");
+                    if depth == 3 {
+                        if namespace == DS && name == b"SignedInfo" {
+                            signature.signed_info_path = vec![signature.path[0], signature_children];
+                        }
+                        signature_children += 1;
+                    }
+                    open.push((namespace, name));
+                    for singular in [SIGNED_INFO, CANONICALIZATION_METHOD, SIGNATURE_METHOD, SIGNATURE_VALUE, SIGNED_PROPERTIES, SIGNING_TIME] {
+                        if is_at(&open, singular) {
+                            if read.contains(&singular) {
+                                return Err(format!(
+                                    "The seal has more than one {}…",
+                                    String::from_utf8_lossy(singular[singular.len() - 1].1)
+                                ));
+                            }
+                            read.push(singular);
+                        }
+                    }
+                    if is_at(&open, CANONICALIZATION_METHOD) {
+                        signature.canonicalization_method = attribute(element, b"Algorithm")?.unwrap_or_default();
+                    } else if is_at(&open, SIGNATURE_METHOD) {
+                        signature.signature_method = attribute(element, b"Algorithm")?.unwrap_or_default();
+                    } else if is_at(&open, REFERENCE) {
+                        signature.references.push(Reference {
+                            uri: attribute(element, b"URI")?.unwrap_or_default(),
+                            reference_type: attribute(element, b"Type")?,
+                            ..Reference::default()
+                        });
+                    } else if is_at(&open, TRANSFORM) {
+                        if let Some(reference) = signature.references.last_mut() {
+                            reference.transforms.push(attribute(element, b"Algorithm")?.unwrap_or_default());
+                        }
+                    } else if is_at(&open, DIGEST_METHOD) {
+                        if let Some(reference) = signature.references.last_mut() {
+                            reference.digest_method = attribute(element, b"Algorithm")?.unwrap_or_default();
+                        }
+                    } else if is_at(&open, CERTIFICATE_DIGEST_METHOD) {
+                        let method = attribute(element, b"Algorithm")?.unwrap_or_default();
+                        signature.certificate_digests.push((method, String::new()));
+                    } else if is_at(&open, SIGNED_PROPERTIES) {
+                        signature.signed_properties_id = attribute(element, b"Id")?;
+                    }
+                    if is_empty {
+                        open.pop();
+                    }
+                }
+                if is_empty {
+                    depth -= 1;
+                }
+                text.clear();
+            }
+            Event::Text(ref content) if in_signature => {
+                text.push_str(&content.unescape_and_decode(&reader).map_err(|e| e.to_string())?);
+            }
+            Event::End(_) => {
+                if in_signature && depth == 2 {
+                    in_signature = false;
+                } else if in_signature {
+                    let signature = signature.as_mut().expect("A signature is being read");
+                    let content = text.trim().to_string();
+                    let base64: String = content.chars().filter(|c| !c.is_whitespace()).collect();
+                    if is_at(&open, DIGEST_VALUE) {
+                        if let Some(reference) = signature.references.last_mut() {
+                            reference.digest_value = base64;
+                        }
+                    } else if is_at(&open, CERTIFICATE_DIGEST_VALUE) {
+                        if let Some(digest) = signature.certificate_digests.last_mut() {
+                            digest.1 = base64;
+                        }
+                    } else if is_at(&open, SIGNATURE_VALUE) {
+                        signature.signature_value = base64;
+                    } else if is_at(&open, X509_CERTIFICATE) {
+                        signature.certificates.push(base64);
+                    } else if is_at(&open, SIGNING_TIME) {
+                        signature.signing_time = Some(content);
+                    }
+                    open.pop();
+                }
+                depth = depth.saturating_sub(1);
+                text.clear();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    if let Some(signature) = &signature {
+        if signature.signed_info_path.is_empty() {
+            return Err(String::from("The seal has no ds:SignedInfo…"));
+        }
+    }
+    Ok(signature)
+}
+
+/// The value of the `id` attribute of the document element.
+fn document_element_id(xml: &str) -> Result<Option<String>, String> {
+    let mut reader = Reader::from_str(xml);
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event(&mut buf) {
+            Ok(Event::Start(ref element)) | Ok(Event::Empty(ref element)) => return attribute(element, b"id"),
+            Ok(Event::Eof) => return Ok(None),
+            Err(e) => return Err(format!("Could not read XML at {}: {}…", reader.buffer_position(), e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+fn decode_base64(content: &str) -> Result<Vec<u8>, String> {
+    BASE64.decode(content).map_err(|e| format!("Could not decode base64: {}…", e))
+}
+
+fn to_datetime(time: x509_cert::time::Time) -> DateTime<Utc> {
+    let seconds = time.to_unix_duration().as_secs();
+    Utc.timestamp_opt(seconds as i64, 0).single().unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+fn subject_name(certificate: &Certificate) -> String {
+    certificate.tbs_certificate.subject.to_string()
+}
+
+/// Checks that a certificate was valid at the given time.
+fn check_validity(certificate: &Certificate, time: DateTime<Utc>) -> Result<(), String> {
+    let validity = &certificate.tbs_certificate.validity;
+    let (not_before, not_after) = (to_datetime(validity.not_before), to_datetime(validity.not_after));
+    if time < not_before || time > not_after {
+        return Err(format!(
+            "The certificate {} was not valid at {}: it is valid from {} to {}…",
+            subject_name(certificate),
+            time.to_rfc3339_opts(SecondsFormat::Secs, true),
+            not_before.to_rfc3339_opts(SecondsFormat::Secs, true),
+            not_after.to_rfc3339_opts(SecondsFormat::Secs, true),
+        ));
+    }
+    Ok(())
+}
+
+/// Whether a certificate may issue other certificates.
+fn is_certification_authority(certificate: &Certificate) -> bool {
+    use x509_cert::der::oid::AssociatedOid;
+    use x509_cert::ext::pkix::BasicConstraints;
+    certificate
+        .tbs_certificate
+        .extensions
+        .iter()
+        .flatten()
+        .find(|extension| extension.extn_id == BasicConstraints::OID)
+        .and_then(|extension| BasicConstraints::from_der(extension.extn_value.as_bytes()).ok())
+        .map(|constraints| constraints.ca)
+        .unwrap_or(false)
+}
+
+/// Checks that `issuer` has signed `certificate`.
+fn check_issued_by(certificate: &Certificate, issuer: &Certificate) -> Result<(), String> {
+    use x509_cert::der::oid::ObjectIdentifier;
+    const SHA256_WITH_RSA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.11");
+    const SHA384_WITH_RSA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.12");
+    const SHA512_WITH_RSA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.13");
+    const ECDSA_WITH_SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2");
+
+    let tbs = certificate
+        .tbs_certificate
+        .to_der()
+        .map_err(|e| format!("Could not encode a certificate: {}…", e))?;
+    let signature = certificate.signature.raw_bytes();
+    let valid = match (PublicKey::from_certificate(issuer)?, certificate.signature_algorithm.oid) {
+        (PublicKey::Rsa(key), SHA256_WITH_RSA) => rsa_verifies::<Sha256>(key, &tbs, signature),
+        (PublicKey::Rsa(key), SHA384_WITH_RSA) => rsa_verifies::<sha2::Sha384>(key, &tbs, signature),
+        (PublicKey::Rsa(key), SHA512_WITH_RSA) => rsa_verifies::<sha2::Sha512>(key, &tbs, signature),
+        (PublicKey::EcdsaP256(key), ECDSA_WITH_SHA256) => p256::ecdsa::Signature::from_der(signature)
+            .map(|signature| p256::ecdsa::VerifyingKey::from(key).verify(&tbs, &signature).is_ok())
+            .unwrap_or(false),
+        (_, algorithm) => {
+            return Err(format!(
+                "Could not verify the certificate {} signed with algorithm {}…",
+                subject_name(certificate),
+                algorithm
+            ))
+        }
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("The certificate {} has not been signed by {}…", subject_name(certificate), subject_name(issuer)))
+    }
+}
+
+fn rsa_verifies<D>(key: RsaPublicKey, data: &[u8], signature: &[u8]) -> bool
+where
+    D: Digest + rsa::pkcs8::AssociatedOid,
+{
+    rsa::pkcs1v15::Signature::try_from(signature)
+        .map(|signature| rsa::pkcs1v15::VerifyingKey::<D>::new(key).verify(data, &signature).is_ok())
+        .unwrap_or(false)
+}
+
+/// Builds a chain of certificates valid at `time` from the signing certificate
+/// to a certificate of the trust store and returns the trusted certificate.
+fn check_chain<'a>(
+    certificate: &'a Certificate,
+    intermediates: &'a [Certificate],
+    trust_store: &'a TrustStore,
+    time: DateTime<Utc>,
+) -> Result<&'a Certificate, String> {
+    // Chains longer than this are not expected from trust service providers.
+    const MAX_CHAIN_LENGTH: usize = 8;
+    let mut current = certificate;
+    for _ in 0..MAX_CHAIN_LENGTH {
+        check_validity(current, time)?;
+        if trust_store.contains(current) {
+            return Ok(current);
+        }
+        let issuer = trust_store
+            .certificates
+            .iter()
+            .chain(intermediates.iter())
+            .filter(|candidate| *candidate != current)
+            .filter(|candidate| candidate.tbs_certificate.subject == current.tbs_certificate.issuer)
+            .find(|candidate| check_issued_by(current, candidate).is_ok())
+            .ok_or_else(|| {
+                format!(
+                    "Could not find a trusted issuer {} for the certificate {}…",
+                    current.tbs_certificate.issuer,
+                    subject_name(current)
+                )
+            })?;
+        if !is_certification_authority(issuer) {
+            return Err(format!("The issuer {} is not a certification authority…", subject_name(issuer)));
+        }
+        current = issuer;
+    }
+    Err(format!("Could not find a trusted chain for the certificate {}…", subject_name(certificate)))
+}
+
+/// The certificates of a seal and the position of its signing certificate among them.
+fn signing_certificate(signature: &SignatureElements) -> Result<(Vec<Certificate>, usize), String> {
+    let certificates = signature
+        .certificates
+        .iter()
+        .map(|c| {
+            decode_base64(c).and_then(|der| {
+                Certificate::from_der(&der).map_err(|e| format!("Could not read an X.509 certificate: {}…", e))
+            })
+        })
+        .collect::<Result<Vec<Certificate>, String>>()?;
+    let (digest_method, certificate_digest) = signature
+        .certificate_digests
+        .first()
+        .ok_or_else(|| String::from("The seal has no signing certificate reference…"))?;
+    if digest_method != SHA256 {
+        return Err(format!("Could not verify a certificate digested with {}…", digest_method));
+    }
+    let signing_certificate = certificates
+        .iter()
+        .position(|c| c.to_der().map(|der| digest(&der) == *certificate_digest).unwrap_or(false))
+        .ok_or_else(|| String::from("The seal does not contain its signing certificate…"))?;
+    Ok((certificates, signing_certificate))
+}
+
+/// A seal that has been verified.
+pub(crate) struct CheckedSeal {
+    /// The subject of the signing certificate.
+    pub(crate) sealed_by: String,
+    /// A description of the valid seal.
+    pub(crate) description: String,
+}
+
+/// Verifies the seal of a document.
+pub(crate) fn check_seal(xml: &str, trust_store: &TrustStore, as_of: DateTime<Utc>) -> Result<CheckedSeal, String> {
+    let signature = read_signature(xml)?.ok_or_else(|| String::from("Could not find a seal in the credential…"))?;
+    if signature.canonicalization_method != EXCLUSIVE_C14N {
+        return Err(format!(
+            "Could not verify a seal canonicalized with {}…",
+            signature.canonicalization_method
+        ));
+    }
+
+    // References
+    let properties_id = signature
+        .signed_properties_id
+        .as_deref()
+        .ok_or_else(|| String::from("The seal has no XAdES signed properties…"))?;
+    let mut covers_document = false;
+    let mut covers_properties = false;
+    for reference in &signature.references {
+        if reference.digest_method != SHA256 {
+            return Err(format!("Could not verify a reference digested with {}…", reference.digest_method));
+        }
+        let canonical = if reference.uri.is_empty() {
+            if reference.transforms != [ENVELOPED_SIGNATURE, EXCLUSIVE_C14N] {
+                return Err(String::from("Could not verify a document reference without the enveloped signature and exclusive canonicalization transforms…"));
+            }
+            covers_document = true;
+            canonicalize_enveloped(xml, &signature.path)?
+        } else if let Some(id) = reference.uri.strip_prefix('#') {
+            if reference.transforms != [EXCLUSIVE_C14N] {
+                return Err(format!("Could not verify reference {} without exclusive canonicalization…", reference.uri));
+            }
+            if id == properties_id && reference.reference_type.as_deref() == Some(SIGNED_PROPERTIES_TYPE) {
+                covers_properties = true;
+            }
+            canonicalize_element(xml, Selection::Id(id))?
+        } else {
+            return Err(format!("Could not verify an external reference {}…", reference.uri));
+        };
+        if digest(canonical.as_bytes()) != reference.digest_value {
+            return Err(format!(
+                "The digest of reference \"{}\" does not match: the credential has been modified after sealing…",
+                reference.uri
+            ));
+        }
+    }
+    if !covers_document {
+        return Err(String::from("The seal does not cover the whole credential…"));
+    }
+    if !covers_properties {
+        return Err(String::from("The seal does not cover its XAdES signed properties…"));
+    }
+
+    // Signing certificate
+    let (certificates, signing_certificate) = signing_certificate(&signature)?;
+    let signing_certificate = &certificates[signing_certificate];
+
+    // Signature value
+    // The signature value is checked against the same ds:SignedInfo whose references were checked.
+    let signed_info = canonicalize_element(xml, Selection::Path(&signature.signed_info_path))?;
+    let signature_value = decode_base64(&signature.signature_value)?;
+    let valid = match (PublicKey::from_certificate(signing_certificate)?, signature.signature_method.as_str()) {
+        (PublicKey::Rsa(key), RSA_SHA256) => rsa_verifies::<Sha256>(key, signed_info.as_bytes(), &signature_value),
+        (PublicKey::EcdsaP256(key), ECDSA_SHA256) => p256::ecdsa::Signature::from_slice(&signature_value)
+            .map(|s| p256::ecdsa::VerifyingKey::from(key).verify(signed_info.as_bytes(), &s).is_ok())
+            .unwrap_or(false),
+        (_, method) => {
+            return Err(format!("Could not verify a seal made with {} using the signing certificate…", method))
+        }
+    };
+    if !valid {
+        return Err(String::from("The signature value of the seal does not match the signing certificate…"));
+    }
+
+    // Signing time and trust
+    let signing_time = signature
+        .signing_time
+        .as_deref()
+        .ok_or_else(|| String::from("The seal has no signing time…"))?;
+    let signing_time = DateTime::parse_from_rfc3339(signing_time)
+        .map_err(|e| format!("Could not read signing time {}: {}…", signing_time, e))?
+        .with_timezone(&Utc);
+    if signing_time > as_of {
+        return Err(format!(
+            "The seal has been made in the future at {}…",
+            signing_time.to_rfc3339_opts(SecondsFormat::Secs, true)
+        ));
+    }
+    let trusted = check_chain(signing_certificate, &certificates, trust_store, signing_time)?;
+
+    Ok(CheckedSeal {
+        sealed_by: subject_name(signing_certificate),
+        description: format!(
+            "The credential has been sealed by {} at {} with a certificate trusted through {}.",
+            subject_name(signing_certificate),
+            signing_time.to_rfc3339_opts(SecondsFormat::Secs, true),
+            subject_name(trusted)
+        ),
+    })
+}
+
+/// The subject of the signing certificate of the seal of a document,
+/// read without verifying the seal.
+pub(crate) fn sealed_by(xml: &str) -> Option<String> {
+    let signature = read_signature(xml).ok().flatten()?;
+    let (certificates, signing_certificate) = signing_certificate(&signature).ok()?;
+    Some(subject_name(&certificates[signing_certificate]))
+}
+
+/// Verifies the seal of a serialized credential at the time `as_of`
+/// against the certification authorities of the trust store:
+/// the digests of the references, the signature value, the signing certificate
+/// and its chain to a trusted certificate at the signing time,
+/// which may not be later than `as_of`.
+pub fn verify_seal(xml: &str, trust_store: &TrustStore, as_of: DateTime<Utc>) -> VerificationCheck {
+    let (status, description) = seal_status(xml, trust_store, as_of);
+    let subject = document_element_id(xml).ok().flatten().unwrap_or_default();
+    VerificationCheck::new(
+        VerificationType::Seal,
+        &subject,
+        status,
+        Note::new(Text::new(EuropeanLanguage::English, &description)),
+    )
+}
+
+fn seal_status(xml: &str, trust_store: &TrustStore, as_of: DateTime<Utc>) -> (VerificationStatus, String) {
+    match check_seal(xml, trust_store, as_of) {
+        Ok(seal) => (VerificationStatus::Green, seal.description),
+        Err(error) => (VerificationStatus::Red, error),
+    }
+}
+
+/// Verifies the seals of credentials in a verification pipeline.
+pub struct SealVerifier {
+    trust_store: TrustStore,
+}
+
+impl SealVerifier {
+    /// Constructs a verifier trusting the certification authorities of the trust store.
+    pub fn new(trust_store: TrustStore) -> SealVerifier {
+        SealVerifier { trust_store }
+    }
+}
+
+impl Verifier for SealVerifier {
+    fn check_type(&self) -> VerificationType {
+        VerificationType::Seal
+    }
+
+    fn verify(&self, credential: &CredentialDocument, as_of: DateTime<Utc>) -> (VerificationStatus, String) {
+        seal_status(credential.xml(), &self.trust_store, as_of)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::verification::VerificationPipeline;
+
+    const CREDENTIAL: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <europassCredential xmlns=\"http://data.europa.eu/snb\" id=\"urn:credential:1\">\n\
+        <title><text content-type=\"text/plain\" lang=\"en\">Bachelor of Science</text></title>\n\
+        </europassCredential>\n";
+
+    /// The content of an element of a sealed document.
+    fn element<'a>(xml: &'a str, name: &str) -> &'a str {
+        let start = xml.find(&format!("<{}", name)).unwrap();
+        let start = start + xml[start..].find('>').unwrap() + 1;
+        let end = start + xml[start..].find(&format!("</{}>", name)).unwrap();
+        &xml[start..end]
+    }
+
+    fn sign_and_check(key: &SealingKey) -> String {
+        let signing_time = Utc.with_ymd_and_hms(2024, 5, 31, 12, 0, 0).unwrap();
+        let sealed = match seal(CREDENTIAL, key, signing_time) {
+            Ok(s) => s,
+            Err(e) => panic!("{}", e),
+        };
+        assert!(sealed.contains("<xades:SigningTime>2024-05-31T12:00:00Z</xades:SigningTime>"));
+        assert!(sealed.trim_end().ends_with("</ds:Signature></europassCredential>"));
+
+        let document = canonicalize_enveloped(&sealed, &[1]).unwrap();
+        assert_eq!(document, canonicalize(CREDENTIAL).unwrap());
+        assert!(!element(&sealed, "ds:SignatureValue").is_empty());
+        let properties = canonicalize_element(&sealed, Selection::Element(XADES_NAMESPACE, "SignedProperties")).unwrap();
+        let digests: Vec<&str> = sealed
+            .match_indices("<ds:DigestValue>")
+            .map(|(i, _)| element(&sealed[i..], "ds:DigestValue"))
+            .collect();
+        assert_eq!(
+            digests,
+            vec![
+                digest(document.as_bytes()),
+                digest(properties.as_bytes()),
+                digest(key.certificate_der()),
+            ]
+        );
+        assert_eq!(element(&sealed, "ds:X509Certificate"), BASE64.encode(key.certificate_der()));
+        sealed
+    }
+
+    #[test]
+    fn seal_with_rsa_pem () {
+        let key = match SealingKey::from_pem(
+            include_str!("../test_data/seal/seal-rsa.key"),
+            include_str!("../test_data/seal/seal-rsa.pem"),
+        ) {
+            Ok(k) => k,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(key.signature_method(), RSA_SHA256);
+        let sealed = sign_and_check(&key);
+
+        let signed_info = canonicalize_element(&sealed, Selection::Element(XMLDSIG_NAMESPACE, "SignedInfo")).unwrap();
+        let signature = BASE64.decode(element(&sealed, "ds:SignatureValue")).unwrap();
+        let certificate = Certificate::from_der(key.certificate_der()).unwrap();
+        let public = match PublicKey::from_certificate(&certificate) {
+            Ok(PublicKey::Rsa(public)) => public,
+            _ => panic!("Expected an RSA public key"),
+        };
+        let verifying_key = rsa::pkcs1v15::VerifyingKey::<Sha256>::new(public);
+        let signature = rsa::pkcs1v15::Signature::try_from(signature.as_slice()).unwrap();
+        assert!(verifying_key.verify(signed_info.as_bytes(), &signature).is_ok());
+    }
+
+    #[test]
+    fn seal_with_ecdsa_pem () {
+        let key = match SealingKey::from_pem(
+            include_str!("../test_data/seal/seal-ec.key"),
+            include_str!("../test_data/seal/seal-ec.pem"),
+        ) {
+            Ok(k) => k,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(key.signature_method(), ECDSA_SHA256);
+        let sealed = sign_and_check(&key);
+
+        let signed_info = canonicalize_element(&sealed, Selection::Element(XMLDSIG_NAMESPACE, "SignedInfo")).unwrap();
+        let signature = BASE64.decode(element(&sealed, "ds:SignatureValue")).unwrap();
+        assert_eq!(signature.len(), 64);
+        let certificate = Certificate::from_der(key.certificate_der()).unwrap();
+        let public = match PublicKey::from_certificate(&certificate) {
+            Ok(PublicKey::EcdsaP256(public)) => public,
+            _ => panic!("Expected a P-256 public key"),
+        };
+        let verifying_key = p256::ecdsa::VerifyingKey::from(public);
+        let signature = p256::ecdsa::Signature::from_slice(&signature).unwrap();
+        assert!(verifying_key.verify(signed_info.as_bytes(), &signature).is_ok());
+    }
+
+    #[test]
+    fn sealing_keys_from_files () {
+        let key = match SealingKey::from_pkcs12(include_bytes!("../test_data/seal/seal-rsa.p12"), "test-password") {
+            Ok(k) => k,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(key.chain.len(), 2);
+        sign_and_check(&key);
+        assert!(SealingKey::from_pkcs12(include_bytes!("../test_data/seal/seal-rsa.p12"), "wrong").is_err());
+        assert!(SealingKey::from_pem_files(
+            Path::new("test_data/seal/seal-rsa.key"),
+            Path::new("test_data/seal/seal-rsa.pem"),
+        )
+        .is_ok());
+        // The certificate of the RSA key does not belong to the EC key.
+        assert!(SealingKey::from_pem(
+            include_str!("../test_data/seal/seal-ec.key"),
+            include_str!("../test_data/seal/seal-rsa.pem"),
+        )
+        .is_err());
+    }
+
+    fn sealed_with(key_pem: &str, certificate_pem: &str, signing_time: DateTime<Utc>) -> String {
+        let key = match SealingKey::from_pem(key_pem, certificate_pem) {
+            Ok(k) => k,
+            Err(e) => panic!("{}", e),
+        };
+        match seal(CREDENTIAL, &key, signing_time) {
+            Ok(s) => s,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn description(check: &VerificationCheck) -> &str {
+        check.description().content().primary().map(|(_, d)| d).unwrap_or_default()
+    }
+
+    #[test]
+    fn seal_verification () {
+        let mut trust_store = TrustStore::new();
+        assert_eq!(trust_store.add_pem(include_str!("../test_data/seal/ca.pem")), Ok(1));
+        let signing_time = Utc.with_ymd_and_hms(2025, 6, 1, 9, 30, 0).unwrap();
+        let as_of = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let rsa_key = include_str!("../test_data/seal/seal-rsa.key");
+        let rsa_certificate = include_str!("../test_data/seal/seal-rsa.pem");
+
+        let sealed = sealed_with(rsa_key, rsa_certificate, signing_time);
+        let check = verify_seal(&sealed, &trust_store, as_of);
+        assert_eq!(check.status(), VerificationStatus::Green, "{}", description(&check));
+        assert_eq!(check.check_type(), VerificationType::Seal);
+        assert_eq!(check.subject(), "urn:credential:1");
+        assert_eq!(check.id(), "urn:credential:1#verification-seal");
+        assert!(description(&check).contains("Test University eSeal"));
+        assert!(description(&check).contains("2025-06-01T09:30:00Z"));
+
+        let sealed_ec = sealed_with(
+            include_str!("../test_data/seal/seal-ec.key"),
+            include_str!("../test_data/seal/seal-ec.pem"),
+            signing_time,
+        );
+        assert_eq!(verify_seal(&sealed_ec, &trust_store, as_of).status(), VerificationStatus::Green);
+        let key = SealingKey::from_pkcs12(include_bytes!("../test_data/seal/seal-rsa.p12"), "test-password").unwrap();
+        let sealed_p12 = seal(CREDENTIAL, &key, signing_time).unwrap();
+        assert_eq!(verify_seal(&sealed_p12, &trust_store, as_of).status(), VerificationStatus::Green);
+
+        // Tampering with the content or the signed properties breaks the digests.
+        let tampered = sealed.replace("Bachelor of Science", "Master of Science");
+        let check = verify_seal(&tampered, &trust_store, as_of);
+        assert_eq!(check.status(), VerificationStatus::Red);
+        assert!(description(&check).contains("modified after sealing"));
+        let tampered = sealed.replace("2025-06-01T09:30:00Z", "2025-05-01T09:30:00Z");
+        assert_eq!(verify_seal(&tampered, &trust_store, as_of).status(), VerificationStatus::Red);
+
+        // Seals made in the future or outside the validity of the certificate are not valid.
+        let check = verify_seal(&sealed, &trust_store, Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(check.status(), VerificationStatus::Red);
+        let early = sealed_with(rsa_key, rsa_certificate, Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap());
+        let check = verify_seal(&early, &trust_store, as_of);
+        assert_eq!(check.status(), VerificationStatus::Red);
+        assert!(description(&check).contains("was not valid at 2024-06-01T00:00:00Z"));
+
+        // Certificates that do not chain to the trust store are not trusted.
+        let untrusted = sealed_with(
+            include_str!("../test_data/seal/untrusted.key"),
+            include_str!("../test_data/seal/untrusted.pem"),
+            signing_time,
+        );
+        let check = verify_seal(&untrusted, &trust_store, as_of);
+        assert_eq!(check.status(), VerificationStatus::Red);
+        assert!(description(&check).contains("Could not find a trusted issuer"));
+        assert_eq!(verify_seal(&sealed, &TrustStore::new(), as_of).status(), VerificationStatus::Red);
+
+        let check = verify_seal(CREDENTIAL, &trust_store, as_of);
+        assert_eq!(check.status(), VerificationStatus::Red);
+        assert_eq!(description(&check), "Could not find a seal in the credential…");
+    }
+
+    #[test]
+    fn seal_signature_wrapping () {
+        let mut trust_store = TrustStore::new();
+        assert_eq!(trust_store.add_pem(include_str!("../test_data/seal/ca.pem")), Ok(1));
+        let signing_time = Utc.with_ymd_and_hms(2025, 6, 1, 9, 30, 0).unwrap();
+        let as_of = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let sealed = sealed_with(
+            include_str!("../test_data/seal/seal-rsa.key"),
+            include_str!("../test_data/seal/seal-rsa.pem"),
+            signing_time,
+        );
+        let start = sealed.find("<ds:Signature").unwrap();
+        let end = sealed.find("</ds:Signature>").unwrap() + "</ds:Signature>".len();
+        let signature = &sealed[start..end];
+        // A fake signature inserted before the real one, with its children
+        // in another namespace under the same local names and a forged signing time.
+        let children = &signature[signature.find('>').unwrap() + 1..signature.len() - "</ds:Signature>".len()];
+        let fake = format!(
+            "<ds:Signature xmlns:ds=\"{}\" xmlns:fake=\"http://example.org/fake#\">{}</ds:Signature>",
+            XMLDSIG_NAMESPACE,
+            children
+                .replace("<ds:", "<fake:")
+                .replace("</ds:", "</fake:")
+                .replace("2025-06-01T09:30:00Z", "2024-06-01T09:30:00Z"),
+        );
+        let wrapped = format!("{}{}{}", &sealed[..start], fake, &sealed[start..]);
+        let check = verify_seal(&wrapped, &trust_store, as_of);
+        assert_eq!(check.status(), VerificationStatus::Red);
+        assert_eq!(description(&check), "The credential has more than one seal…");
+
+        // Parts of the seal in another namespace are not read as parts of it.
+        let renamed = sealed.replacen(
+            "<ds:SignedInfo>",
+            "<fake:SignedInfo xmlns:fake=\"http://example.org/fake#\">",
+            1,
+        ).replacen("</ds:SignedInfo>", "</fake:SignedInfo>", 1);
+        let check = verify_seal(&renamed, &trust_store, as_of);
+        assert_eq!(check.status(), VerificationStatus::Red);
+        assert_eq!(description(&check), "The seal has no ds:SignedInfo…");
+    }
+
+    #[test]
+    fn seal_verifier_in_pipeline () {
+        let mut trust_store = TrustStore::new();
+        trust_store.add_pem(include_str!("../test_data/seal/ca.pem")).unwrap();
+        let mut pipeline = VerificationPipeline::new();
+        pipeline.register(SealVerifier::new(trust_store));
+        let sealed = sealed_with(
+            include_str!("../test_data/seal/seal-ec.key"),
+            include_str!("../test_data/seal/seal-ec.pem"),
+            Utc.with_ymd_and_hms(2025, 6, 1, 9, 30, 0).unwrap(),
+        );
+        let checks = pipeline.run(&sealed, Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+        let seal_check = checks.iter().find(|c| c.check_type() == VerificationType::Seal).unwrap();
+        assert_eq!(seal_check.status(), VerificationStatus::Green, "{}", description(seal_check));
+        let checks = pipeline.run(CREDENTIAL, Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+        let seal_check = checks.iter().find(|c| c.check_type() == VerificationType::Seal).unwrap();
+        assert_eq!(seal_check.status(), VerificationStatus::Red);
+    }
+}
@@ -7,8 +7,15 @@ the [SISU Swagger UI](https://sis-tuni.funidata.fi/ori/swagger-ui.html) with ser
 */
 
 use serde::Deserialize;
-use serde_json;
-use chrono;
+use std::collections::BTreeMap;
+use crate::europass_learning_model::{
+    Code, CreditWorkloadRule, Duration, EuropassCredential, EuropeanLanguage, LanguageFallback, LearningSpecification,
+    Qualification, Text, ValidityPeriod, VerifiableCredential,
+};
+use crate::national_qualification_frameworks::{FinlandNQF, NQF};
+use crate::isced_fields_of_education::ISCEDFField;
+use crate::controlled_vocabularies::Language;
+use crate::revocation::RevocationRegistry;
 
 /// An attainment returned from the SISU database upon sending
 /// a successful GET request to  the SISU Swagger API.
@@ -30,9 +37,10 @@ struct SISUAttainment {
     credits: serde_json::value::Number,
     /// A read only string of the document state.
     document_state: DocumentState,
-    /// The date when this attainment will expire.
+    /// The date when this attainment will expire, if it expires.
     /// Must conform to the date pattern.
-    expiry_date: chrono::naive::NaiveDate,
+    #[serde(default)]
+    expiry_date: Option<chrono::naive::NaiveDate>,
     /// A result of grade average calculation.
     grade_average: GradeAverage,
     /// The index of the grade, within the grade scale,
@@ -103,6 +111,107 @@ struct SISUAttainment {
     verifier_person_id: String,
 }
 
+impl SISUAttainment {
+    /// The expected workload of this attainment, derived from its credits
+    /// with the given rule. The workload is recorded in the credential
+    /// alongside the ECTS credit points of the attainment.
+    fn expected_workload(&self, rule: &CreditWorkloadRule) -> Option<Duration> {
+        self.credits.as_f64().map(|credits| rule.workload(credits))
+    }
+
+    /// The specification of the learning this attainment awards, with the given
+    /// identifier and title, such as those of the attained course unit.
+    /// The specification carries the credits of the attainment,
+    /// the workload expected of them according to the given rule,
+    /// the ISCED-F field of the study field of the attainment
+    /// and the language of the attainment.
+    fn learning_specification(&self, id: &str, title: Text, rule: &CreditWorkloadRule) -> LearningSpecification {
+        let mut specification = LearningSpecification::new(id, title);
+        if let (Some(credits), Some(workload)) = (self.credits.as_f64(), self.expected_workload(rule)) {
+            specification.set_credit_points(credits, workload);
+        }
+        if let Some(code) = self.iscedf_code() {
+            specification.set_iscedf_code(code);
+        }
+        if let Some(language) = self.attainment_language() {
+            specification.set_language(language);
+        }
+        specification
+    }
+
+    /// The specification of the qualification this attainment of the given
+    /// module awards, as a part of the given degree programme, which may be
+    /// the module itself. Only module attainments award qualifications.
+    fn qualification_specification(
+        &self,
+        id: &str,
+        title: Text,
+        rule: &CreditWorkloadRule,
+        module: &SISUModule,
+        degree_programme: &SISUModule,
+    ) -> Result<LearningSpecification, String> {
+        if !matches!(self.attainment_type, AttainmentType::ModuleAttainment) {
+            return Err(format!("Could not award a qualification for attainment {}, which is not a module attainment…", self.id));
+        }
+        let mut specification = self.learning_specification(id, title, rule);
+        specification.set_qualification(module.qualification(degree_programme)?);
+        Ok(specification)
+    }
+
+    /// The ISCED-F field of education of this attainment,
+    /// derived from its study field URN. Used as the thematic area
+    /// of the learning specification in the credential.
+    fn iscedf_code(&self) -> Option<Code> {
+        ISCEDFField::from_study_field_urn(&self.study_field_urn).map(ISCEDFField::to_code)
+    }
+
+    /// The language of this attainment, derived from its language URN.
+    /// Used as the language of the learning specification in the credential,
+    /// which covers both instruction and assessment, as the crate does not
+    /// issue separate assessment specifications.
+    fn attainment_language(&self) -> Option<Language> {
+        std::convert::TryFrom::try_from(self.attainment_language_urn.as_str()).ok()
+    }
+
+    /// The validity of a credential for this attainment, which is valid from
+    /// the attainment date and expires with the attainment.
+    /// Used as the validity of the verifiable credential.
+    fn validity(&self) -> Result<ValidityPeriod, String> {
+        ValidityPeriod::from_dates(Some(self.attainment_date), self.expiry_date)
+    }
+
+    /// Wraps a credential issued for this attainment at the time `issued`
+    /// into a verifiable credential. Its identifier is derived from the attainment
+    /// and the time of issuance, and it is valid from the attainment date until the attainment expires.
+    fn verifiable_credential(
+        &self,
+        europass_credential: EuropassCredential,
+        issued: chrono::naive::NaiveDateTime,
+    ) -> Result<VerifiableCredential, String> {
+        Ok(VerifiableCredential::new(europass_credential, &self.id, issued, self.validity()?))
+    }
+
+    /// Revokes the credential issued for this attainment with the given identifier,
+    /// if SISU has marked the attainment as misregistered after issuance.
+    /// Returns whether the credential was revoked now, so that the attainments
+    /// can be checked again without revoking their credentials twice.
+    fn revoke_if_misregistered(
+        &self,
+        credential_id: &str,
+        registry: &mut RevocationRegistry,
+        revoked_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<bool, String> {
+        if !self.misregistration || registry.revocation(credential_id).is_some() {
+            return Ok(false);
+        }
+        let reason = match self.misregistration_rationale.trim() {
+            "" => String::from("The attainment was misregistered."),
+            rationale => format!("The attainment was misregistered: {}", rationale),
+        };
+        registry.revoke(credential_id, &reason, revoked_at).map(|_| true)
+    }
+}
+
 impl crate::ToEuropassCredential for SISUAttainment {
     fn to_europass_credential(
         attainment: SISUAttainment
@@ -191,12 +300,35 @@ impl<'de> serde::Deserialize<'de> for RoleURN {
     }
 }
 
-// A map <langCode, value> of plain strings containing localized versions of a text
+/// A map <langCode, value> of plain strings containing localized versions of a text.
+/// SISU typically provides English, Finnish and Swedish versions,
+/// but any of them may be missing and other languages may be present.
 #[derive(Deserialize)]
-struct LocalizedString {
-    en: String,
-    fi: String,
-    sv: String,
+#[serde(transparent)]
+struct LocalizedString(BTreeMap<String, String>);
+
+impl LocalizedString {
+    /// The version of the text in the language with the given code, if any.
+    fn get(&self, language: &str) -> Option<&str> {
+        self.0.get(language).map(String::as_str)
+    }
+
+    /// Converts this string into a multilingual Europass `Text`,
+    /// with the primary language picked according to `fallback`.
+    /// Versions in languages Europass texts cannot carry are left out
+    /// with a warning.
+    fn to_text(&self, fallback: &LanguageFallback) -> Text {
+        Text::from_translations(
+            self.0.iter().filter_map(|(language, content)| match language.parse::<EuropeanLanguage>() {
+                Ok(language) => Some((language, content.clone())),
+                Err(_) => {
+                    log::warn!("The translation {:?} is left out, as Europass texts do not support the language {}", content, language);
+                    None
+                }
+            }),
+            fallback,
+        )
+    }
 }
 
 /// Credit transfer information for an attainment that has been transferred.
@@ -281,12 +413,137 @@ enum AttainmentState {
 /// A type of attainment.
 #[derive(Deserialize)]
 #[serde(rename_all="PascalCase")]
+#[allow(clippy::enum_variant_names)]
 enum AttainmentType {
     AssessmentItemAttainment,
     CourseUnitAttainment,
     ModuleAttainment
 }
 
+/// A module returned from SISU, as far as it is needed to determine
+/// the qualification it leads to. Degree programmes lead to a full degree,
+/// whereas other modules form a part of one.
+#[derive(Deserialize)]
+#[serde(rename_all="camelCase")]
+struct SISUModule {
+    /// The type of the module.
+    #[serde(rename="type")]
+    module_type: ModuleType,
+    /// The titles of the degrees the module leads to, such as
+    /// `urn:code:degree-title:filosofian-maisteri`.
+    /// Only given for degree programmes.
+    #[serde(default)]
+    degree_title_urns: Vec<String>,
+    /// The education classification of the module according to
+    /// Statistics Finland, such as `urn:code:education-classification:712101`.
+    #[serde(default)]
+    education_classification_urn: Option<String>,
+}
+
+/// A type of module.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all="PascalCase")]
+enum ModuleType {
+    DegreeProgramme,
+    StudyModule,
+    GroupingModule,
+}
+
+/// Words in degree title URNs and the Finnish NQF level of the degrees
+/// whose titles contain them, checked in order. The licentiate degrees of
+/// medicine, dentistry and veterinary medicine are second cycle degrees,
+/// unlike the other licentiate degrees, and master’s degrees of universities
+/// of applied sciences contain the words of their bachelor’s degrees.
+const DEGREE_TITLE_LEVELS: &[(&str, FinlandNQF)] = &[
+    ("laaketieteen-lisensiaatti", FinlandNQF::Level7),
+    ("hammaslaaketieteen-lisensiaatti", FinlandNQF::Level7),
+    ("elainlaaketieteen-lisensiaatti", FinlandNQF::Level7),
+    ("medicine-licentiat", FinlandNQF::Level7),
+    ("odontologie-licentiat", FinlandNQF::Level7),
+    ("veterinarmedicine-licentiat", FinlandNQF::Level7),
+    ("licentiate-of-medicine", FinlandNQF::Level7),
+    ("licentiate-of-dentistry", FinlandNQF::Level7),
+    ("licentiate-of-veterinary-medicine", FinlandNQF::Level7),
+    ("ylempi-amk", FinlandNQF::Level7),
+    ("hogre-yh", FinlandNQF::Level7),
+    ("amk", FinlandNQF::Level6),
+    ("yh", FinlandNQF::Level6),
+    ("tohtori", FinlandNQF::Level8),
+    ("doktor", FinlandNQF::Level8),
+    ("doctor", FinlandNQF::Level8),
+    ("lisensiaatti", FinlandNQF::Level8),
+    ("licentiat", FinlandNQF::Level8),
+    ("licentiate", FinlandNQF::Level8),
+    ("maisteri", FinlandNQF::Level7),
+    ("magister", FinlandNQF::Level7),
+    ("master", FinlandNQF::Level7),
+    ("diplomi-insinoori", FinlandNQF::Level7),
+    ("arkkitehti", FinlandNQF::Level7),
+    ("proviisori", FinlandNQF::Level7),
+    ("kandidaatti", FinlandNQF::Level6),
+    ("kandidat", FinlandNQF::Level6),
+    ("bachelor", FinlandNQF::Level6),
+    ("farmaseutti", FinlandNQF::Level6),
+];
+
+/// The Finnish NQF level of the degree with the given title URN,
+/// such as level 7 for `urn:code:degree-title:filosofian-maisteri`.
+fn degree_title_level(urn: &str) -> Option<FinlandNQF> {
+    let title = urn.strip_prefix("urn:code:degree-title:")?.to_lowercase();
+    let words: Vec<&str> = title.split('-').collect();
+    DEGREE_TITLE_LEVELS
+        .iter()
+        .find(|(pattern, _)| {
+            let pattern: Vec<&str> = pattern.split('-').collect();
+            words.windows(pattern.len()).any(|window| window == pattern.as_slice())
+        })
+        .map(|(_, level)| *level)
+}
+
+/// The Finnish NQF level of the education with the given classification URN.
+/// The first digit of the classification gives the level of education,
+/// 6 for bachelor’s, 7 for master’s and 8 for doctoral level education.
+fn education_classification_level(urn: &str) -> Option<FinlandNQF> {
+    let code = urn.strip_prefix("urn:code:education-classification:")?;
+    match code.chars().next() {
+        Some('6') => Some(FinlandNQF::Level6),
+        Some('7') => Some(FinlandNQF::Level7),
+        Some('8') => Some(FinlandNQF::Level8),
+        _ => None,
+    }
+}
+
+impl SISUModule {
+    /// The Finnish NQF level of the degree this module leads to.
+    /// Degree titles take precedence over the education classification,
+    /// and a programme leading to several degrees, such as both a bachelor’s
+    /// and a master’s degree, is placed on the level of the highest one.
+    fn nqf_level(&self) -> Option<FinlandNQF> {
+        self.degree_title_urns
+            .iter()
+            .filter_map(|urn| degree_title_level(urn))
+            .max()
+            .or_else(|| {
+                self.education_classification_urn
+                    .as_deref()
+                    .and_then(education_classification_level)
+            })
+    }
+
+    /// The qualification this module leads to as a part of the given
+    /// degree programme, which may be this module itself. Modules other
+    /// than degree programmes are partial qualifications of the degree.
+    fn qualification(&self, degree_programme: &SISUModule) -> Result<Qualification, String> {
+        let level = self.nqf_level().or_else(|| degree_programme.nqf_level()).ok_or_else(|| {
+            format!(
+                "Could not determine the qualification level from degree titles {:?}…",
+                degree_programme.degree_title_urns
+            )
+        })?;
+        Qualification::from_nqf_level(self.module_type != ModuleType::DegreeProgramme, NQF::Finland(level))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -300,7 +557,7 @@ mod tests {
             ..
         } = &attainment;
         // Test acceptor_persons
-        let only_acceptor = match acceptor_persons.get(0) {
+        let only_acceptor = match acceptor_persons.first() {
             Some(acceptor) => acceptor,
             None => panic!("No acceptor found!")
         };
@@ -318,22 +575,12 @@ mod tests {
             _ => panic!("Wrong acceptor URN type!")
         }
         // Test for LocalizedStrings in acceptor
-        let crate::sisu_attainment::LocalizedString {
-            en,
-            fi,
-            sv
-        } = &text;
-        assert_eq!(en, "English version");
-        assert_eq!(fi, "Finnish version");
-        assert_eq!(sv, "Swedish version");
-        let crate::sisu_attainment::LocalizedString {
-            en,
-            fi,
-            sv
-        } = &title;
-        assert_eq!(en, "English version");
-        assert_eq!(fi, "Finnish version");
-        assert_eq!(sv, "Swedish version");
+        assert_eq!(text.get("en"), Some("English version"));
+        assert_eq!(text.get("fi"), Some("Finnish version"));
+        assert_eq!(text.get("sv"), Some("Swedish version"));
+        assert_eq!(title.get("en"), Some("English version"));
+        assert_eq!(title.get("fi"), Some("Finnish version"));
+        assert_eq!(title.get("sv"), Some("Swedish version"));
     }
     #[test]
     fn sisu_swagger_ui_example_additional_info () {
@@ -346,9 +593,25 @@ mod tests {
             ..
         } = &attainment;
         // Test additional_info
-        assert_eq!(additional_info.en, "English version");
-        assert_eq!(additional_info.fi, "Finnish version");
-        assert_eq!(additional_info.sv, "Swedish version");
+        assert_eq!(additional_info.get("en"), Some("English version"));
+        assert_eq!(additional_info.get("fi"), Some("Finnish version"));
+        assert_eq!(additional_info.get("sv"), Some("Swedish version"));
+    }
+    #[test]
+    fn sisu_localized_string_to_text () {
+        use crate::europass_learning_model::{EuropeanLanguage, LanguageFallback};
+        // SISU may omit languages and add others than English, Finnish and Swedish.
+        let localized: crate::sisu_attainment::LocalizedString = match serde_json::from_str(
+            r#"{ "fi": "Finnish version", "sv": "Swedish version", "de": "German version", "xx": "Unknown" }"#
+        ) {
+            Ok(localized) => localized,
+            Err(e) => panic!("Could not parse localized string: {}", e)
+        };
+        assert_eq!(localized.get("en"), None);
+        let text = localized.to_text(&LanguageFallback::default());
+        assert_eq!(text.primary(), Some((EuropeanLanguage::Finnish, "Finnish version")));
+        assert_eq!(text.get(EuropeanLanguage::German), Some("German version"));
+        assert_eq!(text.translations().count(), 3);
     }
     #[test]
     fn sisu_swagger_ui_example_attainment_date () {
@@ -361,7 +624,7 @@ mod tests {
             ..
         } = &attainment;
         // Test attainment_date
-        assert_eq!(attainment_date, &chrono::naive::NaiveDate::from_ymd(2019,1,1));
+        assert_eq!(attainment_date, &chrono::naive::NaiveDate::from_ymd_opt(2019,1,1).unwrap());
     }
     #[test]
     fn sisu_swagger_ui_example_attainment_language_urn () {
@@ -377,6 +640,24 @@ mod tests {
         assert_eq!(attainment_language_urn, "string");
     }
     #[test]
+    fn sisu_attainment_language () {
+        // Replace the placeholder language of the example with an actual one.
+        let example = SISU_SWAGGER_UI_EXAMPLE_RESPONSE.replace(
+            r#""attainmentLanguageUrn": "string""#,
+            r#""attainmentLanguageUrn": "urn:code:language:sv""#,
+        );
+        let attainment = parse_example_attainment(&example);
+        assert_eq!(attainment.attainment_language(), Some(crate::controlled_vocabularies::Language::Swedish));
+        let rule = crate::europass_learning_model::CreditWorkloadRule::default();
+        let title = || crate::europass_learning_model::Text::new(crate::europass_learning_model::EuropeanLanguage::English, "Course");
+        let specification = attainment.learning_specification("urn:course-unit:1", title(), &rule);
+        assert_eq!(specification.language(), Some(crate::controlled_vocabularies::Language::Swedish));
+        // The placeholder language URN is not a language.
+        let attainment = parse_example_attainment(SISU_SWAGGER_UI_EXAMPLE_RESPONSE);
+        assert_eq!(attainment.attainment_language(), None);
+        assert_eq!(attainment.learning_specification("urn:course-unit:1", title(), &rule).language(), None);
+    }
+    #[test]
     fn sisu_swagger_ui_example_credit_transfer_info () {
         // Parse example JSON response
         let attainment = parse_example_attainment(SISU_SWAGGER_UI_EXAMPLE_RESPONSE);
@@ -393,7 +674,7 @@ mod tests {
             international_institution_urn,
             organisation,
         } = &credit_transfer_info;
-        assert_eq!(credit_transfer_date, &chrono::naive::NaiveDate::from_ymd(2019,1,1));
+        assert_eq!(credit_transfer_date, &chrono::naive::NaiveDate::from_ymd_opt(2019,1,1).unwrap());
         assert_eq!(educational_institution_urn, "string");
         assert_eq!(international_institution_urn, "string");
         assert_eq!(organisation, "string");
@@ -414,6 +695,30 @@ mod tests {
         }
     }
     #[test]
+    fn sisu_swagger_ui_example_expected_workload () {
+        // Parse example JSON response
+        let attainment = parse_example_attainment(SISU_SWAGGER_UI_EXAMPLE_RESPONSE);
+        // Test the workload derived from the credits of the attainment.
+        let rule = crate::europass_learning_model::CreditWorkloadRule::default();
+        match attainment.expected_workload(&rule) {
+            Some(workload) => assert_eq!(workload.to_string(), "PT0S"),
+            None => panic!("No workload derived from credits!")
+        }
+        // A 5 credit course is worth 135 hours of work.
+        let attainment = parse_example_attainment(&SISU_SWAGGER_UI_EXAMPLE_RESPONSE.replace(r#""credits": 0"#, r#""credits": 5"#));
+        match attainment.expected_workload(&rule) {
+            Some(workload) => assert_eq!(workload.to_string(), "PT135H"),
+            None => panic!("No workload derived from credits!")
+        }
+        let specification = attainment.learning_specification(
+            "urn:course-unit:1",
+            crate::europass_learning_model::Text::new(crate::europass_learning_model::EuropeanLanguage::English, "Course"),
+            &rule,
+        );
+        assert_eq!(specification.ects_credit_points(), Some(5.0));
+        assert_eq!(specification.volume_of_learning().map(|workload| workload.to_string()), Some(String::from("PT135H")));
+    }
+    #[test]
     fn sisu_swagger_ui_example_document_state () {
         // Parse example JSON response
         let attainment = parse_example_attainment(SISU_SWAGGER_UI_EXAMPLE_RESPONSE);
@@ -440,7 +745,95 @@ mod tests {
             ..
         } = &attainment;
         // Test expiry_date
-        assert_eq!(expiry_date, &chrono::naive::NaiveDate::from_ymd(2019,1,1));
+        assert_eq!(expiry_date, &chrono::naive::NaiveDate::from_ymd_opt(2019,1,1));
+    }
+    #[test]
+    fn sisu_attainment_validity () {
+        let mut attainment = parse_example_attainment(SISU_SWAGGER_UI_EXAMPLE_RESPONSE);
+        let date = |y, m, d| chrono::naive::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        attainment.attainment_date = date(2024, 5, 31);
+        attainment.expiry_date = Some(date(2026, 5, 31));
+        let validity = match attainment.validity() {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(validity.valid_from(), date(2024, 5, 31).and_hms_opt(0, 0, 0));
+        assert_eq!(validity.expiration_date(), date(2026, 5, 31).and_hms_opt(0, 0, 0));
+        assert!(validity.is_valid_at(date(2026, 5, 30).and_hms_opt(23, 59, 59).unwrap()));
+        assert!(!validity.is_valid_at(date(2026, 5, 31).and_hms_opt(0, 0, 0).unwrap()));
+        // Most attainments do not expire.
+        attainment.expiry_date = None;
+        assert_eq!(attainment.validity().map(|v| v.expiration_date()), Ok(None));
+        attainment.expiry_date = Some(date(2024, 1, 1));
+        assert!(attainment.validity().is_err());
+    }
+    #[test]
+    fn sisu_verifiable_credential () {
+        use crate::controlled_vocabularies::CredentialType;
+        use crate::europass_learning_model::{CreditWorkloadRule, EuropassCredential, EuropeanLanguage, Organisation, Person, Text};
+        let mut attainment = parse_example_attainment(SISU_SWAGGER_UI_EXAMPLE_RESPONSE);
+        let date = |y, m, d| chrono::naive::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        attainment.attainment_date = date(2024, 5, 31);
+        attainment.expiry_date = None;
+        let credential = || {
+            EuropassCredential::new(
+                CredentialType::LearningActivity,
+                Text::new(EuropeanLanguage::English, "Course"),
+                Organisation::new("https://www.tuni.fi", Text::new(EuropeanLanguage::Finnish, "Tampereen yliopisto")),
+                Person::new(
+                    "urn:person:1",
+                    Text::new(EuropeanLanguage::Finnish, &attainment.person_first_names),
+                    Text::new(EuropeanLanguage::Finnish, &attainment.person_last_name),
+                    None,
+                ),
+            )
+        };
+        let issued = date(2024, 6, 1).and_hms_opt(12, 0, 0).unwrap();
+        let mut with_workload = credential();
+        with_workload.add_achievement(attainment.learning_specification(
+            "urn:course-unit:1",
+            Text::new(EuropeanLanguage::English, "Course"),
+            &CreditWorkloadRule::default(),
+        ));
+        let xml = match attainment.verifiable_credential(with_workload, issued).and_then(|vc| vc.to_xml()) {
+            Ok(xml) => xml,
+            Err(e) => panic!("{}", e),
+        };
+        assert!(xml.contains("<specifiedBy idref=\"urn:course-unit:1\"/>"), "{}", xml);
+        assert!(xml.contains("<volumeOfLearning>PT0S</volumeOfLearning><ectsCreditPoints>0</ectsCreditPoints>"), "{}", xml);
+        let verifiable_credential = match attainment.verifiable_credential(credential(), issued) {
+            Ok(vc) => vc,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(verifiable_credential.validity().valid_from(), date(2024, 5, 31).and_hms_opt(0, 0, 0));
+        assert_eq!(verifiable_credential.validity().expiration_date(), None);
+        // The identifier of a credential is derived from the attainment and the time of issuance,
+        // so an attainment issued again gets a new identifier and is not revoked with the credential it replaces.
+        let id = |issued| attainment.verifiable_credential(credential(), issued).map(|vc| vc.id().to_string());
+        assert_eq!(id(issued), Ok(verifiable_credential.id().to_string()));
+        let reissued = id(issued + chrono::Duration::days(1));
+        assert!(reissued.is_ok());
+        assert_ne!(reissued, Ok(verifiable_credential.id().to_string()));
+        let mut registry = crate::revocation::RevocationRegistry::new();
+        registry.revoke(verifiable_credential.id(), "The attainment was misregistered.", chrono::Utc::now()).unwrap();
+        assert!(registry.revocation(&reissued.unwrap()).is_none());
+    }
+    #[test]
+    fn sisu_misregistered_attainment_revocation () {
+        use chrono::TimeZone;
+        let mut attainment = parse_example_attainment(SISU_SWAGGER_UI_EXAMPLE_RESPONSE);
+        let mut registry = crate::revocation::RevocationRegistry::new();
+        let revoked_at = chrono::Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        attainment.misregistration_rationale = String::from("Registered for the wrong student.");
+        assert_eq!(attainment.revoke_if_misregistered("urn:credential:1", &mut registry, revoked_at), Ok(true));
+        assert_eq!(attainment.revoke_if_misregistered("urn:credential:1", &mut registry, revoked_at), Ok(false));
+        assert_eq!(
+            registry.revocation("urn:credential:1").map(|r| r.reason()),
+            Some("The attainment was misregistered: Registered for the wrong student.")
+        );
+        attainment.misregistration = false;
+        assert_eq!(attainment.revoke_if_misregistered("urn:credential:2", &mut registry, revoked_at), Ok(false));
+        assert_eq!(registry.records().len(), 1);
     }
     #[test]
     fn sisu_swagger_ui_example_grade_average () {
@@ -570,7 +963,7 @@ mod tests {
             ..
         } = &attainment;
         // Test organisations
-        let only_organisation = match organisations.get(0) {
+        let only_organisation = match organisations.first() {
             Some(org)   => org,
             None        => panic!("No organisations!")
         };
@@ -665,7 +1058,7 @@ mod tests {
             ..
         } = &attainment;
         // Test registration_date
-        assert_eq!(registration_date, &chrono::naive::NaiveDate::from_ymd(2019,1,1));
+        assert_eq!(registration_date, &chrono::naive::NaiveDate::from_ymd_opt(2019,1,1).unwrap());
     }
     #[test]
     fn sisu_swagger_ui_example_state () {
@@ -710,6 +1103,32 @@ mod tests {
         assert_eq!(study_field_urn, "string");
     }
     #[test]
+    fn sisu_study_field_urn_iscedf_code () {
+        // Replace the placeholder study field of the example with an actual one.
+        let example = SISU_SWAGGER_UI_EXAMPLE_RESPONSE.replace(
+            r#""studyFieldUrn": "string""#,
+            r#""studyFieldUrn": "urn:code:study-field:7""#,
+        );
+        let attainment = parse_example_attainment(&example);
+        // Test the ISCED-F code of the study field
+        match attainment.iscedf_code() {
+            Some(code) => {
+                assert_eq!(code.target_notation(), "061");
+                assert_eq!(code.uri(), "http://data.europa.eu/snb/isced-f/061");
+            },
+            None => panic!("No ISCED-F code for study field!")
+        }
+        // The field is the thematic area of the learning specification of the attainment.
+        let rule = crate::europass_learning_model::CreditWorkloadRule::default();
+        let title = || crate::europass_learning_model::Text::new(crate::europass_learning_model::EuropeanLanguage::English, "Course");
+        let specification = attainment.learning_specification("urn:course-unit:1", title(), &rule);
+        assert_eq!(specification.iscedf_code().map(|code| code.target_notation()), Some("061"));
+        // The placeholder study field does not map to any ISCED-F field.
+        let attainment = parse_example_attainment(SISU_SWAGGER_UI_EXAMPLE_RESPONSE);
+        assert!(attainment.iscedf_code().is_none());
+        assert!(attainment.learning_specification("urn:course-unit:1", title(), &rule).iscedf_code().is_none());
+    }
+    #[test]
     fn sisu_swagger_ui_example_study_right_id () {
         // Parse example JSON response
         let attainment = parse_example_attainment(SISU_SWAGGER_UI_EXAMPLE_RESPONSE);
@@ -775,15 +1194,14 @@ mod tests {
             Ok(att) => att,
             Err(e)  => panic!("Could not parse example SISU attainment: {}", e)
         };
-        let attainment = match att_vec.pop() {
+        match att_vec.pop() {
             Some(attainment) => attainment,
             None => panic!("No attainment in JSON array!")
-        };
-        attainment
+        }
     }
     /// The example JSON response found in the SISU Swagger UI:
     /// https://sis-tuni.funidata.fi/ori/swagger-ui.html#/attainment-controller/getAttainmentsUsingGET
-    const SISU_SWAGGER_UI_EXAMPLE_RESPONSE: &'static str =
+    const SISU_SWAGGER_UI_EXAMPLE_RESPONSE: &str =
         r#"[
               {
                 "acceptorPersons": [
@@ -853,4 +1271,102 @@ mod tests {
                 "verifierPersonId": "string"
               }
         ]"#;
+    #[test]
+    fn sisu_degree_title_qualification_levels () {
+        use crate::national_qualification_frameworks::FinlandNQF;
+        use crate::sisu_attainment::degree_title_level;
+        assert_eq!(degree_title_level("urn:code:degree-title:filosofian-kandidaatti"), Some(FinlandNQF::Level6));
+        assert_eq!(degree_title_level("urn:code:degree-title:insinoori-amk"), Some(FinlandNQF::Level6));
+        assert_eq!(degree_title_level("urn:code:degree-title:insinoori-ylempi-amk"), Some(FinlandNQF::Level7));
+        assert_eq!(degree_title_level("urn:code:degree-title:diplomi-insinoori"), Some(FinlandNQF::Level7));
+        assert_eq!(degree_title_level("urn:code:degree-title:laaketieteen-lisensiaatti"), Some(FinlandNQF::Level7));
+        assert_eq!(degree_title_level("urn:code:degree-title:hammaslaaketieteen-lisensiaatti"), Some(FinlandNQF::Level7));
+        assert_eq!(degree_title_level("urn:code:degree-title:elainlaaketieteen-lisensiaatti"), Some(FinlandNQF::Level7));
+        assert_eq!(degree_title_level("urn:code:degree-title:veterinarmedicine-licentiat"), Some(FinlandNQF::Level7));
+        assert_eq!(degree_title_level("urn:code:degree-title:odontologie-licentiat"), Some(FinlandNQF::Level7));
+        assert_eq!(degree_title_level("urn:code:degree-title:filosofian-lisensiaatti"), Some(FinlandNQF::Level8));
+        assert_eq!(degree_title_level("urn:code:degree-title:tekniikan-tohtori"), Some(FinlandNQF::Level8));
+        assert_eq!(degree_title_level("urn:code:degree-title:unknown"), None);
+        assert_eq!(degree_title_level("urn:code:study-field:7"), None);
+    }
+    #[test]
+    fn sisu_module_qualification () {
+        use crate::european_qualifications_framework::EQFLevel;
+        use crate::national_qualification_frameworks::{FinlandNQF, NQF};
+        use crate::sisu_attainment::SISUModule;
+        let programme: SISUModule = match serde_json::from_str(r#"{
+            "type": "DegreeProgramme",
+            "degreeTitleUrns": [
+                "urn:code:degree-title:tekniikan-kandidaatti",
+                "urn:code:degree-title:diplomi-insinoori"
+            ]
+        }"#) {
+            Ok(programme) => programme,
+            Err(e) => panic!("{}", e)
+        };
+        let qualification = match programme.qualification(&programme) {
+            Ok(qualification) => qualification,
+            Err(e) => panic!("{}", e)
+        };
+        assert_eq!(qualification.nqf_level(), NQF::Finland(FinlandNQF::Level7));
+        assert_eq!(qualification.eqf_level(), EQFLevel::Level7);
+        assert!(!qualification.is_partial_qualification());
+        // Other modules are partial qualifications of their degree programme.
+        let module: SISUModule = match serde_json::from_str(r#"{"type": "StudyModule"}"#) {
+            Ok(module) => module,
+            Err(e) => panic!("{}", e)
+        };
+        match module.qualification(&programme) {
+            Ok(qualification) => assert!(qualification.is_partial_qualification()),
+            Err(e) => panic!("{}", e)
+        }
+        // The education classification is used without degree titles.
+        let doctoral: SISUModule = match serde_json::from_str(r#"{
+            "type": "DegreeProgramme",
+            "educationClassificationUrn": "urn:code:education-classification:815101"
+        }"#) {
+            Ok(doctoral) => doctoral,
+            Err(e) => panic!("{}", e)
+        };
+        match doctoral.qualification(&doctoral) {
+            Ok(qualification) => assert_eq!(qualification.eqf_level(), EQFLevel::Level8),
+            Err(e) => panic!("{}", e)
+        }
+        assert!(module.qualification(&module).is_err());
+    }
+    #[test]
+    fn sisu_module_attainment_qualification_specification () {
+        use crate::europass_learning_model::{CreditWorkloadRule, EuropeanLanguage, Text};
+        use crate::european_qualifications_framework::EQFLevel;
+        use crate::sisu_attainment::{AttainmentType, SISUModule};
+        let mut attainment = parse_example_attainment(SISU_SWAGGER_UI_EXAMPLE_RESPONSE);
+        let programme: SISUModule = match serde_json::from_str(r#"{
+            "type": "DegreeProgramme",
+            "degreeTitleUrns": ["urn:code:degree-title:elainlaaketieteen-lisensiaatti"]
+        }"#) {
+            Ok(programme) => programme,
+            Err(e) => panic!("{}", e)
+        };
+        let specification = |attainment: &crate::sisu_attainment::SISUAttainment| attainment.qualification_specification(
+            "urn:degree-programme:1",
+            Text::new(EuropeanLanguage::English, "Licentiate of Veterinary Medicine"),
+            &CreditWorkloadRule::default(),
+            &programme,
+            &programme,
+        );
+        // The example is an assessment item attainment, which awards no qualification.
+        assert!(specification(&attainment).is_err());
+        attainment.attainment_type = AttainmentType::ModuleAttainment;
+        let specification = match specification(&attainment) {
+            Ok(specification) => specification,
+            Err(e) => panic!("{}", e)
+        };
+        match specification.qualification() {
+            Some(qualification) => {
+                assert_eq!(qualification.eqf_level(), EQFLevel::Level7);
+                assert!(!qualification.is_partial_qualification());
+            }
+            None => panic!("The specification has no qualification"),
+        }
+    }
 }
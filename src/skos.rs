@@ -0,0 +1,324 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+/*!
+This submodule reads concept schemes from SKOS RDF/XML files, such as
+the exports of the Named Authority Lists on the EU Vocabularies website.
+Only the parts of SKOS used for controlled vocabularies in Europass are read:
+the URIs, notations, preferred labels, English definitions, deprecation status
+and replacements of the concepts and the URI, preferred labels, definition
+and version of the concept scheme. Elements are recognised by their namespaces,
+whatever prefixes the file binds them to.
+
+The build script uses this module to generate the controlled vocabularies
+of the crate, so it may not refer to the rest of the crate.
+*/
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::io::BufRead;
+
+/// A concept scheme as read from a SKOS file.
+#[derive(Debug, Clone, Default)]
+pub struct ConceptScheme {
+    pub uri: String,
+    pub pref_labels: Vec<(String, String)>,
+    pub definition: Option<String>,
+    pub version: Option<String>,
+    /// The concepts of the scheme in document order.
+    pub concepts: Vec<Concept>,
+}
+
+/// A concept as read from a SKOS file.
+#[derive(Debug, Clone, Default)]
+pub struct Concept {
+    pub uri: String,
+    /// The notations of the concept with their datatypes, if given.
+    pub notations: Vec<(Option<String>, String)>,
+    /// The preferred labels of the concept with their language tags.
+    pub pref_labels: Vec<(String, String)>,
+    pub definition: Option<String>,
+    /// Whether the concept has been deprecated (`owl:deprecated`).
+    pub deprecated: bool,
+    /// The URIs of the concepts replacing this one (`dct:isReplacedBy`).
+    pub replaced_by: Vec<String>,
+}
+
+impl Concept {
+    /// The notation of the concept without a datatype.
+    pub fn notation(&self) -> Option<&str> {
+        self.notations
+            .iter()
+            .find(|(datatype, _)| datatype.is_none())
+            .map(|(_, notation)| notation.as_str())
+    }
+
+    /// The preferred label of the concept in the given language.
+    pub fn pref_label(&self, language: &str) -> Option<&str> {
+        pref_label(&self.pref_labels, language)
+    }
+}
+
+impl ConceptScheme {
+    /// The preferred label of the scheme in the given language.
+    pub fn pref_label(&self, language: &str) -> Option<&str> {
+        pref_label(&self.pref_labels, language)
+    }
+}
+
+fn pref_label<'a>(labels: &'a [(String, String)], language: &str) -> Option<&'a str> {
+    labels
+        .iter()
+        .find(|(tag, _)| tag.eq_ignore_ascii_case(language))
+        .map(|(_, label)| label.as_str())
+}
+
+/// The namespaces of the RDF vocabularies read from SKOS files.
+const RDF: &[u8] = b"http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const SKOS: &[u8] = b"http://www.w3.org/2004/02/skos/core#";
+const OWL: &[u8] = b"http://www.w3.org/2002/07/owl#";
+const DCT: &[u8] = b"http://purl.org/dc/terms/";
+/// The namespace of `xml:lang`, which is bound to the `xml` prefix without a declaration.
+const XML: &[u8] = b"http://www.w3.org/XML/1998/namespace";
+
+/// The URI of a SKOS class, as given in an `rdf:type`.
+fn skos_class(name: &str) -> String {
+    format!("{}{}", String::from_utf8_lossy(SKOS), name)
+}
+
+/// An attribute with its namespace resolved.
+struct Attribute {
+    namespace: Option<Vec<u8>>,
+    local_name: Vec<u8>,
+    value: String,
+}
+
+/// The attributes of an element with their namespaces resolved.
+fn attributes<R: BufRead>(reader: &Reader<R>, element: &BytesStart, namespaces: &[u8]) -> Result<Vec<Attribute>, String> {
+    let mut resolved = Vec::new();
+    for attribute in element.attributes() {
+        let attribute = attribute.map_err(|e| e.to_string())?;
+        let (namespace, local_name) = match attribute.key.split_at(attribute.key.len().min(4)) {
+            (b"xml:", local_name) => (Some(XML), local_name),
+            _ => reader.attribute_namespace(attribute.key, namespaces),
+        };
+        let value = attribute.unescaped_value().map_err(|e| e.to_string())?;
+        resolved.push(Attribute {
+            namespace: namespace.map(<[u8]>::to_vec),
+            local_name: local_name.to_vec(),
+            value: String::from_utf8(value.into_owned()).map_err(|e| e.to_string())?,
+        });
+    }
+    Ok(resolved)
+}
+
+fn attribute(attributes: &[Attribute], namespace: &[u8], local_name: &[u8]) -> Option<String> {
+    attributes
+        .iter()
+        .find(|attribute| attribute.namespace.as_deref() == Some(namespace) && attribute.local_name == local_name)
+        .map(|attribute| attribute.value.clone())
+}
+
+/// The value of a property of a node
+/// as (namespace, local name, language, datatype, value).
+type Property = (Vec<u8>, Vec<u8>, Option<String>, Option<String>, String);
+
+/// A node of the RDF graph described by a top level element of the document.
+#[derive(Default)]
+struct Node {
+    about: Option<String>,
+    types: Vec<String>,
+    /// The literal and resource values of the properties of the node.
+    properties: Vec<Property>,
+}
+
+impl Node {
+    fn literals<'a>(&'a self, namespace: &'a [u8], local_name: &'a [u8]) -> impl Iterator<Item = (&'a Option<String>, &'a Option<String>, &'a str)> + 'a {
+        self.properties
+            .iter()
+            .filter(move |(ns, name, _, _, _)| ns == namespace && name == local_name)
+            .map(|(_, _, language, datatype, value)| (language, datatype, value.as_str()))
+    }
+
+    fn uri(&self) -> Result<String, String> {
+        self.about
+            .clone()
+            .ok_or_else(|| format!("Could not read a node of types {:?} without an rdf:about…", self.types))
+    }
+
+    fn pref_labels(&self) -> Vec<(String, String)> {
+        self.literals(SKOS, b"prefLabel")
+            .map(|(language, _, label)| (language.clone().unwrap_or_default(), label.to_string()))
+            .collect()
+    }
+
+    fn definition(&self) -> Option<String> {
+        self.literals(SKOS, b"definition")
+            .find(|(language, _, _)| language.as_deref() == Some("en"))
+            .map(|(_, _, definition)| definition.to_string())
+    }
+}
+
+/// Reads the concept scheme of a SKOS RDF/XML document. The concepts and
+/// the scheme may be given either as `skos:Concept` and `skos:ConceptScheme`
+/// elements or as `rdf:Description` elements with an `rdf:type`, and with any
+/// namespace prefixes.
+pub fn read_concept_scheme<R: BufRead>(input: R) -> Result<ConceptScheme, String> {
+    let mut reader = Reader::from_reader(input);
+    let mut buf = Vec::new();
+    let mut namespaces = Vec::new();
+    let mut nodes: Vec<Node> = Vec::new();
+    // The depth of the current element below `rdf:RDF`.
+    let mut depth = 0;
+    let mut node: Option<Node> = None;
+    let mut property: Option<Property> = None;
+    loop {
+        let (namespace, event) = reader
+            .read_namespaced_event(&mut buf, &mut namespaces)
+            .map_err(|e| format!("Could not read SKOS at {}: {}", reader.buffer_position(), e))?;
+        let namespace = namespace.map(<[u8]>::to_vec);
+        match event {
+            Event::Start(ref element) | Event::Empty(ref element) => {
+                let empty = matches!(event, Event::Empty(_));
+                let attributes = attributes(&reader, element, &namespaces)?;
+                match depth {
+                    0 if namespace.as_deref() == Some(RDF) && element.local_name() == b"RDF" => {}
+                    0 => {
+                        return Err(format!(
+                            "Could not read SKOS from a document rooted at {}…",
+                            String::from_utf8_lossy(element.name())
+                        ))
+                    }
+                    1 => {
+                        let mut new_node = Node { about: attribute(&attributes, RDF, b"about"), ..Node::default() };
+                        if namespace.as_deref() != Some(RDF) || element.local_name() != b"Description" {
+                            let mut class = namespace.clone().unwrap_or_default();
+                            class.extend_from_slice(element.local_name());
+                            new_node.types.push(String::from_utf8_lossy(&class).into_owned());
+                        }
+                        if empty {
+                            nodes.push(new_node);
+                        } else {
+                            node = Some(new_node);
+                        }
+                    }
+                    2 => {
+                        let namespace = namespace.unwrap_or_default();
+                        let resource = attribute(&attributes, RDF, b"resource");
+                        match (node.as_mut(), resource) {
+                            (Some(node), Some(resource)) if namespace == RDF && element.local_name() == b"type" => {
+                                node.types.push(resource)
+                            }
+                            (Some(node), Some(resource)) => {
+                                node.properties.push((namespace, element.local_name().to_vec(), None, None, resource))
+                            }
+                            (_, None) if !empty => {
+                                property = Some((
+                                    namespace,
+                                    element.local_name().to_vec(),
+                                    attribute(&attributes, XML, b"lang"),
+                                    attribute(&attributes, RDF, b"datatype"),
+                                    String::new(),
+                                ))
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                }
+                if !empty {
+                    depth += 1;
+                }
+            }
+            Event::Text(ref text) | Event::CData(ref text) => {
+                if let Some((_, _, _, _, ref mut content)) = property {
+                    let text = match event {
+                        Event::CData(_) => String::from_utf8(text.to_vec()).map_err(|e| e.to_string())?,
+                        _ => text.unescape_and_decode(&reader).map_err(|e| e.to_string())?,
+                    };
+                    content.push_str(&text);
+                }
+            }
+            Event::End(_) => {
+                depth -= 1;
+                match depth {
+                    2 => {
+                        if let (Some(node), Some((namespace, local_name, language, datatype, content))) =
+                            (node.as_mut(), property.take())
+                        {
+                            node.properties.push((namespace, local_name, language, datatype, content.trim().to_string()));
+                        }
+                    }
+                    1 => nodes.extend(node.take()),
+                    _ => {}
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    let (concept_class, scheme_class) = (skos_class("Concept"), skos_class("ConceptScheme"));
+    let mut schemes = nodes.iter().filter(|node| node.types.contains(&scheme_class));
+    let scheme_node = schemes
+        .next()
+        .ok_or_else(|| String::from("Could not find a skos:ConceptScheme in SKOS…"))?;
+    if schemes.next().is_some() {
+        return Err(String::from("Could not read SKOS with more than one skos:ConceptScheme…"));
+    }
+    let mut scheme = ConceptScheme {
+        uri: scheme_node.uri()?,
+        pref_labels: scheme_node.pref_labels(),
+        definition: scheme_node.definition(),
+        version: scheme_node.literals(OWL, b"versionInfo").next().map(|(_, _, version)| version.to_string()),
+        concepts: Vec::new(),
+    };
+    for node in nodes.iter().filter(|node| node.types.contains(&concept_class)) {
+        scheme.concepts.push(Concept {
+            uri: node.uri()?,
+            notations: node
+                .literals(SKOS, b"notation")
+                .map(|(_, datatype, notation)| (datatype.clone(), notation.to_string()))
+                .collect(),
+            pref_labels: node.pref_labels(),
+            definition: node.definition(),
+            deprecated: node.literals(OWL, b"deprecated").any(|(_, _, value)| value == "true" || value == "1"),
+            replaced_by: node.literals(DCT, b"isReplacedBy").map(|(_, _, uri)| uri.to_string()).collect(),
+        });
+    }
+    Ok(scheme)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn namespaces_not_prefixes () {
+        let rdf = r#"<?xml version="1.0" encoding="UTF-8"?>
+<r:RDF xmlns:r="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+       xmlns:k="http://www.w3.org/2004/02/skos/core#"
+       xmlns:skos="http://example.org/not-skos#">
+  <r:Description r:about="http://example.org/scheme">
+    <r:type r:resource="http://www.w3.org/2004/02/skos/core#ConceptScheme"/>
+    <k:prefLabel xml:lang="en">Scheme</k:prefLabel>
+  </r:Description>
+  <k:Concept r:about="http://example.org/scheme/a">
+    <k:inScheme r:resource="http://example.org/scheme"/>
+    <k:notation>a</k:notation>
+    <k:prefLabel xml:lang="en">A</k:prefLabel>
+    <skos:prefLabel xml:lang="en">Not a label</skos:prefLabel>
+  </k:Concept>
+  <skos:Concept r:about="http://example.org/scheme/b">
+    <k:inScheme r:resource="http://example.org/scheme"/>
+  </skos:Concept>
+</r:RDF>"#;
+        let scheme = match read_concept_scheme(rdf.as_bytes()) {
+            Ok(scheme) => scheme,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(scheme.uri, "http://example.org/scheme");
+        assert_eq!(scheme.pref_label("en"), Some("Scheme"));
+        assert_eq!(scheme.concepts.len(), 1);
+        assert_eq!(scheme.concepts[0].notation(), Some("a"));
+        assert_eq!(scheme.concepts[0].pref_labels, vec![("en".to_string(), "A".to_string())]);
+    }
+}
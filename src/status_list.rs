@@ -0,0 +1,619 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+/*!
+This submodule implements the
+[W3C Bitstring Status List](https://www.w3.org/TR/vc-bitstring-status-list/),
+which scales the revocation of credentials to hundreds of thousands of
+course credentials: every issued credential is allocated an index in a list,
+and the status of the credential is the bit at that index.
+
+A [`BitstringStatusList`] is kept by the issuer in a local JSON file
+with its allocations and its bitstring in the compressed form it is published in,
+a multibase base64url encoded GZIP stream. Credentials refer to their bit
+with a `credentialStatus` entry, which [`StatusListEntry`] emits both
+in the JSON form of the W3C data model and as an XML element.
+The list itself is published as a `BitstringStatusListCredential`,
+in the JSON form of the W3C data model with [`BitstringStatusList::to_credential`]
+and sealed in an XML form with [`BitstringStatusList::publish`]:
+
+```xml
+<BitstringStatusListCredential id="https://example.org/status/1" issuer="https://example.org" validFrom="2025-06-01T00:00:00Z">
+  <credentialSubject id="https://example.org/status/1#list">
+    <statusPurpose>revocation</statusPurpose>
+    <encodedList>uH4sIAAAAAAAA…</encodedList>
+  </credentialSubject>
+  <ds:Signature>…</ds:Signature>
+</BitstringStatusListCredential>
+```
+
+A [`StatusListVerifier`] accepts only sealed lists whose seal is valid
+against a trust store, read with [`StatusListCredential::parse`].
+
+Indices are allocated at random among the free indices of a list, so that
+the index of a credential tells nothing of when it was issued. Lists are
+at least 131 072 bits long, as required by the specification for group privacy.
+*/
+
+use crate::controlled_vocabularies::{VerificationStatus, VerificationType};
+use crate::seal::{check_seal, seal, SealingKey, TrustStore};
+use crate::verification::{CredentialDocument, Verifier};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD as BASE64URL;
+use base64::Engine;
+use chrono::{DateTime, SecondsFormat, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use quick_xml::escape::escape;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::Path;
+
+/// The minimum length of a status list in bits.
+pub const MINIMUM_LENGTH: usize = 131_072;
+
+/// The purpose of a status list, which tells what a set bit means.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusPurpose {
+    /// A set bit revokes the credential permanently.
+    Revocation,
+    /// A set bit suspends the credential until the bit is cleared.
+    Suspension,
+}
+
+impl StatusPurpose {
+    /// The value of `statusPurpose` for this purpose.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatusPurpose::Revocation => "revocation",
+            StatusPurpose::Suspension => "suspension",
+        }
+    }
+
+    fn from_str(value: &str) -> Option<StatusPurpose> {
+        match value {
+            "revocation" => Some(StatusPurpose::Revocation),
+            "suspension" => Some(StatusPurpose::Suspension),
+            _ => None,
+        }
+    }
+}
+
+/// A status list kept by the issuer of the credentials it covers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitstringStatusList {
+    /// The URL the status list credential is published at.
+    id: String,
+    status_purpose: StatusPurpose,
+    /// The length of the list in bits.
+    length: usize,
+    /// The indices allocated to credentials by their identifiers.
+    allocations: BTreeMap<String, usize>,
+    /// The free indices in no particular order, rebuilt from the allocations when the list is read.
+    #[serde(skip)]
+    free: Vec<usize>,
+    /// The bitstring, kept in the file in its published form.
+    #[serde(rename = "encodedList", with = "encoded_list")]
+    bits: Vec<u8>,
+}
+
+/// Serializes a bitstring as its multibase encoded compressed form.
+mod encoded_list {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bits: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&super::encode(bits).map_err(serde::ser::Error::custom)?)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        super::decode(&encoded).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Compresses a bitstring with GZIP and encodes it as multibase base64url.
+fn encode(bits: &[u8]) -> Result<String, String> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder
+        .write_all(bits)
+        .and_then(|_| encoder.finish())
+        .map(|compressed| format!("u{}", BASE64URL.encode(compressed)))
+        .map_err(|e| format!("Could not compress a status list: {}…", e))
+}
+
+/// Decodes a multibase base64url encoded GZIP compressed bitstring.
+fn decode(encoded: &str) -> Result<Vec<u8>, String> {
+    let compressed = encoded
+        .strip_prefix('u')
+        .ok_or_else(|| String::from("Could not decode a status list that is not multibase base64url encoded…"))
+        .and_then(|content| {
+            BASE64URL
+                .decode(content)
+                .map_err(|e| format!("Could not decode a status list: {}…", e))
+        })?;
+    let mut bits = Vec::new();
+    GzDecoder::new(compressed.as_slice())
+        .read_to_end(&mut bits)
+        .map_err(|e| format!("Could not decompress a status list: {}…", e))?;
+    Ok(bits)
+}
+
+/// The bit at `index`, counting from the most significant bit of the first byte.
+fn bit(bits: &[u8], index: usize) -> Option<bool> {
+    bits.get(index / 8).map(|byte| byte & (0x80 >> (index % 8)) != 0)
+}
+
+impl BitstringStatusList {
+    /// Constructs an empty list of the given length in bits,
+    /// to be published at `id`.
+    pub fn new(id: &str, status_purpose: StatusPurpose, length: usize) -> Result<BitstringStatusList, String> {
+        if length < MINIMUM_LENGTH || !length.is_multiple_of(8) {
+            return Err(format!(
+                "Could not form a status list of {} bits: it must be a multiple of 8 of at least {} bits…",
+                length, MINIMUM_LENGTH
+            ));
+        }
+        Ok(BitstringStatusList {
+            id: id.to_string(),
+            status_purpose,
+            length,
+            allocations: BTreeMap::new(),
+            free: (0..length).collect(),
+            bits: vec![0; length / 8],
+        })
+    }
+
+    /// Reads a list from its JSON file.
+    pub fn load(path: &Path) -> Result<BitstringStatusList, String> {
+        let json =
+            fs::read_to_string(path).map_err(|e| format!("Could not read status list {}: {}…", path.display(), e))?;
+        let mut list: BitstringStatusList = serde_json::from_str(&json)
+            .map_err(|e| format!("Could not parse status list {}: {}…", path.display(), e))?;
+        if list.bits.len() * 8 != list.length {
+            return Err(format!("Could not read status list {}: its bitstring is not {} bits long…", path.display(), list.length));
+        }
+        let mut allocated = vec![false; list.length];
+        for (credential_id, &index) in &list.allocations {
+            if index >= list.length || std::mem::replace(&mut allocated[index], true) {
+                return Err(format!(
+                    "Could not read status list {}: index {} of credential {} is out of range or allocated twice…",
+                    path.display(),
+                    index,
+                    credential_id
+                ));
+            }
+        }
+        list.free = (0..list.length).filter(|&index| !allocated[index]).collect();
+        Ok(list)
+    }
+
+    /// Writes the list to its JSON file. The file is replaced only once
+    /// the new content has been written in full.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| format!("Could not serialize status list: {}…", e))?;
+        let temporary = path.with_extension("tmp");
+        fs::write(&temporary, json)
+            .map_err(|e| format!("Could not write status list {}: {}…", temporary.display(), e))?;
+        fs::rename(&temporary, path).map_err(|e| format!("Could not replace status list {}: {}…", path.display(), e))
+    }
+
+    /// The URL the list is published at.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The purpose of the list.
+    pub fn status_purpose(&self) -> StatusPurpose {
+        self.status_purpose
+    }
+
+    /// Allocates a random free index of the list to a credential
+    /// and returns its status entry. A credential keeps the index it was allocated first.
+    pub fn allocate(&mut self, credential_id: &str) -> Result<StatusListEntry, String> {
+        if let Some(entry) = self.entry(credential_id) {
+            return Ok(entry);
+        }
+        if self.free.is_empty() {
+            return Err(format!("Could not allocate an index for {}: status list {} is full…", credential_id, self.id));
+        }
+        let index = self.free.swap_remove(rand::thread_rng().gen_range(0..self.free.len()));
+        self.allocations.insert(credential_id.to_string(), index);
+        Ok(self.entry_at(index))
+    }
+
+    /// The status entry of a credential, if an index has been allocated to it.
+    pub fn entry(&self, credential_id: &str) -> Option<StatusListEntry> {
+        self.index(credential_id).map(|index| self.entry_at(index))
+    }
+
+    fn entry_at(&self, index: usize) -> StatusListEntry {
+        StatusListEntry {
+            status_purpose: self.status_purpose,
+            index,
+            status_list_credential: self.id.clone(),
+        }
+    }
+
+    fn index(&self, credential_id: &str) -> Option<usize> {
+        self.allocations.get(credential_id).copied()
+    }
+
+    /// Sets or clears the status bit of a credential.
+    pub fn set_status(&mut self, credential_id: &str, status: bool) -> Result<(), String> {
+        let index = self
+            .index(credential_id)
+            .ok_or_else(|| format!("Could not find credential {} in status list {}…", credential_id, self.id))?;
+        let mask = 0x80 >> (index % 8);
+        if status {
+            self.bits[index / 8] |= mask;
+        } else {
+            self.bits[index / 8] &= !mask;
+        }
+        Ok(())
+    }
+
+    /// The status bit of a credential, if an index has been allocated to it.
+    pub fn status(&self, credential_id: &str) -> Option<bool> {
+        self.index(credential_id).and_then(|index| bit(&self.bits, index))
+    }
+
+    /// The bitstring in its published form.
+    pub fn encoded_list(&self) -> Result<String, String> {
+        encode(&self.bits)
+    }
+
+    /// The status list credential of the list to be published at its URL,
+    /// issued by `issuer` and valid from `valid_from`.
+    pub fn to_credential(&self, issuer: &str, valid_from: DateTime<Utc>) -> Result<serde_json::Value, String> {
+        Ok(serde_json::json!({
+            "@context": ["https://www.w3.org/ns/credentials/v2"],
+            "id": self.id,
+            "type": ["VerifiableCredential", "BitstringStatusListCredential"],
+            "issuer": issuer,
+            "validFrom": valid_from.to_rfc3339_opts(SecondsFormat::Secs, true),
+            "credentialSubject": {
+                "id": format!("{}#list", self.id),
+                "type": "BitstringStatusList",
+                "statusPurpose": self.status_purpose.as_str(),
+                "encodedList": self.encoded_list()?,
+            },
+        }))
+    }
+
+    /// Publishes the status list credential of the list in its XML form,
+    /// issued by `issuer` and sealed with its key at `valid_from`.
+    pub fn publish(&self, issuer: &str, key: &SealingKey, valid_from: DateTime<Utc>) -> Result<String, String> {
+        let text = |value: &str| String::from_utf8_lossy(&escape(value.as_bytes())).into_owned();
+        let xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <BitstringStatusListCredential id=\"{id}\" issuer=\"{issuer}\" validFrom=\"{valid_from}\">\n\
+            <credentialSubject id=\"{id}#list\">\
+            <statusPurpose>{purpose}</statusPurpose>\
+            <encodedList>{encoded_list}</encodedList>\
+            </credentialSubject>\n\
+            </BitstringStatusListCredential>\n",
+            id = text(&self.id),
+            issuer = text(issuer),
+            valid_from = valid_from.to_rfc3339_opts(SecondsFormat::Secs, true),
+            purpose = self.status_purpose.as_str(),
+            encoded_list = self.encoded_list()?,
+        );
+        seal(&xml, key, valid_from)
+    }
+}
+
+/// Lists are equal when they have the same allocations and bits,
+/// whatever the order their free indices happen to be kept in.
+impl PartialEq for BitstringStatusList {
+    fn eq(&self, other: &BitstringStatusList) -> bool {
+        self.id == other.id
+            && self.status_purpose == other.status_purpose
+            && self.length == other.length
+            && self.allocations == other.allocations
+            && self.bits == other.bits
+    }
+}
+
+/// The `credentialStatus` entry of a credential in a status list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusListEntry {
+    status_purpose: StatusPurpose,
+    index: usize,
+    /// The URL of the status list credential.
+    status_list_credential: String,
+}
+
+impl StatusListEntry {
+    /// The identifier of the entry.
+    pub fn id(&self) -> String {
+        format!("{}#{}", self.status_list_credential, self.index)
+    }
+
+    /// The index of the credential in the list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The entry in the JSON form of the W3C data model.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "id": self.id(),
+            "type": "BitstringStatusListEntry",
+            "statusPurpose": self.status_purpose.as_str(),
+            "statusListIndex": self.index.to_string(),
+            "statusListCredential": self.status_list_credential,
+        })
+    }
+
+    /// The entry as a `credentialStatus` element of an XML credential.
+    pub fn to_xml(&self) -> String {
+        let text = |value: &str| String::from_utf8_lossy(&escape(value.as_bytes())).into_owned();
+        format!(
+            "<credentialStatus id=\"{}\">\
+            <type>BitstringStatusListEntry</type>\
+            <statusPurpose>{}</statusPurpose>\
+            <statusListIndex>{}</statusListIndex>\
+            <statusListCredential>{}</statusListCredential>\
+            </credentialStatus>",
+            text(&self.id()),
+            self.status_purpose.as_str(),
+            self.index,
+            text(&self.status_list_credential)
+        )
+    }
+}
+
+/// A status list read from a published status list credential.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusListCredential {
+    id: String,
+    status_purpose: StatusPurpose,
+    bits: Vec<u8>,
+}
+
+impl StatusListCredential {
+    /// Reads a status list credential published with [`BitstringStatusList::publish`],
+    /// whose seal must be valid at `as_of` against the trust store.
+    pub fn parse(xml: &str, trust_store: &TrustStore, as_of: DateTime<Utc>) -> Result<StatusListCredential, String> {
+        check_seal(xml, trust_store, as_of).map_err(|e| format!("Could not trust the status list credential: {}", e))?;
+        let credential = CredentialDocument::parse(xml)?;
+        if credential.document_element() != "BitstringStatusListCredential" {
+            return Err(String::from("Could not read a credential that is not a BitstringStatusListCredential…"));
+        }
+        let field = |path: &str| {
+            credential
+                .value(path)
+                .map(str::to_string)
+                .ok_or_else(|| format!("Could not find {} in the status list credential…", path))
+        };
+        let purpose = field("credentialSubject/statusPurpose")?;
+        let bits = decode(&field("credentialSubject/encodedList")?)?;
+        if bits.len() * 8 < MINIMUM_LENGTH {
+            return Err(format!(
+                "Could not read a status list of {} bits: it must be at least {} bits long…",
+                bits.len() * 8,
+                MINIMUM_LENGTH
+            ));
+        }
+        Ok(StatusListCredential {
+            id: field("@id")?,
+            status_purpose: StatusPurpose::from_str(&purpose)
+                .ok_or_else(|| format!("Could not read status purpose {}…", purpose))?,
+            bits,
+        })
+    }
+
+    /// The URL of the status list credential.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The status bit at `index`, if the list is that long.
+    pub fn status(&self, index: usize) -> Option<bool> {
+        bit(&self.bits, index)
+    }
+}
+
+/// Checks the `credentialStatus` entries of credentials against published status lists.
+#[derive(Default)]
+pub struct StatusListVerifier {
+    lists: Vec<StatusListCredential>,
+}
+
+impl StatusListVerifier {
+    /// Constructs a verifier without any status lists.
+    pub fn new() -> StatusListVerifier {
+        StatusListVerifier::default()
+    }
+
+    /// Adds a status list the credentials may refer to.
+    pub fn add(&mut self, list: StatusListCredential) {
+        self.lists.push(list);
+    }
+
+    /// The status of a single entry with a description.
+    fn entry_status(&self, list_id: &str, purpose: &str, index: &str) -> (VerificationStatus, String) {
+        let index = match index.trim().parse::<usize>() {
+            Ok(index) => index,
+            Err(_) => return (VerificationStatus::Red, format!("The status list index {} is not valid…", index)),
+        };
+        let list = self
+            .lists
+            .iter()
+            .find(|list| list.id == list_id && list.status_purpose.as_str() == purpose);
+        match list.map(|list| list.status(index)) {
+            None => (VerificationStatus::Gray, format!("The {} status list {} is not available…", purpose, list_id)),
+            Some(None) => (VerificationStatus::Red, format!("The status list {} has no index {}…", list_id, index)),
+            Some(Some(true)) => (
+                VerificationStatus::Red,
+                format!("The credential is marked for {} in status list {}…", purpose, list_id),
+            ),
+            Some(Some(false)) => (
+                VerificationStatus::Green,
+                format!("The credential is not marked for {} in status list {}.", purpose, list_id),
+            ),
+        }
+    }
+}
+
+impl Verifier for StatusListVerifier {
+    fn check_type(&self) -> VerificationType {
+        VerificationType::Revocation
+    }
+
+    fn name(&self) -> &str {
+        "status-list"
+    }
+
+    fn verify(&self, credential: &CredentialDocument, _as_of: DateTime<Utc>) -> (VerificationStatus, String) {
+        let list_ids: Vec<&str> = credential.values("credentialStatus/statusListCredential").collect();
+        let purposes: Vec<&str> = credential.values("credentialStatus/statusPurpose").collect();
+        let indices: Vec<&str> = credential.values("credentialStatus/statusListIndex").collect();
+        if list_ids.is_empty() {
+            return (VerificationStatus::Gray, String::from("The credential has no status list entries…"));
+        }
+        if list_ids.len() != purposes.len() || list_ids.len() != indices.len() {
+            return (VerificationStatus::Red, String::from("The status list entries of the credential are incomplete…"));
+        }
+        let statuses: Vec<(VerificationStatus, String)> = list_ids
+            .iter()
+            .zip(purposes.iter().zip(indices.iter()))
+            .map(|(list_id, (purpose, index))| self.entry_status(list_id, purpose, index))
+            .collect();
+        let status = [VerificationStatus::Red, VerificationStatus::Gray]
+            .iter()
+            .copied()
+            .find(|status| statuses.iter().any(|(s, _)| s == status))
+            .unwrap_or(VerificationStatus::Green);
+        let description = statuses.into_iter().map(|(_, d)| d).collect::<Vec<_>>().join(" ");
+        (status, description)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use chrono::TimeZone;
+
+    const LIST_ID: &str = "https://example.org/status/1";
+
+    #[test]
+    fn bitstring_status_list () {
+        assert!(BitstringStatusList::new(LIST_ID, StatusPurpose::Revocation, 1024).is_err());
+        let mut list = match BitstringStatusList::new(LIST_ID, StatusPurpose::Revocation, MINIMUM_LENGTH) {
+            Ok(l) => l,
+            Err(e) => panic!("{}", e),
+        };
+        let first = list.allocate("urn:credential:1").unwrap();
+        let second = list.allocate("urn:credential:2").unwrap();
+        assert_ne!(first.index(), second.index());
+        assert!(first.index() < MINIMUM_LENGTH && second.index() < MINIMUM_LENGTH);
+        assert_eq!(list.allocate("urn:credential:1"), Ok(first.clone()));
+        assert_eq!(
+            second.to_json(),
+            serde_json::json!({
+                "id": format!("https://example.org/status/1#{}", second.index()),
+                "type": "BitstringStatusListEntry",
+                "statusPurpose": "revocation",
+                "statusListIndex": second.index().to_string(),
+                "statusListCredential": LIST_ID,
+            })
+        );
+
+        assert_eq!(list.set_status("urn:credential:2", true), Ok(()));
+        assert!(list.set_status("urn:credential:3", true).is_err());
+        assert_eq!(list.status("urn:credential:1"), Some(false));
+        assert_eq!(list.status("urn:credential:2"), Some(true));
+        assert_eq!(list.bits.iter().map(|byte| byte.count_ones()).sum::<u32>(), 1);
+        assert_eq!(bit(&list.bits, second.index()), Some(true));
+        // The empty list compresses well below its 16 kB.
+        let encoded = list.encoded_list().unwrap();
+        assert!(encoded.starts_with('u') && encoded.len() < 200);
+        assert_eq!(decode(&encoded), Ok(list.bits.clone()));
+
+        let path = std::env::temp_dir().join(format!("status-list-{}.json", std::process::id()));
+        if let Err(e) = list.save(&path) {
+            panic!("{}", e);
+        }
+        let loaded = BitstringStatusList::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, Ok(list));
+
+        // A full list has no index to allocate, and every index is allocated once.
+        let mut full = BitstringStatusList::new(LIST_ID, StatusPurpose::Suspension, MINIMUM_LENGTH).unwrap();
+        for i in 0..MINIMUM_LENGTH {
+            if let Err(e) = full.allocate(&format!("urn:credential:{}", i)) {
+                panic!("{}", e);
+            }
+        }
+        assert!(full.free.is_empty());
+        let mut indices: Vec<usize> = full.allocations.values().copied().collect();
+        indices.sort_unstable();
+        assert!(indices.iter().copied().eq(0..MINIMUM_LENGTH));
+        assert!(full.allocate("urn:credential:full").is_err());
+    }
+
+    #[test]
+    fn status_list_verification () {
+        let mut list = BitstringStatusList::new(LIST_ID, StatusPurpose::Revocation, MINIMUM_LENGTH).unwrap();
+        let valid = list.allocate("urn:credential:1").unwrap();
+        let revoked = list.allocate("urn:credential:2").unwrap();
+        list.set_status("urn:credential:2", true).unwrap();
+        let key = match SealingKey::from_pem(
+            include_str!("../test_data/seal/seal-rsa.key"),
+            include_str!("../test_data/seal/seal-rsa.pem"),
+        ) {
+            Ok(k) => k,
+            Err(e) => panic!("{}", e),
+        };
+        let mut trust_store = TrustStore::new();
+        trust_store.add_pem(include_str!("../test_data/seal/ca.pem")).unwrap();
+        let published = match list.publish("https://example.org", &key, Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap()) {
+            Ok(p) => p,
+            Err(e) => panic!("{}", e),
+        };
+        let as_of = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        // Lists that have been tampered with or are not trusted are not read.
+        let encoded = list.encoded_list().unwrap();
+        let cleared = BitstringStatusList::new(LIST_ID, StatusPurpose::Revocation, MINIMUM_LENGTH)
+            .unwrap()
+            .encoded_list()
+            .unwrap();
+        assert!(StatusListCredential::parse(&published.replace(&encoded, &cleared), &trust_store, as_of).is_err());
+        assert!(StatusListCredential::parse(&published, &TrustStore::new(), as_of).is_err());
+        let unsealed = list.to_credential("https://example.org", as_of).unwrap().to_string();
+        assert!(StatusListCredential::parse(&unsealed, &trust_store, as_of).is_err());
+        // Lists shorter than the minimum length are not read, even when sealed.
+        let mut short = list.clone();
+        short.bits.truncate(1024);
+        let short = short.publish("https://example.org", &key, Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap()).unwrap();
+        assert!(StatusListCredential::parse(&short, &trust_store, as_of).is_err());
+        let mut verifier = StatusListVerifier::new();
+        match StatusListCredential::parse(&published, &trust_store, as_of) {
+            Ok(list) => verifier.add(list),
+            Err(e) => panic!("{}", e),
+        }
+
+        let credential = |status: &str| {
+            CredentialDocument::parse(&format!(
+                "<europassCredential xmlns=\"http://data.europa.eu/snb\" id=\"urn:credential:1\">{}</europassCredential>",
+                status
+            ))
+            .unwrap()
+        };
+        let status = |entry: &str| verifier.verify(&credential(entry), as_of).0;
+        assert_eq!(status(&valid.to_xml()), VerificationStatus::Green);
+        assert_eq!(status(&revoked.to_xml()), VerificationStatus::Red);
+        assert_eq!(status(""), VerificationStatus::Gray);
+        let unknown = BitstringStatusList::new("https://example.org/status/2", StatusPurpose::Revocation, MINIMUM_LENGTH)
+            .unwrap()
+            .allocate("urn:credential:1")
+            .unwrap();
+        assert_eq!(status(&unknown.to_xml()), VerificationStatus::Gray);
+        assert_eq!(status(&format!("{}{}", valid.to_xml(), revoked.to_xml())), VerificationStatus::Red);
+    }
+}
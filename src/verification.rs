@@ -0,0 +1,499 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+/*!
+This submodule verifies serialized Europass credentials,
+such as the credentials received from partner institutions.
+
+A [`VerificationPipeline`] runs a [`Verifier`] for each
+type of verification check against a credential and produces
+the [`VerificationCheck`]s of the Europass verification types
+Format, Seal, Validity, Revocation, Accreditation and Owner in this order,
+followed by the custom checks in the order they were registered.
+A standard check without a configured verifier is reported as gray,
+as are all checks of a credential that cannot be read.
+
+The format check, which requires the credential to be well-formed XML
+with an identified document element in the Europass namespace,
+is always configured. The validity check is defined here as well.
+The other verifiers are defined next to the data they need,
+such as the [seal verifier](crate::seal::SealVerifier),
+and custom checks are added by implementing [`Verifier`].
+*/
+
+use crate::controlled_vocabularies::{ControlledVocabulary, VerificationStatus, VerificationType};
+use crate::europass_learning_model::{EuropeanLanguage, Note, Text, ValidityPeriod, VerificationCheck};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// The namespace of Europass credential documents.
+pub const EUROPASS_NAMESPACE: &str = "http://data.europa.eu/snb";
+
+/// The standard verification types in the order their checks are reported.
+const STANDARD_CHECKS: [VerificationType; 6] = [
+    VerificationType::Format,
+    VerificationType::Seal,
+    VerificationType::Validity,
+    VerificationType::Revocation,
+    VerificationType::Accreditation,
+    VerificationType::Owner,
+];
+
+/// A check performed on credentials under verification.
+pub trait Verifier {
+    /// The type of the check. Checks outside the standard types
+    /// are of type `VerificationType::Custom`.
+    fn check_type(&self) -> VerificationType;
+
+    /// The name of the check, which identifies it among the checks of a credential.
+    /// Custom checks should give a name of their own.
+    fn name(&self) -> &str {
+        self.check_type().notation()
+    }
+
+    /// Checks the credential at the time `as_of` and
+    /// returns the status of the check with a description of the result.
+    fn verify(&self, credential: &CredentialDocument, as_of: DateTime<Utc>) -> (VerificationStatus, String);
+}
+
+/// A serialized credential under verification with the values read from it.
+#[derive(Debug, Clone)]
+pub struct CredentialDocument {
+    xml: String,
+    /// The local name of the document element.
+    document_element: String,
+    /// The namespace of the document element, if it has one.
+    namespace: Option<String>,
+    /// The trimmed text contents of the elements and the values of the attributes
+    /// in document order, with their paths of local names below the document element.
+    /// The paths of attributes end with `@` followed by the local name of the attribute.
+    values: Vec<(String, String)>,
+    /// The paths of the elements with the range of their values in `values`.
+    elements: Vec<(String, std::ops::Range<usize>)>,
+}
+
+/// An element of a credential under verification with the values at and below it.
+#[derive(Debug, Clone, Copy)]
+pub struct CredentialElement<'a> {
+    /// The path of the element below the document element.
+    path: &'a str,
+    /// The values of the element and of its descendants in document order.
+    values: &'a [(String, String)],
+}
+
+impl<'a> CredentialElement<'a> {
+    /// The values at the given path of local names below the element,
+    /// such as `eqfLevel/@uri`.
+    pub fn values(&self, path: &str) -> impl Iterator<Item = &'a str> + 'a {
+        let path = if self.path.is_empty() { path.to_string() } else { format!("{}/{}", self.path, path) };
+        self.values
+            .iter()
+            .filter(move |(value_path, _)| *value_path == path)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The first value at the given path below the element.
+    pub fn value(&self, path: &str) -> Option<&'a str> {
+        self.values(path).next()
+    }
+}
+
+impl CredentialDocument {
+    /// Reads a credential from its serialized XML form.
+    pub fn parse(xml: &str) -> Result<CredentialDocument, String> {
+        let mut reader = Reader::from_str(xml);
+        reader.check_end_names(true);
+        let mut buf = Vec::new();
+        let mut document = CredentialDocument {
+            xml: xml.to_string(),
+            document_element: String::new(),
+            namespace: None,
+            values: Vec::new(),
+            elements: Vec::new(),
+        };
+        // The paths, text contents and indices of the first values of the open elements.
+        let mut open: Vec<(String, String, usize)> = Vec::new();
+        loop {
+            let event = reader
+                .read_event(&mut buf)
+                .map_err(|e| format!("Could not read the credential at {}: {}…", reader.buffer_position(), e))?;
+            match event {
+                Event::Start(ref element) | Event::Empty(ref element) => {
+                    let name = String::from_utf8_lossy(element.name()).into_owned();
+                    let (prefix, local_name) = match name.split_once(':') {
+                        Some((prefix, local_name)) => (prefix, local_name),
+                        None => ("", name.as_str()),
+                    };
+                    let path = match open.last() {
+                        None if !document.document_element.is_empty() => {
+                            return Err(String::from("Could not read a credential with several document elements…"))
+                        }
+                        None => {
+                            document.document_element = local_name.to_string();
+                            String::new()
+                        }
+                        Some((parent, _, _)) if parent.is_empty() => local_name.to_string(),
+                        Some((parent, _, _)) => format!("{}/{}", parent, local_name),
+                    };
+                    let first = document.values.len();
+                    for attribute in element.attributes() {
+                        let attribute = attribute.map_err(|e| e.to_string())?;
+                        let key = String::from_utf8_lossy(attribute.key).into_owned();
+                        let value = attribute.unescape_and_decode_value(&reader).map_err(|e| e.to_string())?;
+                        if key == "xmlns" || key.starts_with("xmlns:") {
+                            if open.is_empty() && key.strip_prefix("xmlns").map(|p| p.trim_start_matches(':')) == Some(prefix) {
+                                document.namespace = Some(value);
+                            }
+                            continue;
+                        }
+                        let attribute_name = key.rsplit(':').next().unwrap_or_default();
+                        let attribute_path = if path.is_empty() {
+                            format!("@{}", attribute_name)
+                        } else {
+                            format!("{}/@{}", path, attribute_name)
+                        };
+                        document.values.push((attribute_path, value));
+                    }
+                    if matches!(event, Event::Start(_)) {
+                        open.push((path, String::new(), first));
+                    } else {
+                        document.elements.push((path, first..document.values.len()));
+                    }
+                }
+                Event::Text(ref text) | Event::CData(ref text) => {
+                    if let Some((_, content, _)) = open.last_mut() {
+                        content.push_str(&text.unescape_and_decode(&reader).map_err(|e| e.to_string())?);
+                    }
+                }
+                Event::End(_) => {
+                    if let Some((path, content, first)) = open.pop() {
+                        let content = content.trim();
+                        if !content.is_empty() {
+                            document.values.push((path.clone(), content.to_string()));
+                        }
+                        document.elements.push((path, first..document.values.len()));
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        if document.document_element.is_empty() {
+            return Err(String::from("Could not find a document element in the credential…"));
+        }
+        if !open.is_empty() {
+            return Err(String::from("Could not read the credential to its end: the document element is not closed…"));
+        }
+        Ok(document)
+    }
+
+    /// The serialized credential.
+    pub fn xml(&self) -> &str {
+        &self.xml
+    }
+
+    /// The identifier of the credential, that is the `id` attribute
+    /// of the document element, if it has one.
+    pub fn id(&self) -> Option<&str> {
+        self.value("@id")
+    }
+
+    /// The local name of the document element.
+    pub fn document_element(&self) -> &str {
+        &self.document_element
+    }
+
+    /// The namespace of the document element, if it has one.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// The values at the given path of local names below the document element,
+    /// such as `title/text` or `credentialSubject/@id`.
+    pub fn values<'a>(&'a self, path: &str) -> impl Iterator<Item = &'a str> + 'a {
+        let path = path.to_string();
+        self.values
+            .iter()
+            .filter(move |(value_path, _)| *value_path == path)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The first value at the given path.
+    pub fn value(&self, path: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(value_path, _)| value_path == path)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The elements at the given path of local names below the document element
+    /// in document order, such as each `credentialSubject/achievements/learningAchievement`,
+    /// so that the values of one element can be read apart from those of its siblings.
+    pub fn elements<'a>(&'a self, path: &str) -> impl Iterator<Item = CredentialElement<'a>> + 'a {
+        let path = path.to_string();
+        self.elements
+            .iter()
+            .filter(move |(element_path, _)| *element_path == path)
+            .map(move |(path, range)| CredentialElement { path: path.as_str(), values: &self.values[range.clone()] })
+    }
+}
+
+/// Checks that a credential is an identified Europass credential.
+pub struct FormatVerifier;
+
+impl Verifier for FormatVerifier {
+    fn check_type(&self) -> VerificationType {
+        VerificationType::Format
+    }
+
+    fn verify(&self, credential: &CredentialDocument, _as_of: DateTime<Utc>) -> (VerificationStatus, String) {
+        if credential.namespace() != Some(EUROPASS_NAMESPACE) {
+            return (
+                VerificationStatus::Red,
+                format!(
+                    "The document element {} is not in the Europass namespace {}…",
+                    credential.document_element(),
+                    EUROPASS_NAMESPACE
+                ),
+            );
+        }
+        match credential.id() {
+            Some(id) if !id.trim().is_empty() => (
+                VerificationStatus::Green,
+                String::from("The credential is well-formed and identified in the Europass namespace."),
+            ),
+            _ => (VerificationStatus::Red, String::from("The credential has no identifier…")),
+        }
+    }
+}
+
+/// Checks that a credential is valid at the time of verification
+/// according to its `validFrom` and `expirationDate`.
+pub struct ValidityVerifier;
+
+impl Verifier for ValidityVerifier {
+    fn check_type(&self) -> VerificationType {
+        VerificationType::Validity
+    }
+
+    fn verify(&self, credential: &CredentialDocument, as_of: DateTime<Utc>) -> (VerificationStatus, String) {
+        let date = |path| credential.value(path).map(parse_date_time).transpose();
+        let validity = date("validFrom")
+            .and_then(|valid_from| Ok((valid_from, date("expirationDate")?)))
+            .and_then(|(valid_from, expiration_date)| ValidityPeriod::new(valid_from, expiration_date));
+        match validity {
+            Ok(validity) => validity.status_at(as_of.naive_utc()),
+            Err(error) => (VerificationStatus::Red, error),
+        }
+    }
+}
+
+/// Reads an `xsd:dateTime` or an `xsd:date` as a time in UTC.
+/// Times without a time zone are taken to be in UTC and dates start at midnight.
+pub(crate) fn parse_date_time(value: &str) -> Result<NaiveDateTime, String> {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.naive_utc())
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f"))
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d").map(|date| date.and_time(NaiveTime::MIN)))
+        .map_err(|_| format!("Could not read date and time {}…", value))
+}
+
+/// Runs the configured verifiers against credentials.
+pub struct VerificationPipeline {
+    verifiers: Vec<Box<dyn Verifier>>,
+}
+
+impl Default for VerificationPipeline {
+    fn default() -> Self {
+        VerificationPipeline::new()
+    }
+}
+
+impl VerificationPipeline {
+    /// Constructs a pipeline with only the format check configured.
+    pub fn new() -> VerificationPipeline {
+        VerificationPipeline { verifiers: vec![Box::new(FormatVerifier)] }
+    }
+
+    /// Adds a verifier to the pipeline. Several verifiers
+    /// of the same type are run in the order they were registered.
+    pub fn register<V: Verifier + 'static>(&mut self, verifier: V) {
+        self.verifiers.push(Box::new(verifier));
+    }
+
+    /// Verifies a serialized credential at the time `as_of`.
+    pub fn run(&self, xml: &str, as_of: DateTime<Utc>) -> Vec<VerificationCheck> {
+        let check = |verifier_name: &str, check_type, subject: &str, status, description: &str| {
+            VerificationCheck::named(
+                verifier_name,
+                check_type,
+                subject,
+                status,
+                Note::new(Text::new(EuropeanLanguage::English, description)),
+            )
+        };
+        let credential = match CredentialDocument::parse(xml) {
+            Ok(credential) => credential,
+            Err(error) => {
+                let mut checks = vec![check("format", VerificationType::Format, "", VerificationStatus::Red, &error)];
+                let unread = "The credential could not be read, so it was not checked.";
+                for check_type in STANDARD_CHECKS.iter().skip(1) {
+                    checks.push(check(check_type.notation(), *check_type, "", VerificationStatus::Gray, unread));
+                }
+                for verifier in self.custom_verifiers() {
+                    checks.push(check(verifier.name(), VerificationType::Custom, "", VerificationStatus::Gray, unread));
+                }
+                return checks;
+            }
+        };
+        let subject = credential.id().unwrap_or_default();
+        let mut checks = Vec::new();
+        for check_type in STANDARD_CHECKS.iter() {
+            let mut configured = false;
+            for verifier in self.verifiers.iter().filter(|v| v.check_type() == *check_type) {
+                configured = true;
+                let (status, description) = verifier.verify(&credential, as_of);
+                checks.push(check(verifier.name(), *check_type, subject, status, &description));
+            }
+            if !configured {
+                let description = format!(
+                    "No {} check has been configured.",
+                    check_type.pref_label("en").unwrap_or_else(|| check_type.notation()).to_lowercase()
+                );
+                checks.push(check(check_type.notation(), *check_type, subject, VerificationStatus::Gray, &description));
+            }
+        }
+        for verifier in self.custom_verifiers() {
+            let (status, description) = verifier.verify(&credential, as_of);
+            checks.push(check(verifier.name(), VerificationType::Custom, subject, status, &description));
+        }
+        checks
+    }
+
+    fn custom_verifiers(&self) -> impl Iterator<Item = &dyn Verifier> {
+        self.verifiers
+            .iter()
+            .filter(|v| v.check_type() == VerificationType::Custom)
+            .map(|v| v.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use chrono::TimeZone;
+
+    const CREDENTIAL: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <europassCredential xmlns=\"http://data.europa.eu/snb\" id=\"urn:credential:1\">\n\
+        <title><text content-type=\"text/plain\" lang=\"en\">Bachelor of Science</text></title>\n\
+        <credentialSubject id=\"urn:person:1\"><givenNames>Maija</givenNames></credentialSubject>\n\
+        </europassCredential>\n";
+
+    /// A custom check requiring the credential to have a title.
+    struct TitleVerifier;
+
+    impl Verifier for TitleVerifier {
+        fn check_type(&self) -> VerificationType {
+            VerificationType::Custom
+        }
+
+        fn name(&self) -> &str {
+            "title"
+        }
+
+        fn verify(&self, credential: &CredentialDocument, _as_of: DateTime<Utc>) -> (VerificationStatus, String) {
+            match credential.value("title/text") {
+                Some(title) => (VerificationStatus::Green, format!("The credential is titled {}.", title)),
+                None => (VerificationStatus::Red, String::from("The credential has no title…")),
+            }
+        }
+    }
+
+    #[test]
+    fn credential_document_values () {
+        let credential = match CredentialDocument::parse(CREDENTIAL) {
+            Ok(c) => c,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(credential.document_element(), "europassCredential");
+        assert_eq!(credential.namespace(), Some(EUROPASS_NAMESPACE));
+        assert_eq!(credential.id(), Some("urn:credential:1"));
+        assert_eq!(credential.value("title/text"), Some("Bachelor of Science"));
+        assert_eq!(credential.value("title/text/@lang"), Some("en"));
+        assert_eq!(credential.value("credentialSubject/@id"), Some("urn:person:1"));
+        assert_eq!(credential.values("credentialSubject/givenNames").collect::<Vec<_>>(), vec!["Maija"]);
+        assert!(CredentialDocument::parse("<a><b></a>").is_err());
+
+        // The values of sibling elements are read apart, even when some are missing.
+        let credential = match CredentialDocument::parse("<a><b id=\"1\"/><b><c>2</c></b><b id=\"3\"><c>4</c></b></a>") {
+            Ok(c) => c,
+            Err(e) => panic!("{}", e),
+        };
+        let elements: Vec<(Option<&str>, Option<&str>)> =
+            credential.elements("b").map(|b| (b.value("@id"), b.value("c"))).collect();
+        assert_eq!(elements, vec![(Some("1"), None), (None, Some("2")), (Some("3"), Some("4"))]);
+    }
+
+    #[test]
+    fn verification_pipeline () {
+        let as_of = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let mut pipeline = VerificationPipeline::new();
+        pipeline.register(TitleVerifier);
+        let checks = pipeline.run(CREDENTIAL, as_of);
+        let summary: Vec<(VerificationType, VerificationStatus)> =
+            checks.iter().map(|c| (c.check_type(), c.status())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (VerificationType::Format, VerificationStatus::Green),
+                (VerificationType::Seal, VerificationStatus::Gray),
+                (VerificationType::Validity, VerificationStatus::Gray),
+                (VerificationType::Revocation, VerificationStatus::Gray),
+                (VerificationType::Accreditation, VerificationStatus::Gray),
+                (VerificationType::Owner, VerificationStatus::Gray),
+                (VerificationType::Custom, VerificationStatus::Green),
+            ]
+        );
+        assert!(checks.iter().all(|c| c.subject() == "urn:credential:1"));
+        assert_eq!(checks[6].id(), "urn:credential:1#verification-title");
+        assert_eq!(
+            checks[1].description().content().get(EuropeanLanguage::English),
+            Some("No seal check has been configured.")
+        );
+
+        let checks = pipeline.run("<europassCredential id=\"urn:credential:2\"/>", as_of);
+        assert_eq!(checks[0].status(), VerificationStatus::Red);
+        let checks = pipeline.run("<europassCredential", as_of);
+        assert_eq!(checks.len(), 7);
+        assert_eq!(checks[0].status(), VerificationStatus::Red);
+        assert!(checks[1..].iter().all(|c| c.status() == VerificationStatus::Gray));
+    }
+
+    #[test]
+    fn validity_verification () {
+        let credential = |dates: &str| {
+            CredentialDocument::parse(&format!(
+                "<europassCredential xmlns=\"http://data.europa.eu/snb\" id=\"urn:credential:1\">{}</europassCredential>",
+                dates
+            ))
+            .unwrap()
+        };
+        let as_of = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let status = |dates: &str| ValidityVerifier.verify(&credential(dates), as_of).0;
+        assert_eq!(status(""), VerificationStatus::Green);
+        assert_eq!(status("<validFrom>2025-06-01T09:30:00Z</validFrom>"), VerificationStatus::Green);
+        assert_eq!(
+            status("<validFrom>2025-06-01</validFrom><expirationDate>2027-06-01T00:00:00+03:00</expirationDate>"),
+            VerificationStatus::Green
+        );
+        assert_eq!(status("<validFrom>2026-01-01T00:00:01</validFrom>"), VerificationStatus::Red);
+        assert_eq!(status("<expirationDate>2026-01-01T02:00:00+02:00</expirationDate>"), VerificationStatus::Red);
+        assert_eq!(status("<expirationDate>tomorrow</expirationDate>"), VerificationStatus::Red);
+        assert_eq!(
+            ValidityVerifier.verify(&credential("<expirationDate>2025-12-31</expirationDate>"), as_of),
+            (VerificationStatus::Red, String::from("The credential expired at 2025-12-31 00:00:00…"))
+        );
+    }
+}
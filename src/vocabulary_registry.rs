@@ -0,0 +1,410 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+/*!
+This submodule defines a registry of concept schemes loaded at runtime,
+for classifications that are not published in the Named Authority Lists,
+such as the local subject classifications of faculties.
+The concepts of the registered schemes are emitted as Europass `Code`s.
+
+Concept schemes are loaded either from SKOS RDF/XML files, like the ones
+the controlled vocabularies of the crate are generated from, or from CSV files
+with a header row naming the columns:
+
+- `notation`: the notation of the concept (required),
+- `uri`: the URI of the concept, formed from the URI of the scheme and
+  the notation of the concept if the column is missing or empty,
+- `prefLabel@<language>`: the preferred label of the concept in the language
+  with the given ISO 639-1 code, e.g. `prefLabel@fi`,
+- `definition`: an English definition of the concept,
+- `deprecated`: `true` if the concept has been deprecated,
+- `replacedBy`: the notation of the concept replacing a deprecated one.
+
+Resolving a deprecated concept into a `Code` logs a warning;
+the current concept to use instead is given by
+[`VocabularyRegistry::replacement`].
+*/
+
+use crate::europass_learning_model::Code;
+use crate::skos::{Concept, ConceptScheme};
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+/// A registry of concept schemes, identified by their URIs.
+/// Loading a scheme with the URI of a registered scheme replaces it.
+#[derive(Debug, Default)]
+pub struct VocabularyRegistry {
+    schemes: Vec<ConceptScheme>,
+}
+
+impl VocabularyRegistry {
+    /// Constructs an empty registry.
+    pub fn new() -> VocabularyRegistry {
+        VocabularyRegistry::default()
+    }
+
+    /// Loads the concept scheme of a SKOS RDF/XML file
+    /// and returns the URI of the scheme.
+    pub fn load_skos(&mut self, path: &Path) -> Result<String, String> {
+        let file = std::fs::File::open(path)
+            .map_err(|e| format!("Could not open SKOS file {}: {}…", path.display(), e))?;
+        self.read_skos(BufReader::new(file))
+    }
+
+    /// Reads the concept scheme of a SKOS RDF/XML document
+    /// and returns the URI of the scheme.
+    pub fn read_skos<R: BufRead>(&mut self, input: R) -> Result<String, String> {
+        let scheme = crate::skos::read_concept_scheme(input)?;
+        let uri = scheme.uri.clone();
+        self.register(scheme);
+        Ok(uri)
+    }
+
+    /// Loads a concept scheme with the given URI and name from a CSV file.
+    pub fn load_csv(&mut self, path: &Path, scheme_uri: &str, scheme_name: &str) -> Result<(), String> {
+        let file = std::fs::File::open(path)
+            .map_err(|e| format!("Could not open CSV file {}: {}…", path.display(), e))?;
+        self.read_csv(file, scheme_uri, scheme_name)
+    }
+
+    /// Reads a concept scheme with the given URI and name from a CSV document.
+    pub fn read_csv<R: Read>(&mut self, mut input: R, scheme_uri: &str, scheme_name: &str) -> Result<(), String> {
+        let mut content = String::new();
+        input
+            .read_to_string(&mut content)
+            .map_err(|e| format!("Could not read CSV: {}…", e))?;
+        let mut records = csv_records(&content)?.into_iter();
+        let header = records
+            .next()
+            .ok_or_else(|| String::from("Could not find a header row in CSV…"))?;
+        let column = |name: &str| header.iter().position(|column| column.trim() == name);
+        let notation_column = column("notation")
+            .ok_or_else(|| String::from("Could not find a notation column in CSV…"))?;
+        let uri_column = column("uri");
+        let definition_column = column("definition");
+        let deprecated_column = column("deprecated");
+        let replaced_by_column = column("replacedBy");
+        let label_columns: Vec<(usize, String)> = header
+            .iter()
+            .enumerate()
+            .filter_map(|(index, column)| {
+                column
+                    .trim()
+                    .strip_prefix("prefLabel@")
+                    .map(|language| (index, language.to_string()))
+            })
+            .collect();
+
+        let mut scheme = ConceptScheme {
+            uri: scheme_uri.to_string(),
+            pref_labels: vec![(String::from("en"), scheme_name.to_string())],
+            ..ConceptScheme::default()
+        };
+        for (row, record) in records.enumerate() {
+            let field = |index: usize| record.get(index).map(|field| field.trim()).unwrap_or("");
+            let notation = field(notation_column);
+            if notation.is_empty() {
+                return Err(format!("Could not find a notation on row {} of CSV…", row + 2));
+            }
+            let uri = match uri_column.map(field) {
+                Some(uri) if !uri.is_empty() => uri.to_string(),
+                _ => concept_uri(scheme_uri, notation),
+            };
+            scheme.concepts.push(Concept {
+                uri,
+                notations: vec![(None, notation.to_string())],
+                pref_labels: label_columns
+                    .iter()
+                    .filter(|(index, _)| !field(*index).is_empty())
+                    .map(|(index, language)| (language.clone(), field(*index).to_string()))
+                    .collect(),
+                definition: definition_column.map(field).filter(|d| !d.is_empty()).map(String::from),
+                deprecated: deprecated_column.map(field) == Some("true"),
+                replaced_by: replaced_by_column
+                    .map(field)
+                    .filter(|notation| !notation.is_empty())
+                    .map(|notation| concept_uri(scheme_uri, notation))
+                    .into_iter()
+                    .collect(),
+            });
+        }
+        self.register(scheme);
+        Ok(())
+    }
+
+    /// The version of a registered concept scheme, if its source gives one.
+    pub fn scheme_version(&self, scheme_uri: &str) -> Result<Option<&str>, String> {
+        Ok(self.scheme(scheme_uri)?.version.as_deref())
+    }
+
+    fn register(&mut self, scheme: ConceptScheme) {
+        self.schemes.retain(|registered| registered.uri != scheme.uri);
+        self.schemes.push(scheme);
+    }
+
+    /// The URIs of the registered concept schemes.
+    pub fn scheme_uris(&self) -> Vec<&str> {
+        self.schemes.iter().map(|scheme| scheme.uri.as_str()).collect()
+    }
+
+    fn scheme(&self, scheme_uri: &str) -> Result<&ConceptScheme, String> {
+        self.schemes
+            .iter()
+            .find(|scheme| scheme.uri == scheme_uri)
+            .ok_or_else(|| format!("Could not find a registered concept scheme {}…", scheme_uri))
+    }
+
+    /// The code of the concept with the given notation in the given scheme,
+    /// named in the language with the given ISO 639-1 code
+    /// or in English if the concept has no label in that language.
+    pub fn code(&self, scheme_uri: &str, notation: &str, language: &str) -> Result<Code, String> {
+        let scheme = self.scheme(scheme_uri)?;
+        let concept = scheme
+            .concepts
+            .iter()
+            .find(|concept| concept.notation() == Some(notation))
+            .ok_or_else(|| format!("Could not find notation {} in concept scheme {}…", notation, scheme_uri))?;
+        Ok(to_code(scheme, concept, language))
+    }
+
+    /// Whether the concept with the given URI has been deprecated.
+    pub fn is_deprecated(&self, uri: &str) -> Result<bool, String> {
+        self.concept(uri).map(|(_, concept)| concept.deprecated)
+    }
+
+    /// The code of the current concept to use in place of the concept
+    /// with the given URI: the concept itself if it is not deprecated,
+    /// or else the first concept it is replaced by, followed through
+    /// any further replacements.
+    pub fn replacement(&self, uri: &str, language: &str) -> Result<Code, String> {
+        let mut current = self.concept(uri)?;
+        let concept_count: usize = self.schemes.iter().map(|scheme| scheme.concepts.len()).sum();
+        for _ in 0..concept_count {
+            if !current.1.deprecated {
+                return Ok(to_code(current.0, current.1, language));
+            }
+            let replaced_by = current
+                .1
+                .replaced_by
+                .first()
+                .ok_or_else(|| format!("The deprecated concept {} has no replacement…", uri))?;
+            current = self.concept(replaced_by)?;
+        }
+        Err(format!("The replacements of the concept {} form a cycle…", uri))
+    }
+
+    fn concept(&self, uri: &str) -> Result<(&ConceptScheme, &Concept), String> {
+        self.schemes
+            .iter()
+            .find_map(|scheme| {
+                scheme
+                    .concepts
+                    .iter()
+                    .find(|concept| concept.uri == uri)
+                    .map(|concept| (scheme, concept))
+            })
+            .ok_or_else(|| format!("Could not find a registered concept {}…", uri))
+    }
+
+    /// The code of the concept with the given URI in any registered scheme.
+    pub fn code_for_uri(&self, uri: &str, language: &str) -> Result<Code, String> {
+        self.concept(uri)
+            .map(|(scheme, concept)| to_code(scheme, concept, language))
+    }
+
+    /// Resolves a code given by its notation and framework URI against the
+    /// registered schemes, filling in the rest of the code. If the code is
+    /// named, the name must be one of the preferred labels of the concept.
+    pub fn resolve(&self, code: &Code, language: &str) -> Result<Code, String> {
+        let resolved = self.code(code.target_framework_uri(), code.target_notation(), language)?;
+        let scheme = self.scheme(code.target_framework_uri())?;
+        let known_name = scheme
+            .concepts
+            .iter()
+            .filter(|concept| concept.notation() == Some(code.target_notation()))
+            .flat_map(|concept| concept.pref_labels.iter())
+            .any(|(_, label)| label == code.target_name());
+        if code.target_name().is_empty() || known_name {
+            Ok(resolved)
+        } else {
+            Err(format!(
+                "The name {} does not match the concept {} in concept scheme {}…",
+                code.target_name(),
+                code.target_notation(),
+                code.target_framework_uri()
+            ))
+        }
+    }
+}
+
+fn concept_uri(scheme_uri: &str, notation: &str) -> String {
+    if scheme_uri.ends_with('/') || scheme_uri.ends_with('#') {
+        format!("{}{}", scheme_uri, notation)
+    } else {
+        format!("{}/{}", scheme_uri, notation)
+    }
+}
+
+/// Converts a concept into a code, warning if the concept has been deprecated.
+fn to_code(scheme: &ConceptScheme, concept: &Concept, language: &str) -> Code {
+    let notation = concept.notation().unwrap_or("");
+    if concept.deprecated {
+        log::warn!(
+            "The concept {} of {} (version {}) is deprecated",
+            notation,
+            scheme.uri,
+            scheme.version.as_deref().unwrap_or("unknown")
+        );
+    }
+    Code::new(
+        notation,
+        &scheme.uri,
+        scheme.pref_label("en").unwrap_or(""),
+        concept
+            .pref_label(language)
+            .or_else(|| concept.pref_label("en"))
+            .unwrap_or(notation),
+        concept.definition.as_deref().unwrap_or(""),
+        &concept.uri,
+    )
+}
+
+/// Splits CSV content into records of fields. Fields may be quoted with
+/// double quotes, in which case they may contain commas, line breaks and
+/// double quotes written twice. Empty lines are skipped.
+fn csv_records(content: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+            }
+            ',' if !quoted => record.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|field| !field.trim().is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+            }
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(String::from("Could not find the end of a quoted field in CSV…"));
+    }
+    record.push(field);
+    if record.iter().any(|field| !field.trim().is_empty()) {
+        records.push(record);
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::europass_learning_model::Code;
+    use crate::vocabulary_registry::VocabularyRegistry;
+
+    /// A local subject classification of a faculty, as a SKOS export.
+    const SUBJECTS_SKOS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rdf:RDF
+    xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+    xmlns:skos="http://www.w3.org/2004/02/skos/core#">
+  <skos:ConceptScheme rdf:about="https://example.tuni.fi/subjects">
+    <skos:prefLabel xml:lang="en">Subjects of the Faculty of Information Technology</skos:prefLabel>
+  </skos:ConceptScheme>
+  <skos:Concept rdf:about="https://example.tuni.fi/subjects/SE">
+    <skos:notation>SE</skos:notation>
+    <skos:prefLabel xml:lang="en">Software Engineering</skos:prefLabel>
+    <skos:prefLabel xml:lang="fi">Ohjelmistotuotanto</skos:prefLabel>
+  </skos:Concept>
+</rdf:RDF>"#;
+
+    /// The same kind of classification as a CSV file.
+    const SUBJECTS_CSV: &str = "notation,prefLabel@en,prefLabel@fi,definition\n\
+        MATH,Mathematics,Matematiikka,\n\
+        \"STAT\",\"Statistics, applied\",Tilastotiede,\"Statistics, incl. \"\"data science\"\"\"\n";
+
+    #[test]
+    fn registry_resolves_skos_concepts () {
+        let mut registry = VocabularyRegistry::new();
+        let scheme_uri = match registry.read_skos(SUBJECTS_SKOS.as_bytes()) {
+            Ok(uri) => uri,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(scheme_uri, "https://example.tuni.fi/subjects");
+        let code = match registry.code(&scheme_uri, "SE", "fi") {
+            Ok(code) => code,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(code.target_name(), "Ohjelmistotuotanto");
+        assert_eq!(code.target_framework(), "Subjects of the Faculty of Information Technology");
+        assert_eq!(code.uri(), "https://example.tuni.fi/subjects/SE");
+        // Labels missing in the requested language fall back to English.
+        assert_eq!(
+            registry.code_for_uri("https://example.tuni.fi/subjects/SE", "sv").map(|code| code.target_name().to_string()),
+            Ok(String::from("Software Engineering"))
+        );
+        assert!(registry.code(&scheme_uri, "XX", "en").is_err());
+        assert!(registry.code("https://example.tuni.fi/other", "SE", "en").is_err());
+    }
+    #[test]
+    fn registry_resolves_csv_concepts () {
+        let mut registry = VocabularyRegistry::new();
+        if let Err(e) = registry.read_csv(SUBJECTS_CSV.as_bytes(), "https://example.tuni.fi/science", "Natural sciences") {
+            panic!("{}", e)
+        }
+        let code = match registry.code("https://example.tuni.fi/science", "STAT", "en") {
+            Ok(code) => code,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(code.target_name(), "Statistics, applied");
+        assert_eq!(code.uri(), "https://example.tuni.fi/science/STAT");
+        // A code given by notation, framework and name is completed.
+        let partial = Code::new("MATH", "https://example.tuni.fi/science", "", "Matematiikka", "", "");
+        assert_eq!(
+            registry.resolve(&partial, "en").map(|code| code.uri().to_string()),
+            Ok(String::from("https://example.tuni.fi/science/MATH"))
+        );
+        let misnamed = Code::new("MATH", "https://example.tuni.fi/science", "", "Physics", "", "");
+        assert!(registry.resolve(&misnamed, "en").is_err());
+        // Loading a scheme again replaces it.
+        if let Err(e) = registry.read_csv("notation\nPHYS\n".as_bytes(), "https://example.tuni.fi/science", "Natural sciences") {
+            panic!("{}", e)
+        }
+        assert_eq!(registry.scheme_uris(), vec!["https://example.tuni.fi/science"]);
+        assert!(registry.code("https://example.tuni.fi/science", "MATH", "en").is_err());
+    }
+    #[test]
+    fn registry_replaces_deprecated_concepts () {
+        let mut registry = VocabularyRegistry::new();
+        let csv = "notation,prefLabel@en,deprecated,replacedBy\n\
+            OLD,Old subject,true,MID\n\
+            MID,Renamed subject,true,NEW\n\
+            NEW,Current subject,,\n\
+            GONE,Discontinued subject,true,\n";
+        if let Err(e) = registry.read_csv(csv.as_bytes(), "https://example.tuni.fi/subjects", "Subjects") {
+            panic!("{}", e)
+        }
+        assert_eq!(registry.is_deprecated("https://example.tuni.fi/subjects/OLD"), Ok(true));
+        assert_eq!(registry.is_deprecated("https://example.tuni.fi/subjects/NEW"), Ok(false));
+        assert_eq!(
+            registry
+                .replacement("https://example.tuni.fi/subjects/OLD", "en")
+                .map(|code| code.target_notation().to_string()),
+            Ok(String::from("NEW"))
+        );
+        assert!(registry.replacement("https://example.tuni.fi/subjects/GONE", "en").is_err());
+        // Deprecated concepts still resolve into codes.
+        assert!(registry.code("https://example.tuni.fi/subjects", "OLD", "en").is_ok());
+    }
+}
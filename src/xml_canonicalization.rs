@@ -0,0 +1,498 @@
+#![cfg_attr(debug_assertions, allow(dead_code, unused_imports))]
+/*!
+This submodule implements
+[Exclusive XML Canonicalization 1.0](https://www.w3.org/TR/xml-exc-c14n/)
+without comments, which is the canonicalization used by the XAdES
+signatures of sealed Europass credentials.
+
+Canonicalization turns logically equivalent XML documents into
+the same sequence of bytes, so that a digest computed over a document
+does not depend on how it happened to be serialized:
+the XML declaration, the document type declaration and comments are removed,
+line endings are normalized, empty elements are written with start and end tags,
+attributes are sorted, character data is escaped in a fixed way and
+a namespace declaration is only written on the elements that visibly use it.
+
+Only the parts of XML used by credentials are supported.
+Entities other than the predefined ones and character references
+cannot be declared, as document type declarations are dropped.
+*/
+
+use quick_xml::escape::unescape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// The namespace of XML digital signatures.
+pub const XMLDSIG_NAMESPACE: &str = "http://www.w3.org/2000/09/xmldsig#";
+
+/// The namespace bound to the prefix `xml` by definition.
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// The part of a document that is canonicalized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Selection<'a> {
+    /// The whole document.
+    Document,
+    /// The first element with the given `Id` attribute and its descendants.
+    Id(&'a str),
+    /// The first element with the given namespace URI and local name
+    /// and its descendants.
+    Element(&'a str, &'a str),
+    /// The element reached from the document element through the child
+    /// elements at the given zero-based positions, and its descendants.
+    /// The document element itself is reached through no positions.
+    Path(&'a [usize]),
+}
+
+/// Canonicalizes a whole XML document.
+pub fn canonicalize(xml: &str) -> Result<String, String> {
+    Canonicalizer::new(Selection::Document, None).run(xml)
+}
+
+/// Canonicalizes a whole XML document after removing the XML signature
+/// at the given path, as in `Selection::Path`, which is what the enveloped
+/// signature transform followed by exclusive canonicalization does
+/// for the signature being verified. Other signatures are kept.
+pub fn canonicalize_enveloped(xml: &str, signature: &[usize]) -> Result<String, String> {
+    Canonicalizer::new(Selection::Document, Some(signature)).run(xml)
+}
+
+/// Canonicalizes the selected element of an XML document.
+/// The namespaces declared by the ancestors of the element are taken into account.
+/// An `Id` given to more than one element cannot be selected.
+pub fn canonicalize_element(xml: &str, selection: Selection) -> Result<String, String> {
+    Canonicalizer::new(selection, None).run(xml)
+}
+
+/// An attribute of an element, as needed for canonicalization.
+struct Attribute {
+    prefix: String,
+    local_name: String,
+    qualified_name: String,
+    value: String,
+}
+
+struct Canonicalizer<'a> {
+    selection: Selection<'a>,
+    /// The path of the signature left out of the output, if any.
+    excluded: Option<&'a [usize]>,
+    output: String,
+    /// The namespace declarations of each open element.
+    scopes: Vec<Vec<(String, String)>>,
+    /// The namespace declarations written on each open element of the output.
+    rendered: Vec<Vec<(String, String)>>,
+    /// The depth of the selected element, once it has been found.
+    selected_depth: Option<usize>,
+    /// The depth of the signature being left out of the output, if any.
+    excluded_depth: Option<usize>,
+    /// Whether the signature to leave out has been found.
+    excluded_found: bool,
+    /// The positions of the open elements below the document element
+    /// among the child elements of their parents.
+    path: Vec<usize>,
+    /// The number of child elements read so far for each open element.
+    children: Vec<usize>,
+    /// Whether the document element has been read.
+    after_document_element: bool,
+    /// Whether the selected element has been written.
+    finished: bool,
+    /// Whether there is nothing left to read.
+    done: bool,
+}
+
+impl<'a> Canonicalizer<'a> {
+    fn new(selection: Selection<'a>, excluded: Option<&'a [usize]>) -> Self {
+        Canonicalizer {
+            selection,
+            excluded,
+            output: String::new(),
+            scopes: Vec::new(),
+            rendered: Vec::new(),
+            selected_depth: if selection == Selection::Document { Some(0) } else { None },
+            excluded_depth: None,
+            excluded_found: false,
+            path: Vec::new(),
+            children: Vec::new(),
+            after_document_element: false,
+            finished: false,
+            done: false,
+        }
+    }
+
+    fn run(mut self, xml: &str) -> Result<String, String> {
+        let xml = xml.replace("\r\n", "\n").replace('\r', "\n");
+        let mut reader = Reader::from_str(&xml);
+        reader.check_end_names(true);
+        let mut buf = Vec::new();
+        while !self.done {
+            let event = reader
+                .read_event(&mut buf)
+                .map_err(|e| format!("Could not read XML at {}: {}…", reader.buffer_position(), e))?;
+            match event {
+                Event::Start(ref element) => self.start(element, false)?,
+                Event::Empty(ref element) => self.start(element, true)?,
+                Event::End(ref element) => self.end(&utf8(element.name())?),
+                Event::Text(ref text) | Event::CData(ref text) => {
+                    let text = text.unescaped().map_err(|e| e.to_string())?;
+                    self.text(&utf8(&text)?);
+                }
+                Event::PI(ref instruction) => self.processing_instruction(&utf8(instruction.escaped())?),
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        if let (Some(path), false) = (self.excluded, self.excluded_found) {
+            return Err(format!("Could not find the signature at {:?} to leave out…", path));
+        }
+        match self.selection {
+            Selection::Document if !self.after_document_element => {
+                Err(String::from("Could not find a document element to canonicalize…"))
+            }
+            Selection::Id(id) if !self.finished => Err(format!("Could not find an element with Id {}…", id)),
+            Selection::Element(namespace, name) if !self.finished => {
+                Err(format!("Could not find an element {{{}}}{} to canonicalize…", namespace, name))
+            }
+            Selection::Path(path) if !self.finished => Err(format!("Could not find an element at {:?} to canonicalize…", path)),
+            _ => Ok(self.output),
+        }
+    }
+
+    /// Whether the current position is inside the selected part of the document
+    /// and not inside an excluded signature.
+    fn is_output(&self) -> bool {
+        self.selected_depth.is_some() && !self.finished && self.excluded_depth.is_none()
+    }
+
+    fn lookup(&self, prefix: &str) -> Option<&str> {
+        if prefix == "xml" {
+            return Some(XML_NAMESPACE);
+        }
+        self.scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter())
+            .find(|(declared, _)| declared == prefix)
+            .map(|(_, namespace)| namespace.as_str())
+    }
+
+    fn rendered_namespace(&self, prefix: &str) -> &str {
+        self.rendered
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter())
+            .find(|(declared, _)| declared == prefix)
+            .map(|(_, namespace)| namespace.as_str())
+            .unwrap_or("")
+    }
+
+    fn namespace(&self, prefix: &str) -> Result<String, String> {
+        match self.lookup(prefix) {
+            Some(namespace) => Ok(namespace.to_string()),
+            None if prefix.is_empty() => Ok(String::new()),
+            None => Err(format!("Could not find a declaration for namespace prefix {}…", prefix)),
+        }
+    }
+
+    fn start(&mut self, element: &BytesStart, is_empty: bool) -> Result<(), String> {
+        let name = utf8(element.name())?;
+        let mut declarations = Vec::new();
+        let mut attributes = Vec::new();
+        for attribute in element.attributes() {
+            let attribute = attribute.map_err(|e| e.to_string())?;
+            let key = utf8(attribute.key)?;
+            let value = attribute_value(&attribute.value)?;
+            if key == "xmlns" {
+                declarations.push((String::new(), value));
+            } else if let Some(prefix) = key.strip_prefix("xmlns:") {
+                declarations.push((prefix.to_string(), value));
+            } else {
+                let (prefix, local_name) = split_name(&key);
+                attributes.push(Attribute {
+                    prefix: prefix.to_string(),
+                    local_name: local_name.to_string(),
+                    qualified_name: key.clone(),
+                    value,
+                });
+            }
+        }
+        if let Some(position) = self.children.last_mut() {
+            self.path.push(*position);
+            *position += 1;
+        }
+        self.children.push(0);
+        self.scopes.push(declarations);
+        self.after_document_element = true;
+        let depth = self.scopes.len();
+        let (prefix, local_name) = split_name(&name);
+        let namespace = self.namespace(prefix)?;
+
+        let has_id = |id: &str| {
+            attributes
+                .iter()
+                .any(|a| a.prefix.is_empty() && matches!(a.local_name.as_str(), "Id" | "ID" | "id") && a.value == id)
+        };
+        let selected = match self.selection {
+            Selection::Document => true,
+            Selection::Id(id) => has_id(id),
+            Selection::Element(selected_namespace, selected_name) => {
+                namespace == selected_namespace && local_name == selected_name
+            }
+            Selection::Path(path) => self.path == path,
+        };
+        if selected && self.selected_depth.is_none() {
+            self.selected_depth = Some(depth - 1);
+        } else if let (true, Selection::Id(id)) = (selected, self.selection) {
+            return Err(format!("Could not select Id {}, which is given to more than one element…", id));
+        }
+        if self.excluded == Some(self.path.as_slice()) {
+            if namespace != XMLDSIG_NAMESPACE || local_name != "Signature" {
+                return Err(format!("Could not leave out the element {} at {:?}, which is not a signature…", name, self.path));
+            }
+            self.excluded_depth = Some(depth - 1);
+            self.excluded_found = true;
+        }
+
+        if self.is_output() {
+            self.write_start_tag(&name, prefix, attributes)?;
+        }
+        if is_empty {
+            self.end(&name);
+        }
+        Ok(())
+    }
+
+    fn write_start_tag(&mut self, name: &str, prefix: &str, mut attributes: Vec<Attribute>) -> Result<(), String> {
+        let mut prefixes = vec![prefix.to_string()];
+        prefixes.extend(
+            attributes
+                .iter()
+                .filter(|a| !a.prefix.is_empty() && a.prefix != "xml")
+                .map(|a| a.prefix.clone()),
+        );
+        prefixes.sort();
+        prefixes.dedup();
+        let mut declarations = Vec::new();
+        for prefix in prefixes {
+            let namespace = self.namespace(&prefix)?;
+            if namespace != self.rendered_namespace(&prefix) {
+                declarations.push((prefix, namespace));
+            }
+        }
+
+        let mut keyed_attributes = Vec::new();
+        for attribute in attributes.drain(..) {
+            let namespace = if attribute.prefix.is_empty() {
+                String::new()
+            } else {
+                self.namespace(&attribute.prefix)?
+            };
+            keyed_attributes.push((namespace, attribute));
+        }
+        keyed_attributes.sort_by(|(a_namespace, a), (b_namespace, b)| {
+            (a_namespace, &a.local_name).cmp(&(b_namespace, &b.local_name))
+        });
+
+        self.output.push('<');
+        self.output.push_str(name);
+        for (prefix, namespace) in &declarations {
+            if prefix.is_empty() {
+                self.output.push_str(" xmlns=\"");
+            } else {
+                self.output.push_str(" xmlns:");
+                self.output.push_str(prefix);
+                self.output.push_str("=\"");
+            }
+            self.output.push_str(&escape_attribute(namespace));
+            self.output.push('"');
+        }
+        for (_, attribute) in &keyed_attributes {
+            self.output.push(' ');
+            self.output.push_str(&attribute.qualified_name);
+            self.output.push_str("=\"");
+            self.output.push_str(&escape_attribute(&attribute.value));
+            self.output.push('"');
+        }
+        self.output.push('>');
+        self.rendered.push(declarations);
+        Ok(())
+    }
+
+    fn end(&mut self, name: &str) {
+        let depth = self.scopes.len().saturating_sub(1);
+        if self.is_output() {
+            self.output.push_str("</");
+            self.output.push_str(name);
+            self.output.push('>');
+            self.rendered.pop();
+        }
+        if self.excluded_depth == Some(depth) {
+            self.excluded_depth = None;
+        }
+        if self.selected_depth == Some(depth) && self.selection != Selection::Document && !self.finished {
+            self.finished = true;
+            // The rest of the document is still read for other elements with the same Id.
+            self.done = !matches!(self.selection, Selection::Id(_));
+        }
+        self.scopes.pop();
+        self.children.pop();
+        if !self.scopes.is_empty() {
+            self.path.pop();
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.is_output() && !self.scopes.is_empty() {
+            self.output.push_str(&escape_text(text));
+        }
+    }
+
+    fn processing_instruction(&mut self, instruction: &str) {
+        if !self.is_output() {
+            return;
+        }
+        if self.scopes.is_empty() {
+            // Processing instructions outside the document element
+            // are separated from it by line feeds.
+            if self.after_document_element {
+                self.output.push('\n');
+            }
+            self.output.push_str(&format!("<?{}?>", instruction));
+            if !self.after_document_element {
+                self.output.push('\n');
+            }
+        } else {
+            self.output.push_str(&format!("<?{}?>", instruction));
+        }
+    }
+}
+
+fn utf8(bytes: &[u8]) -> Result<String, String> {
+    String::from_utf8(bytes.to_vec()).map_err(|e| format!("Could not read XML as UTF-8: {}…", e))
+}
+
+/// Splits a qualified name into its prefix and local name.
+fn split_name(name: &str) -> (&str, &str) {
+    match name.split_once(':') {
+        Some((prefix, local_name)) => (prefix, local_name),
+        None => ("", name),
+    }
+}
+
+/// The normalized value of an attribute: literal whitespace characters
+/// are replaced with spaces before references are resolved.
+fn attribute_value(raw: &[u8]) -> Result<String, String> {
+    let normalized: Vec<u8> = raw
+        .iter()
+        .map(|&b| if matches!(b, b'\t' | b'\n' | b'\r') { b' ' } else { b })
+        .collect();
+    let value = unescape(&normalized).map_err(|e| format!("Could not unescape attribute value: {}…", e))?;
+    utf8(&value)
+}
+
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '\r' => escaped.push_str("&#xD;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' => escaped.push_str("&#x9;"),
+            '\n' => escaped.push_str("&#xA;"),
+            '\r' => escaped.push_str("&#xD;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn canonical_document () {
+        let xml = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\r\n\
+            <!-- A comment -->\r\n\
+            <doc xmlns=\"http://example.org/default\" xmlns:unused=\"http://example.org/unused\" b='2' a=\"1 &amp; &#x9;tab\">\r\n\
+            <e1   />\r\n\
+            <e2 xmlns:p=\"http://example.org/p\" p:z=\"z\" c=\"&quot;x&quot;\"><p:e3>&lt;&gt;&amp; \"quoted\"</p:e3></e2>\r\n\
+            <![CDATA[<cdata> & more]]>\r\n\
+            </doc>\r\n\
+            <!-- Another comment -->";
+        let canonical = match canonicalize(xml) {
+            Ok(c) => c,
+            Err(e) => panic!("{}", e),
+        };
+        assert_eq!(
+            canonical,
+            "<doc xmlns=\"http://example.org/default\" a=\"1 &amp; &#x9;tab\" b=\"2\">\n\
+            <e1></e1>\n\
+            <e2 xmlns:p=\"http://example.org/p\" c=\"&quot;x&quot;\" p:z=\"z\"><p:e3>&lt;&gt;&amp; \"quoted\"</p:e3></e2>\n\
+            &lt;cdata&gt; &amp; more\n\
+            </doc>"
+        );
+    }
+
+    #[test]
+    fn canonical_elements () {
+        let xml = "<root xmlns:ds=\"http://www.w3.org/2000/09/xmldsig#\" xmlns:a=\"http://example.org/a\">\
+            <a:data Id=\"data\"><a:value>1</a:value></a:data>\
+            <ds:Signature><ds:SignedInfo>info</ds:SignedInfo></ds:Signature>\
+            </root>";
+        assert_eq!(
+            canonicalize_element(xml, Selection::Id("data")),
+            Ok(String::from("<a:data xmlns:a=\"http://example.org/a\" Id=\"data\"><a:value>1</a:value></a:data>"))
+        );
+        assert_eq!(
+            canonicalize_element(xml, Selection::Element(XMLDSIG_NAMESPACE, "SignedInfo")),
+            Ok(String::from("<ds:SignedInfo xmlns:ds=\"http://www.w3.org/2000/09/xmldsig#\">info</ds:SignedInfo>"))
+        );
+        assert_eq!(
+            canonicalize_element(xml, Selection::Path(&[0, 0])),
+            Ok(String::from("<a:value xmlns:a=\"http://example.org/a\">1</a:value>"))
+        );
+        assert_eq!(
+            canonicalize_enveloped(xml, &[1]),
+            Ok(String::from(
+                "<root><a:data xmlns:a=\"http://example.org/a\" Id=\"data\"><a:value>1</a:value></a:data></root>"
+            ))
+        );
+        assert!(canonicalize_enveloped(xml, &[0]).is_err());
+        assert!(canonicalize_enveloped(xml, &[2]).is_err());
+        assert!(canonicalize_element(xml, Selection::Id("missing")).is_err());
+        assert!(canonicalize("<a><b:c/></a>").is_err());
+    }
+
+    #[test]
+    fn enveloped_leaves_out_only_the_verified_signature () {
+        let xml = "<root xmlns:ds=\"http://www.w3.org/2000/09/xmldsig#\">\
+            <ds:Signature Id=\"inner\"><ds:SignedInfo>other</ds:SignedInfo></ds:Signature>\
+            <ds:Signature Id=\"verified\"><ds:SignedInfo>info</ds:SignedInfo></ds:Signature>\
+            </root>";
+        assert_eq!(
+            canonicalize_enveloped(xml, &[1]),
+            Ok(String::from(
+                "<root><ds:Signature xmlns:ds=\"http://www.w3.org/2000/09/xmldsig#\" Id=\"inner\">\
+                <ds:SignedInfo>other</ds:SignedInfo></ds:Signature></root>"
+            ))
+        );
+        let duplicated = "<root><a Id=\"x\">1</a><b Id=\"x\">2</b></root>";
+        assert!(canonicalize_element(duplicated, Selection::Id("x")).is_err());
+    }
+}